@@ -8,35 +8,35 @@ fn bench_listcard_0(b: &mut Bencher) {
             let mut result = Template::new();
 
             // 빠른 응답
-            result.add_qr(QuickReply::new("오늘", "오늘 공지 보여줘"));
-            result.add_qr(QuickReply::new("어제", "어제 공지 보여줘"));
+            result.push_quick_reply(QuickReply::new("오늘", "오늘 공지 보여줘"));
+            result.push_quick_reply(QuickReply::new("어제", "어제 공지 보여줘"));
 
             let mut list_card = ListCard::new("리스트 카드 제목!"); // 제목
 
-            list_card.add_button(Button::new(ButtonType::Text).set_label("그냥 텍스트 버튼")); // 메시지 버튼
-            list_card.add_button(
+            list_card.push_button(Button::new(ButtonType::Text).with_label("그냥 텍스트 버튼")); // 메시지 버튼
+            list_card.push_button(
                 Button::new(ButtonType::Link)
-                    .set_label("link label")
-                    .set_link("https://google.com"),
+                    .with_label("link label")
+                    .with_link("https://google.com"),
             ); // 링크 버튼
-            list_card.add_button(
+            list_card.push_button(
                 Button::new(ButtonType::Share)
-                    .set_label("share label")
-                    .set_msg("카톡에 보이는 메시지"),
+                    .with_label("share label")
+                    .with_msg("카톡에 보이는 메시지"),
             ); // 공유 버튼
-            list_card.add_button(
+            list_card.push_button(
                 Button::new(ButtonType::Call)
-                    .set_label("call label")
-                    .set_number("010-1234-5678"),
+                    .with_label("call label")
+                    .with_number("010-1234-5678"),
             ); // 전화 버튼
 
-            list_card.add_item(
+            list_card.push_item(
                 ListItem::new("title")
-                    .set_desc("description")
-                    .set_link("https://naver.com"),
+                    .with_description("description")
+                    .with_link("https://naver.com"),
             );
 
-            result.add_output(list_card.build()); // moved list_card's ownership
+            result.push_output(list_card.build()); // moved list_card's ownership
         }
     });
 }
@@ -45,31 +45,81 @@ fn bench_multiple_output_0(b: &mut Bencher) {
     b.iter(|| {
         for _ in 0..1000 {
             let mut result = Template::new();
-            result.add_qr(QuickReply::new("빠른 응답", "빠른 응답 ㅋㅋ"));
+            result.push_quick_reply(QuickReply::new("빠른 응답", "빠른 응답 ㅋㅋ"));
 
-            let mut carousel = Carousel::new().set_type(BasicCard::id());
+            let mut carousel = Carousel::new().with_type(BasicCard::id());
 
             for i in 0..5 {
                 let basic_card = BasicCard::new()
-                    .set_title(format!("{}번", i))
-                    .set_thumbnail(
+                    .with_title(format!("{}번", i))
+                    .with_thumbnail(
                         "http://k.kakaocdn.net/dn/APR96/btqqH7zLanY/kD5mIPX7TdD2NAxgP29cC0/1x1.jpg",
                     );
 
-                carousel.add_card(basic_card.build_card());
+                carousel.push_card(basic_card.build_card());
             }
 
-            result.add_output(carousel.build());
+            result.push_output(carousel.build());
 
             let simple_text = SimpleText::new("심플 텍스트 테스트");
-            result.add_output(simple_text.build());
+            result.push_output(simple_text.build());
         }
     });
 }
 
+fn listcard_json() -> String {
+    let mut result = Template::new();
+    result.push_quick_reply(QuickReply::new("오늘", "오늘 공지 보여줘"));
+    result.push_quick_reply(QuickReply::new("어제", "어제 공지 보여줘"));
+
+    let mut list_card = ListCard::new("리스트 카드 제목!");
+    list_card.push_button(Button::text("그냥 텍스트 버튼"));
+    list_card.push_button(Button::link("link label", "https://google.com"));
+    for i in 0..5 {
+        list_card.push_item(
+            ListItem::new(format!("{}번 공지", i))
+                .with_description("description")
+                .with_link("https://naver.com"),
+        );
+    }
+    result.push_output(list_card.build());
+    result.to_string()
+}
+
+fn carousel_json() -> String {
+    let mut result = Template::new();
+    result.push_output(SimpleText::new("심플 텍스트 테스트").build());
+
+    let mut carousel = Carousel::new().with_type("itemCard");
+    for i in 0..10 {
+        let item_card = ItemCard::new()
+            .with_title(format!("{}번", i))
+            .with_description("desc")
+            .with_thumbnail("http://dev-mk.kakao.com/dn/bot/scripts/with_barcode_blue_1x1.png")
+            .with_image_title("DOFQTK")
+            .with_item_list_summary("total", "$4,032.54")
+            .with_button(Button::link("보기", "https://namu.wiki"));
+        carousel.push_card(item_card.build_card());
+    }
+    result.push_output(carousel.build());
+    result.to_string()
+}
+
+fn bench_deserialize_listcard(b: &mut Bencher) {
+    let json = listcard_json();
+    b.iter(|| serde_json::from_str::<Template>(&json).unwrap());
+}
+
+fn bench_deserialize_carousel(b: &mut Bencher) {
+    let json = carousel_json();
+    b.iter(|| serde_json::from_str::<Template>(&json).unwrap());
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("listcard_0", bench_listcard_0);
     c.bench_function("multiple_0", bench_multiple_output_0);
+    c.bench_function("deserialize_listcard", bench_deserialize_listcard);
+    c.bench_function("deserialize_carousel", bench_deserialize_carousel);
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -1,3 +1,5 @@
+#![allow(deprecated)] // 0.3 API 그대로
+
 extern crate kakao_rs;
 
 use kakao_rs::prelude::*;
@@ -0,0 +1,263 @@
+//! 응답 JSON 검사, 정리, 변환 도구
+//!
+//! ```text
+//! cargo run --features cli --bin kakao-cli -- validate response.json
+//! cat response.json | cargo run --features cli --bin kakao-cli -- fmt --minify
+//! cargo run --features cli --bin kakao-cli -- convert --to yaml response.json
+//! cargo run --features cli --bin kakao-cli -- text --markdown response.yaml
+//! ```
+use kakao_rs::prelude::*;
+use kakao_rs::render::TextRenderer;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process;
+
+const USAGE: &str = "사용법: kakao-cli <명령> [옵션] [파일...]
+
+파일이 없거나 `-`이면 표준 입력을 읽습니다.
+
+명령:
+  validate    형식과 카카오 제한을 검사합니다 (파일 여러 개 가능)
+  fmt         보기 좋게 다시 씁니다
+  convert     다른 형식으로 바꿉니다 (--to 필수)
+  text        일반 텍스트로 그립니다
+
+옵션:
+      --from <json|yaml|toml>   입력 형식 (기본값: 확장자, 표준 입력은 json)
+      --to <json|yaml|toml>     출력 형식 (기본값: 입력 형식)
+      --minify                  JSON 한 줄로 출력합니다
+      --markdown                text: 마크다운으로 출력합니다
+  -h, --help                    도움말";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl Format {
+    fn parse(name: &str) -> Result<Format, String> {
+        match name.to_ascii_lowercase().as_str() {
+            "json" => Ok(Format::Json),
+            "yaml" | "yml" => Ok(Format::Yaml),
+            "toml" => Ok(Format::Toml),
+            _ => Err(format!("알 수 없는 형식입니다: {}", name)),
+        }
+    }
+
+    fn from_path(path: &str) -> Option<Format> {
+        let ext = Path::new(path).extension()?.to_str()?;
+        Format::parse(ext).ok()
+    }
+}
+
+#[derive(PartialEq)]
+enum Command {
+    Validate,
+    Fmt,
+    Convert,
+    Text,
+}
+
+struct Args {
+    command: Command,
+    inputs: Vec<String>,
+    from: Option<Format>,
+    to: Option<Format>,
+    minify: bool,
+    markdown: bool,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = std::env::args().skip(1);
+    let command = match args.next().as_deref() {
+        Some("validate") => Command::Validate,
+        Some("fmt") => Command::Fmt,
+        Some("convert") => Command::Convert,
+        Some("text") => Command::Text,
+        Some("-h") | Some("--help") => {
+            println!("{}", USAGE);
+            process::exit(0);
+        }
+        Some(command) => return Err(format!("알 수 없는 명령입니다: {}", command)),
+        None => return Err("명령이 없습니다".to_string()),
+    };
+
+    let mut parsed = Args {
+        command,
+        inputs: Vec::new(),
+        from: None,
+        to: None,
+        minify: false,
+        markdown: false,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            "--from" => {
+                parsed.from = Some(Format::parse(&args.next().ok_or("--from 값이 없습니다")?)?)
+            }
+            "--to" => parsed.to = Some(Format::parse(&args.next().ok_or("--to 값이 없습니다")?)?),
+            "--minify" => parsed.minify = true,
+            "--markdown" => parsed.markdown = true,
+            _ if arg.starts_with("--") => return Err(format!("알 수 없는 옵션입니다: {}", arg)),
+            _ => parsed.inputs.push(arg),
+        }
+    }
+
+    if parsed.inputs.is_empty() {
+        parsed.inputs.push("-".to_string());
+    }
+    if parsed.command != Command::Validate && parsed.inputs.len() > 1 {
+        return Err("파일은 하나만 줄 수 있습니다".to_string());
+    }
+    if parsed.command == Command::Convert && parsed.to.is_none() {
+        return Err("convert에는 --to가 필요합니다".to_string());
+    }
+    Ok(parsed)
+}
+
+fn read(input: &str) -> Result<String, String> {
+    if input == "-" {
+        let mut text = String::new();
+        io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|e| format!("표준 입력 읽기 실패: {}", e))?;
+        Ok(text)
+    } else {
+        fs::read_to_string(input).map_err(|e| format!("읽기 실패: {}", e))
+    }
+}
+
+/// 오류 위치(`template.outputs[0]...`)를 함께 알려줍니다.
+fn parse(text: &str, format: Format) -> Result<Template, String> {
+    fn located<E: std::fmt::Display>(e: serde_path_to_error::Error<E>) -> String {
+        let path = e.path().to_string();
+        if path == "." {
+            e.into_inner().to_string()
+        } else {
+            format!("{}: {}", path, e.into_inner())
+        }
+    }
+
+    match format {
+        Format::Json => {
+            let mut de = serde_json::Deserializer::from_str(text);
+            serde_path_to_error::deserialize(&mut de).map_err(located)
+        }
+        Format::Yaml => serde_path_to_error::deserialize(serde_yaml::Deserializer::from_str(text))
+            .map_err(located),
+        Format::Toml => {
+            serde_path_to_error::deserialize(toml::Deserializer::new(text)).map_err(located)
+        }
+    }
+}
+
+fn write(template: &Template, format: Format, minify: bool) -> Result<String, String> {
+    match format {
+        Format::Json if minify => Ok(template.to_string()),
+        Format::Json => serde_json::to_string_pretty(template).map_err(|e| e.to_string()),
+        _ if minify => Err("--minify는 JSON만 지원합니다".to_string()),
+        Format::Yaml => serde_yaml::to_string(template).map_err(|e| e.to_string()),
+        Format::Toml => toml::to_string_pretty(template).map_err(|e| e.to_string()),
+    }
+}
+
+fn load(input: &str, from: Option<Format>) -> Result<(Template, Format), String> {
+    let format = from
+        .or_else(|| Format::from_path(input))
+        .unwrap_or(Format::Json);
+    let text = read(input)?;
+    Ok((parse(&text, format)?, format))
+}
+
+fn name(input: &str) -> &str {
+    if input == "-" {
+        "<stdin>"
+    } else {
+        input
+    }
+}
+
+fn validate(args: &Args) -> bool {
+    let mut ok = true;
+    for input in &args.inputs {
+        let template = match load(input, args.from) {
+            Ok((template, _)) => template,
+            Err(e) => {
+                eprintln!("✗ {}: {}", name(input), e);
+                ok = false;
+                continue;
+            }
+        };
+        match template.validate() {
+            Ok(()) => println!("✓ {}", name(input)),
+            Err(errors) => {
+                ok = false;
+                eprintln!("✗ {}", name(input));
+                for error in errors {
+                    eprintln!("  {}", error);
+                }
+            }
+        }
+    }
+    ok
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let input = &args.inputs[0];
+    let (template, format) =
+        load(input, args.from).map_err(|e| format!("{}: {}", name(input), e))?;
+
+    // 출력은 하되 카카오가 거절할 내용은 알려줍니다.
+    if let Err(errors) = template.validate() {
+        for error in errors {
+            eprintln!("! {}", error);
+        }
+    }
+
+    let output = if args.command == Command::Text {
+        let renderer = if args.markdown {
+            TextRenderer::markdown()
+        } else {
+            TextRenderer::new()
+        };
+        renderer.render(&template)
+    } else {
+        // --minify만 주면 JSON으로
+        let to = match args.to {
+            Some(to) => to,
+            None if args.minify => Format::Json,
+            None => format,
+        };
+        write(&template, to, args.minify)?
+    };
+    println!("{}", output.trim_end());
+    Ok(())
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if args.command == Command::Validate {
+        if !validate(&args) {
+            process::exit(1);
+        }
+        return;
+    }
+    if let Err(e) = run(&args) {
+        eprintln!("✗ {}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,104 @@
+//! 템플릿 JSON 파일이 들어 있는 폴더를 정적 HTML 갤러리로 만듭니다.
+//!
+//! ```text
+//! cargo run --features cli --bin kakao-gallery -- ./templates ./gallery
+//! ```
+//!
+//! 출력 폴더에는 파일마다 `<이름>.html`과 모두 모아 보는 `index.html`이 생깁니다.
+use kakao_rs::prelude::*;
+use kakao_rs::render::html;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+const USAGE: &str = "사용법: kakao-gallery <템플릿 폴더> <출력 폴더>
+
+템플릿 폴더의 *.json 파일을 카카오톡 말풍선 모양으로 그립니다.";
+
+/// 파일 하나를 불러온 결과: 말풍선 또는 오류 메시지
+fn load(path: &Path) -> Result<Template, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("읽기 실패: {}", e))?;
+    let template: Template =
+        serde_json::from_str(&text).map_err(|e| format!("응답 형식 오류: {}", e))?;
+    if let Err(errors) = template.validate() {
+        for error in errors {
+            eprintln!("! {}: {}", path.display(), error);
+        }
+    }
+    Ok(template)
+}
+
+fn json_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+fn run(input: &Path, output: &Path) -> std::io::Result<bool> {
+    let files = json_files(input)?;
+    fs::create_dir_all(output)?;
+
+    let mut ok = true;
+    let mut gallery = String::from("<div class=\"kakao-gallery\">\n");
+    for path in &files {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let content = match load(path) {
+            Ok(template) => html::fragment(&template),
+            Err(e) => {
+                eprintln!("✗ {}: {}", path.display(), e);
+                ok = false;
+                html::error(&e)
+            }
+        };
+        let phone = html::phone(Some(&name), &content);
+        fs::write(
+            output.join(format!("{}.html", stem)),
+            html::page(&name, &phone),
+        )?;
+        gallery.push_str(&phone);
+    }
+    gallery.push_str("</div>\n");
+    fs::write(
+        output.join("index.html"),
+        html::page("kakao-rs gallery", &gallery),
+    )?;
+
+    println!(
+        "{}개 템플릿 -> {}",
+        files.len(),
+        output.join("index.html").display()
+    );
+    Ok(ok)
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+    if args.len() != 2 {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    match run(Path::new(&args[0]), Path::new(&args[1])) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            process::exit(1);
+        }
+    }
+}
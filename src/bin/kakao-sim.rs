@@ -0,0 +1,256 @@
+//! 오픈빌더 시뮬레이터: 로컬 스킬 서버에 스킬 요청을 보내고 응답을 검사합니다.
+//!
+//! ```text
+//! cargo run --features cli --bin kakao-sim -- http://localhost:8000/skill 공지
+//! cargo run --features cli --bin kakao-sim -- http://localhost:8000/skill -i
+//! cargo run --features cli --bin kakao-sim -- http://localhost:8000/skill --replay records.jsonl
+//! ```
+use kakao_rs::prelude::*;
+use kakao_rs::record::Replay;
+use kakao_rs::scenario::choices;
+use serde_json::Value;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+const USAGE: &str = "사용법: kakao-sim <스킬 URL> [옵션] [발화...]
+
+옵션:
+  -i, --interactive   발화를 입력하거나 바로가기/버튼 번호를 골라 대화를 이어갑니다
+      --user <ID>     userRequest.user.id (기본값 kakao-rs-user)
+      --block <ID>    첫 요청의 userRequest.block.id
+      --json          응답 JSON도 출력합니다
+      --replay <파일> Recorder 기록(JSONL)의 요청을 다시 보내고 바뀐 응답을 보여 줍니다
+  -h, --help          도움말";
+
+struct Args {
+    url: String,
+    utterances: Vec<String>,
+    interactive: bool,
+    json: bool,
+    user: Option<String>,
+    block: Option<String>,
+    replay: Option<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = std::env::args().skip(1);
+    let mut url = None;
+    let mut parsed = Args {
+        url: String::new(),
+        utterances: Vec::new(),
+        interactive: false,
+        json: false,
+        user: None,
+        block: None,
+        replay: None,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            "-i" | "--interactive" => parsed.interactive = true,
+            "--json" => parsed.json = true,
+            "--user" => parsed.user = Some(args.next().ok_or("--user 값이 없습니다")?),
+            "--block" => parsed.block = Some(args.next().ok_or("--block 값이 없습니다")?),
+            "--replay" => parsed.replay = Some(args.next().ok_or("--replay 값이 없습니다")?),
+            _ if url.is_none() => url = Some(arg),
+            _ => parsed.utterances.push(arg),
+        }
+    }
+
+    parsed.url = url.ok_or("스킬 URL이 없습니다")?;
+    if parsed.utterances.is_empty() && parsed.replay.is_none() {
+        parsed.interactive = true;
+    }
+    Ok(parsed)
+}
+
+/// 오픈빌더가 보내는 것처럼 채워 넣습니다.
+fn fill_payload(request: &mut SkillRequest, block: Option<&str>) {
+    if let Some(id) = block {
+        if request.block_id().is_empty() {
+            request.put_block(id, id);
+        }
+    }
+    request.bot.id = "kakao-sim".to_string();
+    request.bot.name = "kakao-sim".to_string();
+    request.action.id = "kakao-sim-action".to_string();
+    request.action.name = "kakao-sim".to_string();
+    request
+        .user_request
+        .params
+        .insert("surface".to_string(), "BuilderBotTest".into());
+}
+
+/// 스킬 서버 호출 결과
+struct Turn {
+    response: Option<Template>,
+    choices: Vec<UserAction>,
+    ok: bool,
+}
+
+/// 스킬 서버에 요청을 보내고 응답 JSON을 받습니다.
+fn post(url: &str, request: &SkillRequest) -> Result<Value, String> {
+    let body = serde_json::to_string(request).expect("SkillRequest serialize");
+    let text = match ureq::post(url)
+        .set("Content-Type", "application/json")
+        .send_string(&body)
+    {
+        Ok(response) => response.into_string(),
+        Err(ureq::Error::Status(code, response)) => {
+            return Err(format!(
+                "HTTP {}\n{}",
+                code,
+                response.into_string().unwrap_or_default()
+            ))
+        }
+        Err(e) => return Err(format!("요청 실패: {}", e)),
+    };
+    let text = text.map_err(|e| format!("응답 읽기 실패: {}", e))?;
+    serde_json::from_str(&text).map_err(|e| format!("JSON이 아닙니다: {}\n{}", e, text))
+}
+
+fn send(url: &str, request: &SkillRequest, json: bool) -> Turn {
+    let failed = Turn {
+        response: None,
+        choices: Vec::new(),
+        ok: false,
+    };
+
+    let value = match post(url, request) {
+        Ok(value) => value,
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            return failed;
+        }
+    };
+    let text = value.to_string();
+    if json {
+        println!("{}", serde_json::to_string_pretty(&value).unwrap());
+    }
+
+    let template: Template = match serde_json::from_value(value) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("✗ 응답 형식 오류: {}\n{}", e, text);
+            return failed;
+        }
+    };
+    print!("{}", template.render_terminal());
+
+    let ok = match template.validate() {
+        Ok(()) => {
+            println!("✓ 응답 검사 통과");
+            true
+        }
+        Err(errors) => {
+            for error in errors {
+                eprintln!("✗ {}", error);
+            }
+            false
+        }
+    };
+
+    Turn {
+        choices: choices(&template),
+        response: Some(template),
+        ok,
+    }
+}
+
+/// 기록된 요청을 스킬 서버에 다시 보내 응답이 바뀌었는지 봅니다.
+fn replay(url: &str, path: &str) -> bool {
+    let replay = match Replay::from_path(path) {
+        Ok(replay) => replay,
+        Err(e) => {
+            eprintln!("✗ {}: {}", path, e);
+            return false;
+        }
+    };
+    // 요청이 실패하면 오류 메시지를 응답 대신 보여 줍니다.
+    let report = replay.run_with(|request| post(url, request).unwrap_or_else(Value::String));
+    print!("{}", report);
+    report.is_clean()
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Some(path) = &args.replay {
+        if !replay(&args.url, path) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let mut session = Session::new();
+    if let Some(user) = &args.user {
+        session.put_user(user.as_str());
+    }
+    let mut block = args.block.clone();
+    let mut all_ok = true;
+    let mut choices = Vec::new();
+
+    let mut turn = |session: &mut Session, action: UserAction, choices: &mut Vec<UserAction>| {
+        let mut request = match session.request(&action) {
+            Ok(request) => request,
+            Err(e) => {
+                eprintln!("✗ {}", e);
+                return false;
+            }
+        };
+        fill_payload(&mut request, block.take().as_deref());
+        println!("\n> {}", action);
+
+        let result = send(&args.url, &request, args.json);
+        *choices = result.choices;
+        if let Some(response) = result.response {
+            session.absorb(response);
+        }
+        result.ok
+    };
+
+    for utterance in &args.utterances {
+        all_ok &= turn(
+            &mut session,
+            UserAction::Utterance(utterance.clone()),
+            &mut choices,
+        );
+    }
+
+    if args.interactive {
+        let stdin = io::stdin();
+        loop {
+            print!("발화 또는 번호 (종료: 빈 줄) > ");
+            io::stdout().flush().ok();
+
+            let mut line = String::new();
+            if stdin.lock().read_line(&mut line).unwrap_or(0) == 0 {
+                break;
+            }
+            let line = line.trim();
+            if line.is_empty() {
+                break;
+            }
+
+            let action = match line.parse::<usize>() {
+                Ok(n) if n >= 1 && n <= choices.len() => choices[n - 1].clone(),
+                _ => UserAction::Utterance(line.to_string()),
+            };
+            all_ok &= turn(&mut session, action, &mut choices);
+        }
+    }
+
+    if !all_ok {
+        process::exit(1);
+    }
+}
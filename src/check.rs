@@ -0,0 +1,243 @@
+//! # kakao-rs
+//! URL과 전화번호 형식 검사
+//!
+//! `Button::link` 등은 아무 문자열이나 받고, 형식은
+//! [`Validate::validate`](crate::validate::Validate::validate)에서 검사합니다.
+//! 만들 때부터 확인하려면 `Button::try_link`, `Button::try_call` 같은 fallible 생성자를 쓰세요.
+//!
+//! ```
+//! use kakao_rs::check;
+//!
+//! assert!(check::check_url("https://ajou.ac.kr/notice?id=1").is_ok());
+//! assert!(check::check_url("htps://ajou.ac.kr").is_err());
+//!
+//! assert_eq!(check::normalize_phone("tel:01012345678").unwrap(), "010-1234-5678");
+//! assert_eq!(check::normalize_phone("+82 2 123 4567").unwrap(), "02-123-4567");
+//! assert_eq!(check::normalize_phone("15881234").unwrap(), "1588-1234");
+//! assert!(check::normalize_phone("010-12345678").is_err());
+//! ```
+use std::fmt;
+
+/// 웹 링크와 이미지에 쓸 수 있는 스킴
+pub const WEB_SCHEMES: [&str; 2] = ["http", "https"];
+
+/// URL/전화번호 형식 오류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckError {
+    /// URL을 읽을 수 없음
+    Url { url: String, reason: &'static str },
+    /// [WEB_SCHEMES]에 없는 스킴
+    Scheme { url: String, scheme: String },
+    /// 한국 전화번호 형식이 아님
+    Phone(String),
+}
+
+impl fmt::Display for CheckError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckError::Url { url, reason } => {
+                write!(f, "올바른 URL이 아닙니다 ({}): `{}`", reason, url)
+            }
+            CheckError::Scheme { url, scheme } => write!(
+                f,
+                "허용하지 않는 스킴 `{}`입니다 ({}만 가능): `{}`",
+                scheme,
+                WEB_SCHEMES.join(", "),
+                url
+            ),
+            CheckError::Phone(number) => write!(f, "올바른 전화번호가 아닙니다: `{}`", number),
+        }
+    }
+}
+
+impl std::error::Error for CheckError {}
+
+/***** URL *****/
+/// 웹 URL: [WEB_SCHEMES] 스킴과 호스트가 있어야 합니다.
+pub fn check_url(url: &str) -> Result<(), CheckError> {
+    let (scheme, rest) = split_scheme(url)?;
+    if !WEB_SCHEMES.contains(&scheme.to_ascii_lowercase().as_str()) {
+        return Err(CheckError::Scheme {
+            url: url.to_string(),
+            scheme: scheme.to_string(),
+        });
+    }
+    let rest = rest
+        .strip_prefix("//")
+        .ok_or_else(|| url_error(url, "`://`가 없습니다"))?;
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or("");
+    let host_port = authority.rsplit('@').next().unwrap_or("");
+    let (host, port) = match host_port.rsplit_once(':') {
+        Some((host, port)) => (host, Some(port)),
+        None => (host_port, None),
+    };
+    if let Some(port) = port {
+        if port.is_empty() || !port.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(url_error(url, "포트가 숫자가 아닙니다"));
+        }
+    }
+    if !is_host(host) {
+        return Err(url_error(url, "호스트가 올바르지 않습니다"));
+    }
+    Ok(())
+}
+
+/// 앱 링크(ios, android): 스킴만 맞으면 됩니다. (`kakaomap://`, `intent:` 등)
+pub fn check_app_url(url: &str) -> Result<(), CheckError> {
+    let (_, rest) = split_scheme(url)?;
+    if rest.is_empty() {
+        return Err(url_error(url, "스킴 뒤가 비어 있습니다"));
+    }
+    Ok(())
+}
+
+fn split_scheme(url: &str) -> Result<(&str, &str), CheckError> {
+    if url.is_empty() {
+        return Err(url_error(url, "비어 있습니다"));
+    }
+    if url.chars().any(char::is_whitespace) {
+        return Err(url_error(url, "공백이 있습니다"));
+    }
+    let (scheme, rest) = url
+        .split_once(':')
+        .ok_or_else(|| url_error(url, "스킴이 없습니다"))?;
+    let mut chars = scheme.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !valid {
+        return Err(url_error(url, "스킴이 없습니다"));
+    }
+    Ok((scheme, rest))
+}
+
+/// 점으로 나눈 각 부분이 비어 있지 않고 `-`로 시작/끝나지 않아야 합니다. (한글 도메인 허용)
+fn is_host(host: &str) -> bool {
+    !host.is_empty()
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
+
+fn url_error(url: &str, reason: &'static str) -> CheckError {
+    CheckError::Url {
+        url: url.to_string(),
+        reason,
+    }
+}
+/***** URL *****/
+
+/***** Phone *****/
+/// 한국 전화번호를 `010-1234-5678` 꼴로 맞춥니다.
+///
+/// `tel:`, `+82`, 공백/점/괄호 구분자를 받습니다. 구분자가 있으면 자리가 맞아야 합니다.
+/// 휴대폰(010 등), 지역번호(02, 031 등), 070/050X/080/060, 대표번호(1588-1234 등), 특수번호(112 등)
+pub fn normalize_phone(number: &str) -> Result<String, CheckError> {
+    let invalid = || CheckError::Phone(number.to_string());
+
+    let mut rest = number.trim();
+    rest = rest.strip_prefix("tel:").unwrap_or(rest).trim_start();
+    let international = match rest.strip_prefix("+82") {
+        Some(national) => {
+            rest = national.trim_start_matches([' ', '-', '.']);
+            true
+        }
+        None => false,
+    };
+
+    let mut groups: Vec<String> = rest
+        .split([' ', '-', '.', '(', ')'])
+        .filter(|group| !group.is_empty())
+        .map(String::from)
+        .collect();
+    if groups.is_empty()
+        || groups
+            .iter()
+            .any(|g| !g.bytes().all(|b| b.is_ascii_digit()))
+    {
+        return Err(invalid());
+    }
+    if international {
+        if groups[0].starts_with('0') {
+            return Err(invalid());
+        }
+        groups[0].insert(0, '0');
+    }
+
+    let digits: String = groups.concat();
+    let canonical = phone_groups(&digits).ok_or_else(invalid)?;
+    if groups.len() > 1 && groups != canonical {
+        return Err(invalid());
+    }
+    Ok(canonical.join("-"))
+}
+
+/// 번호 종류에 맞게 자리를 나눕니다.
+fn phone_groups(digits: &str) -> Option<Vec<String>> {
+    let len = digits.len();
+    let prefix_len = if digits.starts_with("02") {
+        2
+    } else if digits.starts_with("050") {
+        4
+    } else if digits.starts_with('0') {
+        3
+    } else {
+        // 대표번호 15XX/16XX/18XX-XXXX, 특수번호 1XX
+        return match len {
+            8 if ["15", "16", "18"].contains(&&digits[..2]) => {
+                Some(vec![digits[..4].to_string(), digits[4..].to_string()])
+            }
+            3 if digits.starts_with('1') => Some(vec![digits.to_string()]),
+            _ => None,
+        };
+    };
+
+    let prefix = &digits[..prefix_len.min(len)];
+    let known = match prefix_len {
+        2 => true,
+        4 => true,
+        _ => {
+            matches!(
+                prefix,
+                "010" | "011" | "016" | "017" | "018" | "019" | "070"
+            ) || matches!(prefix, "080" | "060") // 수신자 부담, 정보 이용료
+                || matches!(
+                prefix,
+                "031"
+                    | "032"
+                    | "033"
+                    | "041"
+                    | "042"
+                    | "043"
+                    | "044"
+                    | "051"
+                    | "052"
+                    | "053"
+                    | "054"
+                    | "055"
+                    | "061"
+                    | "062"
+                    | "063"
+                    | "064"
+            )
+        }
+    };
+    let subscriber = len.checked_sub(prefix_len)?;
+    let subscriber_ok = match prefix {
+        "010" | "070" => subscriber == 8,
+        _ => subscriber == 7 || subscriber == 8,
+    };
+    if !known || !subscriber_ok {
+        return None;
+    }
+
+    let middle = prefix_len + subscriber - 4;
+    Some(vec![
+        digits[..prefix_len].to_string(),
+        digits[prefix_len..middle].to_string(),
+        digits[middle..].to_string(),
+    ])
+}
+/***** Phone *****/
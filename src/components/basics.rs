@@ -1,28 +1,146 @@
+use crate::check::{self, CheckError};
 use crate::components::buttons::*;
 use crate::components::cards::*;
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use crate::limits;
+use crate::text::{self, Fit};
+use crate::validate::*;
+use serde::de::{Error, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
 use std::fmt;
 
 /***** Items *****/
-#[derive(Serialize, Deserialize, Debug)]
-#[serde(deny_unknown_fields)]
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+/// [Link](https://i.kakao.com/docs/skill-response-format#link): pc, mobile, web, ios, android 중 하나 이상
+///
+/// ```
+/// use kakao_rs::prelude::*;
+///
+/// let link = Link::default()
+///     .with_pc("https://ajou.ac.kr")
+///     .with_mobile("https://m.ajou.ac.kr");
+/// let item = ListItem::new("공지").with_link_targets(link);
+/// ```
 pub struct Link {
-    pub web: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub pc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub mobile: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub web: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub ios: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub android: Option<String>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
+}
+
+impl Link {
+    /// Link(web)
+    #[inline]
+    pub fn new<S: Into<String>>(web: S) -> Self {
+        Link {
+            web: Some(web.into()),
+            ..Default::default()
+        }
+    }
+
+    /// URL을 검사하는 [new](Self::new)
+    pub fn try_new<S: Into<String>>(web: S) -> Result<Self, CheckError> {
+        let web = web.into();
+        check::check_url(&web)?;
+        Ok(Link::new(web))
+    }
+
+    pub fn put_pc<S: Into<String>>(&mut self, url: S) -> &mut Self {
+        self.pc = Some(url.into());
+        self
+    }
+
+    pub fn put_mobile<S: Into<String>>(&mut self, url: S) -> &mut Self {
+        self.mobile = Some(url.into());
+        self
+    }
+
+    pub fn put_web<S: Into<String>>(&mut self, url: S) -> &mut Self {
+        self.web = Some(url.into());
+        self
+    }
+
+    pub fn put_ios<S: Into<String>>(&mut self, url: S) -> &mut Self {
+        self.ios = Some(url.into());
+        self
+    }
+
+    pub fn put_android<S: Into<String>>(&mut self, url: S) -> &mut Self {
+        self.android = Some(url.into());
+        self
+    }
+
+    /// 대표 URL: web, mobile, pc 순으로 처음 있는 것
+    pub fn url(&self) -> Option<&str> {
+        [&self.web, &self.mobile, &self.pc]
+            .into_iter()
+            .find_map(|url| url.as_deref())
+    }
+
+    /// 정한 대상이 하나도 없는지
+    pub fn is_empty(&self) -> bool {
+        self.targets().next().is_none()
+    }
+
+    /// (필드 이름, URL): pc, mobile, web, ios, android 순
+    pub fn targets(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        [
+            ("pc", &self.pc),
+            ("mobile", &self.mobile),
+            ("web", &self.web),
+            ("ios", &self.ios),
+            ("android", &self.android),
+        ]
+        .into_iter()
+        .filter_map(|(name, url)| url.as_deref().map(|url| (name, url)))
+    }
+
+    pub fn targets_mut(&mut self) -> impl Iterator<Item = &mut String> {
+        [
+            &mut self.pc,
+            &mut self.mobile,
+            &mut self.web,
+            &mut self.ios,
+            &mut self.android,
+        ]
+        .into_iter()
+        .flatten()
+    }
 }
 
+with!(Link {
+    with_pc => put_pc(url: impl Into<String>);
+    with_mobile => put_mobile(url: impl Into<String>);
+    with_web => put_web(url: impl Into<String>);
+    with_ios => put_ios(url: impl Into<String>);
+    with_android => put_android(url: impl Into<String>);
+});
+
 // Go 버전에서 ListItem, ListItemLink 합침
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
 pub struct ListItem {
-    title: String,
+    pub(crate) title: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    description: Option<String>,
+    pub(crate) description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    image_url: Option<String>,
+    pub(crate) image_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    link: Option<Link>,
+    pub(crate) link: Option<Link>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 impl ListItem {
@@ -34,32 +152,75 @@ impl ListItem {
             description: None,
             image_url: None,
             link: None,
+            unknown_fields: Map::new(),
         }
     }
 
-    pub fn set_desc<S: Into<String>>(mut self, desc: S) -> Self {
+    pub fn put_description<S: Into<String>>(&mut self, desc: S) -> &mut Self {
         self.description = Some(desc.into());
         self
     }
 
-    pub fn set_image<S: Into<String>>(mut self, url: S) -> Self {
+    pub fn put_image<S: Into<String>>(&mut self, url: S) -> &mut Self {
         self.image_url = Some(url.into());
         self
     }
 
-    pub fn set_link<S: Into<String>>(mut self, _url: S) -> Self {
-        self.link = Some(Link { web: _url.into() });
+    /// web 링크
+    pub fn put_link<S: Into<String>>(&mut self, url: S) -> &mut Self {
+        self.link = Some(Link::new(url));
         self
     }
+
+    /// URL을 검사하는 [set_link](Self::set_link)
+    pub fn try_set_link<S: Into<String>>(&mut self, url: S) -> Result<&mut Self, CheckError> {
+        self.link = Some(Link::try_new(url)?);
+        Ok(self)
+    }
+
+    /// pc, mobile 등 여러 대상을 정한 링크
+    pub fn put_link_targets(&mut self, link: Link) -> &mut Self {
+        self.link = Some(link);
+        self
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_description을 쓰세요")]
+    pub fn set_desc<S: Into<String>>(self, desc: S) -> Self {
+        self.with_description(desc)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_image를 쓰세요")]
+    pub fn set_image<S: Into<String>>(self, url: S) -> Self {
+        self.with_image(url)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_link를 쓰세요")]
+    pub fn set_link<S: Into<String>>(self, url: S) -> Self {
+        self.with_link(url)
+    }
 }
+
+with!(ListItem {
+    with_description => put_description(desc: impl Into<String>);
+    with_image => put_image(url: impl Into<String>);
+    with_link => put_link(url: impl Into<String>);
+    with_link_targets => put_link_targets(link: Link);
+});
+
+getters!(ListItem {
+    str title => title;
+    opt_str description => description;
+    opt_str image_url => image_url;
+    opt link, link_mut: Link => link;
+});
 /***** Items *****/
 
 /***** Quick Reply *****/
 // Go 버전에서 QuickReply
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
-/// [QuickReply](https://i.kakao.com/docs/skill-response-format#quickreplies): action, label, messageText (Optional), blockId (Optional)
+/// [QuickReply](https://i.kakao.com/docs/skill-response-format#quickreplies): action, label, messageText (Optional), blockId (Optional), extra (Optional)
 ///
 /// # Examples
 ///
@@ -70,15 +231,25 @@ impl ListItem {
 ///
 /// let mut result = Template::new();
 ///
-/// result.add_qr(QuickReply::new("오늘", "오늘 공지 보여줘"));
+/// result.push_quick_reply(QuickReply::new("오늘", "오늘 공지 보여줘"));
 ///
 /// ```
 pub struct QuickReply {
-    action: String,
-    label: String,
-    message_text: String,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(schema_with = "crate::schema::quick_reply_action")
+    )]
+    pub(crate) action: String,
+    #[cfg_attr(feature = "schema", schemars(length(min = 1)))]
+    pub(crate) label: String,
+    pub(crate) message_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) block_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    block_id: Option<String>,
+    pub(crate) extra: Option<Map<String, Value>>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 impl QuickReply {
@@ -90,31 +261,68 @@ impl QuickReply {
             message_text: _msg.into(),
             action: "message".to_string(),
             block_id: None,
+            extra: None,
+            unknown_fields: Map::new(),
         }
     }
 
-    pub fn set_block_id<S: Into<String>>(mut self, id: S) -> Self {
+    pub fn put_block_id<S: Into<String>>(&mut self, id: S) -> &mut Self {
         self.block_id = Some(id.into());
         self
     }
 
-    pub fn set_action<S: Into<String>>(mut self, _action: S) -> Self {
-        self.action = _action.into();
+    pub fn put_action<S: Into<String>>(&mut self, action: S) -> &mut Self {
+        self.action = action.into();
         self
     }
+
+    /// 다음 스킬 요청의 action.clientExtra로 전달됩니다.
+    pub fn put_extra<S: Into<String>, V: Into<Value>>(&mut self, key: S, value: V) -> &mut Self {
+        self.extra
+            .get_or_insert_with(Map::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_block_id를 쓰세요")]
+    pub fn set_block_id<S: Into<String>>(self, id: S) -> Self {
+        self.with_block_id(id)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_action을 쓰세요")]
+    pub fn set_action<S: Into<String>>(self, action: S) -> Self {
+        self.with_action(action)
+    }
 }
+
+getters!(QuickReply {
+    str action => action;
+    str label => label;
+    str message_text => message_text;
+    opt_str block_id => block_id;
+    opt extra, extra_mut: Map<String, Value> => extra;
+});
+
+with!(QuickReply {
+    with_block_id => put_block_id(id: impl Into<String>);
+    with_action => put_action(action: impl Into<String>);
+    with_extra => put_extra(key: impl Into<String>, value: impl Into<Value>);
+});
 /***** Quick Reply *****/
 
 /***** Extra *****/
 #[derive(Serialize, Deserialize, Debug)]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Title {
-    title: String,
+    pub(crate) title: String,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
 /// [ThumbNail 링크](https://i.kakao.com/docs/skill-response-format#thumbnail)
 pub struct ThumbNail {
     pub image_url: String,
@@ -126,6 +334,9 @@ pub struct ThumbNail {
     pub width: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub height: Option<i32>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 fn is_false(b: &bool) -> bool {
@@ -142,43 +353,97 @@ impl ThumbNail {
             fixed_ratio: false,
             width: None,
             height: None,
+            unknown_fields: Map::new(),
         }
     }
-    pub fn set_link<S: Into<String>>(mut self, url: S) -> Self {
-        self.link = Some(Link { web: url.into() });
+
+    /// 이미지 URL을 검사하는 [new](Self::new)
+    pub fn try_new<S: Into<String>>(url: S) -> Result<Self, CheckError> {
+        let url = url.into();
+        check::check_url(&url)?;
+        Ok(ThumbNail::new(url))
+    }
+
+    /// web 링크
+    pub fn put_link<S: Into<String>>(&mut self, url: S) -> &mut Self {
+        self.link = Some(Link::new(url));
         self
     }
 
-    pub fn set_link_ref<S: Into<String>>(&mut self, url: S) {
-        self.link = Some(Link { web: url.into() });
+    /// pc, mobile 등 여러 대상을 정한 링크
+    pub fn put_links(&mut self, link: Link) -> &mut Self {
+        self.link = Some(link);
+        self
     }
 
-    pub fn set_image_url<S: Into<String>>(mut self, url: S) -> Self {
+    pub fn put_image_url<S: Into<String>>(&mut self, url: S) -> &mut Self {
         self.image_url = url.into();
         self
     }
 
-    pub fn set_fixed_ratio(mut self, fixed: bool) -> Self {
+    pub fn put_fixed_ratio(&mut self, fixed: bool) -> &mut Self {
         self.fixed_ratio = fixed;
         self
     }
 
-    pub fn set_width(mut self, _width: i32) -> Self {
-        self.width = Some(_width);
+    pub fn put_width(&mut self, width: i32) -> &mut Self {
+        self.width = Some(width);
         self
     }
 
-    pub fn set_height(mut self, _height: i32) -> Self {
-        self.height = Some(_height);
+    pub fn put_height(&mut self, height: i32) -> &mut Self {
+        self.height = Some(height);
         self
     }
+
+    #[deprecated(since = "0.4.0", note = "put_link를 쓰세요")]
+    pub fn set_link_ref<S: Into<String>>(&mut self, url: S) {
+        self.put_link(url);
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_link를 쓰세요")]
+    pub fn set_link<S: Into<String>>(self, url: S) -> Self {
+        self.with_link(url)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_image_url을 쓰세요")]
+    pub fn set_image_url<S: Into<String>>(self, url: S) -> Self {
+        self.with_image_url(url)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_fixed_ratio를 쓰세요")]
+    pub fn set_fixed_ratio(self, fixed: bool) -> Self {
+        self.with_fixed_ratio(fixed)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_width를 쓰세요")]
+    pub fn set_width(self, width: i32) -> Self {
+        self.with_width(width)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_height를 쓰세요")]
+    pub fn set_height(self, height: i32) -> Self {
+        self.with_height(height)
+    }
 }
 
+with!(ThumbNail {
+    with_link => put_link(url: impl Into<String>);
+    with_links => put_links(link: Link);
+    with_image_url => put_image_url(url: impl Into<String>);
+    with_fixed_ratio => put_fixed_ratio(fixed: bool);
+    with_width => put_width(width: i32);
+    with_height => put_height(height: i32);
+});
+
+getters!(Title {
+    str title => title;
+});
 /***** Extra *****/
 
 /***** Main *****/
 #[derive(Serialize, Deserialize, Debug)]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 /// 현재 ListCard, BasicCard, SimpleText, SimpleImage, Carousel (Basic/CommerceCard) 지원
 ///
 /// # Examples
@@ -189,14 +454,21 @@ impl ThumbNail {
 /// use kakao_rs::prelude::*;
 ///
 /// let mut result = Template::new();
-/// result.add_qr(...)
-/// result.add_output(...)
-/// result.to_string() -> json!(self)
+/// result.push_quick_reply(QuickReply::new("빠른 응답", "빠른 응답 ㅋㅋ"));
+/// result.push_output(SimpleText::new("심플 텍스트 테스트").build());
 ///
+/// let json = result.to_string(); // json!(self)
 /// ```
 pub struct Template {
     pub template: Outputs,
     pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub context: Option<ContextControl>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub data: Option<Map<String, Value>>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 impl Default for Template {
@@ -212,31 +484,128 @@ impl Template {
         Template {
             template: Outputs::new(),
             version: "2.0".to_string(),
+            context: None,
+            data: None,
+            unknown_fields: Map::new(),
         }
     }
 
-    pub fn add_output(&mut self, output: Types) {
+    pub fn push_output(&mut self, output: Types) -> &mut Self {
         self.template.outputs.push(output);
+        self
     }
 
-    pub fn add_qr(&mut self, qr: QuickReply) {
+    pub fn extend_outputs<I: IntoIterator<Item = Types>>(&mut self, outputs: I) -> &mut Self {
+        self.template.outputs.extend(outputs);
+        self
+    }
+
+    pub fn push_quick_reply(&mut self, qr: QuickReply) -> &mut Self {
         self.template.quick_replies.push(qr);
+        self
+    }
+
+    pub fn extend_quick_replies<I: IntoIterator<Item = QuickReply>>(
+        &mut self,
+        quick_replies: I,
+    ) -> &mut Self {
+        self.template.quick_replies.extend(quick_replies);
+        self
+    }
+
+    /// 다음 발화까지 유지할 [context](https://i.kakao.com/docs/skill-response-format#contextcontrol)를 추가합니다.
+    pub fn push_context(&mut self, context: ContextValue) -> &mut Self {
+        self.context
+            .get_or_insert_with(ContextControl::default)
+            .values
+            .push(context);
+        self
+    }
+
+    /// 응답의 [data](https://i.kakao.com/docs/skill-response-format#skillresponse) 필드
+    pub fn put_data<S: Into<String>, V: Into<Value>>(&mut self, key: S, value: V) -> &mut Self {
+        self.data
+            .get_or_insert_with(Map::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    #[deprecated(since = "0.4.0", note = "push_output을 쓰세요")]
+    pub fn add_output(&mut self, output: Types) {
+        self.push_output(output);
+    }
+
+    #[deprecated(since = "0.4.0", note = "push_quick_reply를 쓰세요")]
+    pub fn add_qr(&mut self, qr: QuickReply) {
+        self.push_quick_reply(qr);
+    }
+
+    #[deprecated(since = "0.4.0", note = "push_context를 쓰세요")]
+    pub fn add_context(&mut self, context: ContextValue) {
+        self.push_context(context);
     }
 
     #[inline]
     pub fn build(&self) -> Value {
         json!(self)
     }
+
+    pub fn outputs(&self) -> &[Types] {
+        &self.template.outputs
+    }
+
+    pub fn outputs_mut(&mut self) -> &mut Vec<Types> {
+        &mut self.template.outputs
+    }
+
+    pub fn quick_replies(&self) -> &[QuickReply] {
+        &self.template.quick_replies
+    }
+
+    pub fn quick_replies_mut(&mut self) -> &mut Vec<QuickReply> {
+        &mut self.template.quick_replies
+    }
+
+    /// context가 없으면 비어 있습니다.
+    pub fn contexts(&self) -> &[ContextValue] {
+        self.context
+            .as_ref()
+            .map_or(&[], |context| context.values.as_slice())
+    }
+
+    /// 모든 출력(캐로셀 카드 포함)의 버튼
+    pub fn buttons(&self) -> impl Iterator<Item = &Button> {
+        self.template.outputs.iter().flat_map(Types::buttons)
+    }
+
+    pub fn buttons_mut(&mut self) -> impl Iterator<Item = &mut Button> {
+        self.template
+            .outputs
+            .iter_mut()
+            .flat_map(Types::buttons_mut)
+    }
 }
 
+with!(Template {
+    with_output => push_output(output: Types);
+    with_outputs => extend_outputs(outputs: impl IntoIterator<Item = Types>);
+    with_quick_reply => push_quick_reply(qr: QuickReply);
+    with_quick_replies => extend_quick_replies(quick_replies: impl IntoIterator<Item = QuickReply>);
+    with_context => push_context(context: ContextValue);
+    with_data => put_data(key: impl Into<String>, value: impl Into<Value>);
+});
+
 impl fmt::Display for Template {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "{}", serde_json::to_string(self).unwrap())
+        write!(f, "{}", serde_json::to_string(self).unwrap())
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// 출력 하나: `{"simpleText": {...}}`처럼 키 하나로 종류를 구분합니다.
+#[derive(Serialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum Types {
     List(ListCard),
     Basic(BasicCard),
@@ -247,13 +616,273 @@ pub enum Types {
     Carousel(Carousel),
 }
 
+impl Types {
+    /// 카드의 버튼 (캐로셀은 모든 카드의 버튼)
+    pub fn buttons(&self) -> Vec<&Button> {
+        match self {
+            Types::List(list) => list.list_card.buttons.iter().collect(),
+            Types::Basic(basic) => basic.content.buttons().iter().collect(),
+            Types::Commerce(commerce) => commerce.content.buttons().iter().collect(),
+            Types::Item(item) => item.content.buttons().iter().collect(),
+            Types::Carousel(carousel) => carousel
+                .carousel
+                .items
+                .iter()
+                .flat_map(|card| card.buttons())
+                .collect(),
+            Types::SimpleTxt(_) | Types::SimpleImg(_) => Vec::new(),
+        }
+    }
+
+    pub fn buttons_mut(&mut self) -> Vec<&mut Button> {
+        match self {
+            Types::List(list) => list.list_card.buttons.iter_mut().collect(),
+            Types::Basic(basic) => basic.content.buttons.iter_mut().collect(),
+            Types::Commerce(commerce) => commerce.content.buttons.iter_mut().collect(),
+            Types::Item(item) => item.content.buttons.iter_mut().collect(),
+            Types::Carousel(carousel) => carousel
+                .carousel
+                .items
+                .iter_mut()
+                .flat_map(|card| card.buttons_mut())
+                .collect(),
+            Types::SimpleTxt(_) | Types::SimpleImg(_) => Vec::new(),
+        }
+    }
+
+    /// `simpleText` 등 종류 키 옆의 모르는 필드
+    pub(crate) fn wrapper_unknown_fields(&self) -> &Map<String, Value> {
+        match self {
+            Types::List(list) => &list.unknown_fields,
+            Types::Basic(basic) => &basic.unknown_fields,
+            Types::Commerce(commerce) => &commerce.unknown_fields,
+            Types::Item(item) => &item.unknown_fields,
+            Types::SimpleTxt(text) => &text.unknown_fields,
+            Types::SimpleImg(image) => &image.unknown_fields,
+            Types::Carousel(carousel) => &carousel.unknown_fields,
+        }
+    }
+
+    fn wrapper_unknown_fields_mut(&mut self) -> &mut Map<String, Value> {
+        match self {
+            Types::List(list) => &mut list.unknown_fields,
+            Types::Basic(basic) => &mut basic.unknown_fields,
+            Types::Commerce(commerce) => &mut commerce.unknown_fields,
+            Types::Item(item) => &mut item.unknown_fields,
+            Types::SimpleTxt(text) => &mut text.unknown_fields,
+            Types::SimpleImg(image) => &mut image.unknown_fields,
+            Types::Carousel(carousel) => &mut carousel.unknown_fields,
+        }
+    }
+}
+
+/// 출력 종류 키
+const OUTPUT_TYPES: [&str; 7] = [
+    "simpleText",
+    "simpleImage",
+    "basicCard",
+    "commerceCard",
+    "itemCard",
+    "listCard",
+    "carousel",
+];
+
+impl<'de> Deserialize<'de> for Types {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(TypesVisitor)
+    }
+}
+
+struct TypesVisitor;
+
+impl<'de> Visitor<'de> for TypesVisitor {
+    type Value = Types;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an output with one of {}", OUTPUT_TYPES.join(", "))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Types, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut output: Option<Types> = None;
+        let mut unknown_fields = Map::new();
+
+        while let Some(key) = map.next_key::<String>()? {
+            let next = match key.as_str() {
+                "simpleText" => Types::SimpleTxt(SimpleText {
+                    simple_text: map.next_value()?,
+                    unknown_fields: Map::new(),
+                }),
+                "simpleImage" => Types::SimpleImg(SimpleImage {
+                    simple_image: map.next_value()?,
+                    unknown_fields: Map::new(),
+                }),
+                "basicCard" => Types::Basic(BasicCard {
+                    content: map.next_value()?,
+                    unknown_fields: Map::new(),
+                }),
+                "commerceCard" => Types::Commerce(CommerceCard {
+                    content: map.next_value()?,
+                    unknown_fields: Map::new(),
+                }),
+                "itemCard" => Types::Item(ItemCard {
+                    content: map.next_value()?,
+                    unknown_fields: Map::new(),
+                }),
+                "listCard" => Types::List(ListCard {
+                    list_card: map.next_value()?,
+                    unknown_fields: Map::new(),
+                }),
+                "carousel" => Types::Carousel(Carousel {
+                    carousel: map.next_value()?,
+                    unknown_fields: Map::new(),
+                }),
+                _ => {
+                    unknown_fields.insert(key, map.next_value()?);
+                    continue;
+                }
+            };
+            if let Some(previous) = &output {
+                return Err(A::Error::custom(format!(
+                    "an output has only one type, found `{}` and `{}`",
+                    output_key(previous),
+                    key
+                )));
+            }
+            output = Some(next);
+        }
+
+        let mut output = output.ok_or_else(|| {
+            A::Error::custom(format!(
+                "missing output type, expected one of {}",
+                OUTPUT_TYPES.join(", ")
+            ))
+        })?;
+        *output.wrapper_unknown_fields_mut() = unknown_fields;
+        Ok(output)
+    }
+}
+
+fn output_key(output: &Types) -> &'static str {
+    match output {
+        Types::SimpleTxt(_) => "simpleText",
+        Types::SimpleImg(_) => "simpleImage",
+        Types::Basic(_) => "basicCard",
+        Types::Commerce(_) => "commerceCard",
+        Types::Item(_) => "itemCard",
+        Types::List(_) => "listCard",
+        Types::Carousel(_) => "carousel",
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+/// [ContextControl](https://i.kakao.com/docs/skill-response-format#contextcontrol): values
+pub struct ContextControl {
+    pub values: Vec<ContextValue>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+/// [ContextValue](https://i.kakao.com/docs/skill-response-format#contextvalue): name, lifeSpan, ttl (Optional), params (Optional)
+///
+/// lifeSpan을 0으로 주면 해당 context가 삭제됩니다.
+///
+/// # Examples
+///
+/// ```
+/// use kakao_rs::prelude::*;
+///
+/// let mut result = Template::new();
+/// result.push_context(ContextValue::new("major", 5).with_param("name", "소프트웨어학과"));
+/// ```
+pub struct ContextValue {
+    pub(crate) name: String,
+    pub(crate) life_span: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) ttl: Option<i32>,
+    #[serde(skip_serializing_if = "Map::is_empty", default)]
+    pub(crate) params: Map<String, Value>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
+}
+
+impl ContextValue {
+    /// ContextValue(이름, lifeSpan)
+    #[inline]
+    pub fn new<S: Into<String>>(name: S, life_span: i32) -> Self {
+        ContextValue {
+            name: name.into(),
+            life_span,
+            ttl: None,
+            params: Map::new(),
+            unknown_fields: Map::new(),
+        }
+    }
+
+    pub fn put_ttl(&mut self, ttl: i32) -> &mut Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    pub fn put_param<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) -> &mut Self {
+        self.params.insert(key.into(), Value::String(value.into()));
+        self
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_param을 쓰세요")]
+    pub fn add_param<S: Into<String>>(self, key: S, value: S) -> Self {
+        self.with_param(key, value)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn life_span(&self) -> i32 {
+        self.life_span
+    }
+
+    pub fn ttl(&self) -> Option<i32> {
+        self.ttl
+    }
+
+    pub fn params(&self) -> &Map<String, Value> {
+        &self.params
+    }
+
+    pub fn params_mut(&mut self) -> &mut Map<String, Value> {
+        &mut self.params
+    }
+}
+
+with!(ContextValue {
+    with_ttl => put_ttl(ttl: i32);
+    with_param => put_param(key: impl Into<String>, value: impl Into<String>);
+});
+
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
 pub struct Outputs {
+    #[cfg_attr(feature = "schema", schemars(length(min = 1, max = "limits::OUTPUTS")))]
     pub outputs: Vec<Types>,
+    #[cfg_attr(feature = "schema", schemars(length(max = "limits::QUICK_REPLIES")))]
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub quick_replies: Vec<QuickReply>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 impl Outputs {
@@ -262,6 +891,7 @@ impl Outputs {
         Outputs {
             outputs: Vec::new(),
             quick_replies: Vec::<QuickReply>::new(),
+            unknown_fields: Map::new(),
         }
     }
 }
@@ -275,7 +905,7 @@ impl Outputs {
 
 /***** Carousel *****/
 #[derive(Serialize, Deserialize, Debug)]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 /// [Carousel](https://i.kakao.com/docs/skill-response-format#carousel) (BasicCard or CommerceCard): type, items (Optional), header (Optional)
 ///
 /// # Examples
@@ -285,34 +915,46 @@ impl Outputs {
 /// ```
 /// use kakao_rs::prelude::*;
 ///
-/// let mut carousel = Carousel::new().set_type(BasicCard::id());
+/// let mut result = Template::new();
+/// let mut carousel = Carousel::new().with_type(BasicCard::id());
 ///
 /// for i in 0..5 {
 /// let basic_card = BasicCard::new()
-///     .set_title(format!("{}번", i))
-///     .set_thumbnail("http://k.kakaocdn.net/dn/APR96/btqqH7zLanY/kD5mIPX7TdD2NAxgP29cC0/1x1.jpg");
+///     .with_title(format!("{}번", i))
+///     .with_thumbnail("http://k.kakaocdn.net/dn/APR96/btqqH7zLanY/kD5mIPX7TdD2NAxgP29cC0/1x1.jpg");
 ///
-///     carousel.add_card(basic_card.build_card());
+///     carousel.push_card(basic_card.build_card());
 /// }
 ///
-/// result.add_output(carousel.build());
+/// result.push_output(carousel.build());
 ///
 /// ```
 pub struct Carousel {
-    carousel: CarouselContent,
+    pub(crate) carousel: CarouselContent,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// items는 type에 맞는 카드로 읽습니다.
+#[derive(Serialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
 pub struct CarouselContent {
-    r#type: String,
+    pub(crate) r#type: String,
     // #[serde(default)]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(length(min = 1, max = "limits::CAROUSEL_ITEMS"))
+    )]
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    items: Vec<Card>, // TODO ListCard
+    pub(crate) items: Vec<Card>,
     // #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    header: Option<CarouselHeader>,
+    pub(crate) header: Option<CarouselHeader>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 impl Default for Carousel {
@@ -330,72 +972,194 @@ impl Carousel {
                 r#type: "basicCard".to_string(),
                 items: Vec::new(),
                 header: None,
+                unknown_fields: Map::new(),
             },
+            unknown_fields: Map::new(),
         }
     }
 
-    pub fn add_card(&mut self, card: Card) {
+    pub fn push_card(&mut self, card: Card) -> &mut Self {
         self.carousel.items.push(card);
-        // match &mut card {
-        //     Card::Basic(basic) => {
-        //         self.carousel.items.push(basic.content);
-        //     }
-        //     Card::Commerce(com) => {
-        //         self.carousel.items.push(com.content);
-        //     }
-        //     Card::Item(item) => {
-        //         self.carousel.items.push(item.content);
-        //     }
-        // }
+        self
+    }
+
+    pub fn extend_cards<I: IntoIterator<Item = Card>>(&mut self, cards: I) -> &mut Self {
+        self.carousel.items.extend(cards);
+        self
     }
 
     pub fn build(self) -> Types {
         Types::Carousel(self)
     }
 
-    pub fn set_type<S: Into<String>>(mut self, _type: S) -> Self {
-        self.carousel.r#type = _type.into();
+    pub fn put_type<S: Into<String>>(&mut self, card_type: S) -> &mut Self {
+        self.carousel.r#type = card_type.into();
         self
     }
 
-    /// set_header(제목, 설명, 썸네일 url)
-    pub fn set_header<S: Into<String>>(&mut self, title: S, desc: S, url: S) {
+    /// put_header(제목, 설명, 썸네일 url)
+    pub fn put_header(
+        &mut self,
+        title: impl Into<String>,
+        desc: impl Into<String>,
+        url: impl Into<String>,
+    ) -> &mut Self {
         self.carousel.header = Some(CarouselHeader::new2(title, desc, url));
+        self
+    }
+
+    pub fn put_header_title<S: Into<String>>(&mut self, title: S) -> &mut Self {
+        self.header_or_default().put_title(title);
+        self
     }
 
+    pub fn put_header_description<S: Into<String>>(&mut self, desc: S) -> &mut Self {
+        self.header_or_default().put_description(desc);
+        self
+    }
+
+    pub fn put_header_thumbnail<S: Into<String>>(&mut self, url: S) -> &mut Self {
+        self.header_or_default().put_image_url(url);
+        self
+    }
+
+    fn header_or_default(&mut self) -> &mut CarouselHeader {
+        self.carousel.header.get_or_insert_with(CarouselHeader::new)
+    }
+
+    #[deprecated(since = "0.4.0", note = "push_card를 쓰세요")]
+    pub fn add_card(&mut self, card: Card) {
+        self.push_card(card);
+    }
+
+    #[deprecated(since = "0.4.0", note = "put_header를 쓰세요")]
+    pub fn set_header(
+        &mut self,
+        title: impl Into<String>,
+        desc: impl Into<String>,
+        url: impl Into<String>,
+    ) {
+        self.put_header(title, desc, url);
+    }
+
+    #[deprecated(since = "0.4.0", note = "put_header_title을 쓰세요")]
     pub fn set_header_title<S: Into<String>>(&mut self, title: S) {
-        if self.carousel.header.is_none() {
-            self.carousel.header = Some(CarouselHeader::new());
-        }
-        self.carousel
-            .header
-            .as_mut()
-            .unwrap()
-            .set_title(title.into());
+        self.put_header_title(title);
     }
 
+    #[deprecated(since = "0.4.0", note = "put_header_description을 쓰세요")]
     pub fn set_header_desc<S: Into<String>>(&mut self, desc: S) {
-        if self.carousel.header.is_none() {
-            self.carousel.header = Some(CarouselHeader::new());
-        }
-        self.carousel.header.as_mut().unwrap().set_desc(desc.into());
+        self.put_header_description(desc);
+    }
+
+    #[deprecated(since = "0.4.0", note = "put_header_thumbnail을 쓰세요")]
+    pub fn set_header_thumbnail<S: Into<String>>(&mut self, url: S) {
+        self.put_header_thumbnail(url);
     }
 
-    pub fn set_header_thumbnail(&mut self, url: String) {
-        if self.carousel.header.is_none() {
-            self.carousel.header = Some(CarouselHeader::new());
+    #[deprecated(since = "0.4.0", note = "with_type을 쓰세요")]
+    pub fn set_type<S: Into<String>>(self, card_type: S) -> Self {
+        self.with_type(card_type)
+    }
+}
+
+with!(Carousel {
+    with_card => push_card(card: Card);
+    with_cards => extend_cards(cards: impl IntoIterator<Item = Card>);
+    with_type => put_type(card_type: impl Into<String>);
+    with_header_title => put_header_title(title: impl Into<String>);
+    with_header_description => put_header_description(desc: impl Into<String>);
+    with_header_thumbnail => put_header_thumbnail(url: impl Into<String>);
+});
+
+getters!(Carousel {
+    str card_type => carousel.r#type;
+    vec cards, cards_mut: Card => carousel.items;
+    opt header, header_mut: CarouselHeader => carousel.header;
+});
+
+impl<'de> Deserialize<'de> for CarouselContent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(CarouselVisitor)
+    }
+}
+
+struct CarouselVisitor;
+
+impl<'de> Visitor<'de> for CarouselVisitor {
+    type Value = CarouselContent;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a carousel with type and items")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<CarouselContent, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut card_type: Option<String> = None;
+        let mut items = None;
+        // type보다 items가 먼저 오면 type을 알 때까지 보관
+        let mut pending: Option<Vec<Value>> = None;
+        let mut header = None;
+        let mut unknown_fields = Map::new();
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "type" => card_type = Some(map.next_value()?),
+                "items" => match &card_type {
+                    Some(card_type) => items = Some(map.next_value_seed(CardsSeed(card_type))?),
+                    None => pending = Some(map.next_value()?),
+                },
+                "header" => header = map.next_value()?,
+                _ => {
+                    unknown_fields.insert(key, map.next_value()?);
+                }
+            }
         }
-        self.carousel.header.as_mut().unwrap().set_image_url(url);
+
+        let card_type = card_type.ok_or_else(|| A::Error::missing_field("type"))?;
+        if let Some(pending) = pending {
+            let mut cards = Vec::with_capacity(pending.len());
+            for (i, value) in pending.into_iter().enumerate() {
+                let mut track = serde_path_to_error::Track::new();
+                let deserializer = serde_path_to_error::Deserializer::new(value, &mut track);
+                let card = Card::deserialize_as(&card_type, deserializer).map_err(|e| {
+                    let path = track.path().to_string();
+                    let location = if path == "." {
+                        format!("items[{}]", i)
+                    } else {
+                        format!("items[{}].{}", i, path)
+                    };
+                    A::Error::custom(format!("{}: {}", location, e))
+                })?;
+                cards.push(card);
+            }
+            items = Some(cards);
+        }
+
+        Ok(CarouselContent {
+            r#type: card_type,
+            items: items.unwrap_or_default(),
+            header,
+            unknown_fields,
+        })
     }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
 pub struct CarouselHeader {
-    title: String,
-    description: String,
-    thumbnail: ThumbNail,
+    pub(crate) title: String,
+    pub(crate) description: String,
+    pub(crate) thumbnail: ThumbNail,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 impl Default for CarouselHeader {
@@ -412,34 +1176,66 @@ impl CarouselHeader {
             title: "".to_string(),
             description: "".to_string(),
             thumbnail: ThumbNail::new("".to_string()),
+            unknown_fields: Map::new(),
         }
     }
 
     #[inline]
-    pub fn new2<S: Into<String>>(title: S, desc: S, url: S) -> Self {
+    pub fn new2(title: impl Into<String>, desc: impl Into<String>, url: impl Into<String>) -> Self {
         CarouselHeader {
             title: title.into(),
             description: desc.into(),
             thumbnail: ThumbNail::new(url.into()),
+            unknown_fields: Map::new(),
         }
     }
 
-    pub fn set_title<S: Into<String>>(&mut self, title: S) {
+    pub fn put_title<S: Into<String>>(&mut self, title: S) -> &mut Self {
         self.title = title.into();
+        self
     }
 
-    pub fn set_desc<S: Into<String>>(&mut self, desc: S) {
+    pub fn put_description<S: Into<String>>(&mut self, desc: S) -> &mut Self {
         self.description = desc.into();
+        self
+    }
+
+    pub fn put_image_url<S: Into<String>>(&mut self, url: S) -> &mut Self {
+        self.thumbnail.put_image_url(url);
+        self
     }
 
+    #[deprecated(since = "0.4.0", note = "put_title을 쓰세요")]
+    pub fn set_title<S: Into<String>>(&mut self, title: S) {
+        self.put_title(title);
+    }
+
+    #[deprecated(since = "0.4.0", note = "put_description을 쓰세요")]
+    pub fn set_desc<S: Into<String>>(&mut self, desc: S) {
+        self.put_description(desc);
+    }
+
+    #[deprecated(since = "0.4.0", note = "put_image_url을 쓰세요")]
     pub fn set_image_url<S: Into<String>>(&mut self, url: S) {
-        self.thumbnail.set_link_ref(url.into());
+        self.put_image_url(url);
     }
 }
+
+with!(CarouselHeader {
+    with_title => put_title(title: impl Into<String>);
+    with_description => put_description(desc: impl Into<String>);
+    with_image_url => put_image_url(url: impl Into<String>);
+});
+
+getters!(CarouselHeader {
+    str title => title;
+    str description => description;
+    val thumbnail, thumbnail_mut: ThumbNail => thumbnail;
+});
 /***** Carousel *****/
 
 #[derive(Serialize, Deserialize, Debug)]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 /// [ListCard](https://i.kakao.com/docs/skill-response-format#listcard): buttons, header, items
 ///
@@ -450,40 +1246,51 @@ impl CarouselHeader {
 /// ```
 /// use kakao_rs::prelude::*;
 ///
+/// let mut result = Template::new();
 /// let mut list_card = ListCard::new("리스트 카드 제목!"); // 제목
 ///
 /// // 버튼 추가
-/// list_card.add_button(Button::Msg(MsgButton::new("그냥 텍스트 버튼")));
+/// list_card.push_button(Button::text("그냥 텍스트 버튼"));
 ///
-/// list_card.add_button(Button::Link(
-///     LinkButton::new("link label").set_link("https://google.com"),
-/// ));
-/// list_card.add_button(Button::Share(
-///     ShareButton::new("share label").set_msg("카톡에 보이는 메시지"),
-/// ));
+/// list_card.push_button(Button::link("link label", "https://google.com"));
+/// list_card.push_button(Button::share("share label").with_msg("카톡에 보이는 메시지"));
 ///
 /// // 아이템 추가
-/// list_card.add_item(
+/// list_card.push_item(
 ///     ListItem::new("title")
-///         .set_desc("description")
-///         .set_link("https://naver.com"),
+///         .with_description("description")
+///         .with_link("https://naver.com"),
 /// );
 ///
 /// // 빌드
-/// result.add_output(list_card.build()); // moved list_card's ownership
+/// result.push_output(list_card.build()); // moved list_card's ownership
 ///
 /// ```
 pub struct ListCard {
-    list_card: ListCardContent,
+    pub(crate) list_card: ListCardContent,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ListCardContent {
+    #[cfg_attr(
+        feature = "schema",
+        schemars(length(max = "limits::LIST_CARD_BUTTONS"))
+    )]
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    buttons: Vec<Button>,
-    header: Title,        // 필수
-    items: Vec<ListItem>, // 필수
+    pub(crate) buttons: Vec<Button>,
+    pub(crate) header: Title, // 필수
+    #[cfg_attr(
+        feature = "schema",
+        schemars(length(min = 1, max = "limits::LIST_CARD_ITEMS"))
+    )]
+    pub(crate) items: Vec<ListItem>, // 필수
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 impl ListCard {
@@ -492,22 +1299,79 @@ impl ListCard {
     pub fn new<S: Into<String>>(_header: S) -> ListCard {
         ListCard {
             list_card: ListCardContent::new(_header.into()),
+            unknown_fields: Map::new(),
         }
     }
 
-    pub fn add_button(&mut self, button: Button) {
+    pub fn put_header<S: Into<String>>(&mut self, title: S) -> &mut Self {
+        self.list_card.header.title = title.into();
+        self
+    }
+
+    pub fn push_button(&mut self, button: Button) -> &mut Self {
         self.list_card.buttons.push(button);
+        self
     }
 
-    pub fn add_item(&mut self, item: ListItem) {
+    pub fn extend_buttons<I: IntoIterator<Item = Button>>(&mut self, buttons: I) -> &mut Self {
+        self.list_card.buttons.extend(buttons);
+        self
+    }
+
+    pub fn push_item(&mut self, item: ListItem) -> &mut Self {
         self.list_card.items.push(item);
+        self
+    }
+
+    pub fn extend_items<I: IntoIterator<Item = ListItem>>(&mut self, items: I) -> &mut Self {
+        self.list_card.items.extend(items);
+        self
+    }
+
+    #[deprecated(since = "0.4.0", note = "push_button을 쓰세요")]
+    pub fn add_button(&mut self, button: Button) {
+        self.push_button(button);
+    }
+
+    #[deprecated(since = "0.4.0", note = "push_item을 쓰세요")]
+    pub fn add_item(&mut self, item: ListItem) {
+        self.push_item(item);
     }
 
     pub fn build(self) -> Types {
         Types::List(self)
     }
+    /// Carousel에 추가할 때 사용하세요. (캐로셀 안에서는 items 4개까지)
+    pub fn build_card(self) -> Card {
+        Card::List(self.list_card)
+    }
+
+    #[inline]
+    pub fn id() -> String {
+        "listCard".to_string()
+    }
 }
 
+with!(ListCard {
+    with_header => put_header(title: impl Into<String>);
+    with_button => push_button(button: Button);
+    with_buttons => extend_buttons(buttons: impl IntoIterator<Item = Button>);
+    with_item => push_item(item: ListItem);
+    with_items => extend_items(items: impl IntoIterator<Item = ListItem>);
+});
+
+getters!(ListCard {
+    str header => list_card.header.title;
+    vec buttons, buttons_mut: Button => list_card.buttons;
+    vec items, items_mut: ListItem => list_card.items;
+});
+
+getters!(ListCardContent {
+    str header => header.title;
+    vec buttons, buttons_mut: Button => buttons;
+    vec items, items_mut: ListItem => items;
+});
+
 impl ListCardContent {
     #[inline]
     fn new<S: Into<String>>(_title: S) -> ListCardContent {
@@ -515,15 +1379,17 @@ impl ListCardContent {
             buttons: Vec::new(),
             header: Title {
                 title: _title.into(),
+                unknown_fields: Map::new(),
             },
             items: Vec::new(),
+            unknown_fields: Map::new(),
         }
     }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
 /// [SimpleText](https://i.kakao.com/docs/skill-response-format#simpletext): text
 ///
 /// # Examples
@@ -534,20 +1400,26 @@ impl ListCardContent {
 /// use kakao_rs::prelude::*;
 ///
 /// let mut result = Template::new();
-/// result.add_qr(QuickReply::new("빠른 응답", "빠른 응답 ㅋㅋ"));
+/// result.push_quick_reply(QuickReply::new("빠른 응답", "빠른 응답 ㅋㅋ"));
 ///
 /// let simple_text = SimpleText::new("심플 텍스트 테스트");
-/// result.add_output(simple_text.build());
+/// result.push_output(simple_text.build());
 ///
 /// ```
 pub struct SimpleText {
-    simple_text: SimpleTextContent,
+    pub(crate) simple_text: SimpleTextContent,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SimpleTextContent {
-    text: String,
+    pub(crate) text: String,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 impl SimpleText {
@@ -555,12 +1427,16 @@ impl SimpleText {
     #[inline]
     pub fn new<S: Into<String>>(_text: S) -> Self {
         SimpleText {
-            simple_text: SimpleTextContent { text: _text.into() },
+            simple_text: SimpleTextContent {
+                text: _text.into(),
+                unknown_fields: Map::new(),
+            },
+            unknown_fields: Map::new(),
         }
     }
 
-    pub fn set_text<S: Into<String>>(mut self, _text: S) -> Self {
-        self.simple_text.text = _text.into();
+    pub fn put_text<S: Into<String>>(&mut self, text: S) -> &mut Self {
+        self.simple_text.text = text.into();
         self
     }
 
@@ -571,11 +1447,24 @@ impl SimpleText {
     pub fn html(&self) -> String {
         self.simple_text.text.to_string()
     }
+
+    #[deprecated(since = "0.4.0", note = "with_text를 쓰세요")]
+    pub fn set_text<S: Into<String>>(self, text: S) -> Self {
+        self.with_text(text)
+    }
 }
 
+with!(SimpleText {
+    with_text => put_text(text: impl Into<String>);
+});
+
+getters!(SimpleText {
+    str text => simple_text.text;
+});
+
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
 /// [SimpleImage](https://i.kakao.com/docs/skill-response-format#simpleimage): imageUrl, altText
 ///
 /// # Examples
@@ -586,25 +1475,31 @@ impl SimpleText {
 /// use kakao_rs::prelude::*;
 ///
 /// let mut result = Template::new();
-/// result.add_qr(QuickReply::new("빠른 응답", "빠른 응답 ㅋㅋ"));
+/// result.push_quick_reply(QuickReply::new("빠른 응답", "빠른 응답 ㅋㅋ"));
 ///
 /// let simple_img = SimpleImage::new(
 ///     "http://k.kakaocdn.net/dn/83BvP/bl20duRC1Q1/lj3JUcmrzC53YIjNDkqbWK/i_6piz1p.jpg",
 ///     "보물상자입니다");
 ///
-/// result.add_output(simple_img.build());
+/// result.push_output(simple_img.build());
 ///
 /// ```
 pub struct SimpleImage {
-    simple_image: SimpleImageContent,
+    pub(crate) simple_image: SimpleImageContent,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
 pub struct SimpleImageContent {
-    image_url: String,
-    alt_text: String,
+    pub(crate) image_url: String,
+    pub(crate) alt_text: String,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 impl SimpleImage {
@@ -615,17 +1510,19 @@ impl SimpleImage {
             simple_image: SimpleImageContent {
                 image_url: _url.into(),
                 alt_text: _text.into(),
+                unknown_fields: Map::new(),
             },
+            unknown_fields: Map::new(),
         }
     }
 
-    pub fn set_image<S: Into<String>>(mut self, _link: S) -> Self {
-        self.simple_image.image_url = _link.into();
+    pub fn put_image<S: Into<String>>(&mut self, url: S) -> &mut Self {
+        self.simple_image.image_url = url.into();
         self
     }
 
-    pub fn set_text<S: Into<String>>(mut self, _text: S) -> Self {
-        self.simple_image.alt_text = _text.into();
+    pub fn put_text<S: Into<String>>(&mut self, text: S) -> &mut Self {
+        self.simple_image.alt_text = text.into();
         self
     }
 
@@ -636,6 +1533,356 @@ impl SimpleImage {
     pub fn html(&self) -> String {
         self.simple_image.image_url.to_string()
     }
+
+    #[deprecated(since = "0.4.0", note = "with_image를 쓰세요")]
+    pub fn set_image<S: Into<String>>(self, url: S) -> Self {
+        self.with_image(url)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_text를 쓰세요")]
+    pub fn set_text<S: Into<String>>(self, text: S) -> Self {
+        self.with_text(text)
+    }
 }
 
+with!(SimpleImage {
+    with_image => put_image(url: impl Into<String>);
+    with_text => put_text(text: impl Into<String>);
+});
+
+getters!(SimpleImage {
+    str image_url => simple_image.image_url;
+    str alt_text => simple_image.alt_text;
+});
+
 /***** Response *****/
+
+unknown_fields!(
+    Link,
+    ListItem,
+    QuickReply,
+    Title,
+    ThumbNail,
+    Template,
+    ContextControl,
+    ContextValue,
+    Outputs,
+    Carousel,
+    CarouselContent,
+    CarouselHeader,
+    ListCard,
+    ListCardContent,
+    SimpleText,
+    SimpleTextContent,
+    SimpleImage,
+    SimpleImageContent,
+);
+
+/***** Validate *****/
+impl Validate for Template {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        unknown(errors, path, &self.unknown_fields);
+        if let Some(context) = &self.context {
+            let context_path = join(path, "context");
+            unknown(errors, &context_path, &context.unknown_fields);
+            for (i, value) in context.values.iter().enumerate() {
+                let value_path = format!("{}[{}]", join(&context_path, "values"), i);
+                unknown(errors, &value_path, &value.unknown_fields);
+            }
+        }
+
+        let path = join(path, "template");
+        unknown(errors, &path, &self.template.unknown_fields);
+        let outputs = &self.template.outputs;
+        item_count(errors, &path, "outputs", outputs.len(), 1, limits::OUTPUTS);
+        outputs.validate_at(&join(&path, "outputs"), errors);
+
+        let quick_replies = &self.template.quick_replies;
+        item_count(
+            errors,
+            &path,
+            "quickReplies",
+            quick_replies.len(),
+            0,
+            limits::QUICK_REPLIES,
+        );
+        quick_replies.validate_at(&join(&path, "quickReplies"), errors);
+    }
+}
+
+impl Validate for Types {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        unknown(errors, path, self.wrapper_unknown_fields());
+
+        match self {
+            Types::List(list) => list.list_card.validate_at(&join(path, "listCard"), errors),
+            Types::Basic(basic) => basic.content.validate_at(&join(path, "basicCard"), errors),
+            Types::Commerce(commerce) => commerce
+                .content
+                .validate_at(&join(path, "commerceCard"), errors),
+            Types::Item(item) => item.content.validate_at(&join(path, "itemCard"), errors),
+            Types::SimpleTxt(text) => text.validate_at(&join(path, "simpleText"), errors),
+            Types::SimpleImg(image) => image.validate_at(&join(path, "simpleImage"), errors),
+            Types::Carousel(carousel) => carousel.validate_at(&join(path, "carousel"), errors),
+        }
+    }
+}
+
+impl Validate for QuickReply {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        unknown(errors, path, &self.unknown_fields);
+        required(errors, path, "label", &self.label);
+        max_chars(
+            errors,
+            path,
+            "label",
+            &self.label,
+            limits::QUICK_REPLY_LABEL,
+        );
+        match self.action.as_str() {
+            "message" => required(errors, path, "messageText", &self.message_text),
+            "block" => required(errors, path, "blockId", self.block_id().unwrap_or("")),
+            action => error(
+                errors,
+                join(path, "action"),
+                format!("알 수 없는 action `{}`", action),
+            ),
+        }
+    }
+}
+
+impl Validate for Link {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        unknown(errors, path, &self.unknown_fields);
+        if self.is_empty() {
+            error(
+                errors,
+                path.to_string(),
+                "pc, mobile, web, ios, android 중 하나는 필요합니다",
+            );
+        }
+        for (name, url) in self.targets() {
+            match name {
+                "ios" | "android" => format(errors, path, name, Some(url), check::check_app_url),
+                _ => format(errors, path, name, Some(url), check::check_url),
+            }
+        }
+    }
+}
+
+impl Validate for ListItem {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        unknown(errors, path, &self.unknown_fields);
+        if let Some(link) = &self.link {
+            link.validate_at(&join(path, "link"), errors);
+        }
+        required(errors, path, "title", &self.title);
+        max_chars(errors, path, "title", &self.title, limits::LIST_ITEM_TITLE);
+        format(
+            errors,
+            path,
+            "imageUrl",
+            self.image_url.as_deref(),
+            check::check_url,
+        );
+        if let Some(description) = &self.description {
+            max_chars(
+                errors,
+                path,
+                "description",
+                description,
+                limits::LIST_ITEM_DESCRIPTION,
+            );
+        }
+    }
+}
+
+impl Validate for ThumbNail {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        unknown(errors, path, &self.unknown_fields);
+        if let Some(link) = &self.link {
+            link.validate_at(&join(path, "link"), errors);
+        }
+        required(errors, path, "imageUrl", &self.image_url);
+        format(
+            errors,
+            path,
+            "imageUrl",
+            Some(&self.image_url),
+            check::check_url,
+        );
+    }
+}
+
+impl Validate for ListCardContent {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        self.validate_items(path, errors, limits::LIST_CARD_ITEMS);
+    }
+}
+
+impl ListCardContent {
+    /// 캐로셀 안에서는 items 제한이 다릅니다.
+    pub(crate) fn validate_items(
+        &self,
+        path: &str,
+        errors: &mut Vec<ValidationError>,
+        max_items: usize,
+    ) {
+        unknown(errors, path, &self.unknown_fields);
+        let header = join(path, "header");
+        unknown(errors, &header, &self.header.unknown_fields);
+        required(errors, &header, "title", &self.header.title);
+        max_chars(
+            errors,
+            &header,
+            "title",
+            &self.header.title,
+            limits::LIST_ITEM_TITLE,
+        );
+
+        item_count(errors, path, "items", self.items.len(), 1, max_items);
+        self.items.validate_at(&join(path, "items"), errors);
+
+        item_count(
+            errors,
+            path,
+            "buttons",
+            self.buttons.len(),
+            0,
+            limits::LIST_CARD_BUTTONS,
+        );
+        self.buttons.validate_at(&join(path, "buttons"), errors);
+    }
+}
+
+impl Validate for SimpleText {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        unknown(errors, path, &self.simple_text.unknown_fields);
+        let text = &self.simple_text.text;
+        required(errors, path, "text", text);
+        max_chars(errors, path, "text", text, limits::SIMPLE_TEXT);
+    }
+}
+
+impl Validate for SimpleImage {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        unknown(errors, path, &self.simple_image.unknown_fields);
+        required(errors, path, "imageUrl", &self.simple_image.image_url);
+        format(
+            errors,
+            path,
+            "imageUrl",
+            Some(&self.simple_image.image_url),
+            check::check_url,
+        );
+        required(errors, path, "altText", &self.simple_image.alt_text);
+    }
+}
+
+impl Validate for Carousel {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        let carousel = &self.carousel;
+        unknown(errors, path, &carousel.unknown_fields);
+        let max = match carousel.r#type.as_str() {
+            "basicCard" | "commerceCard" | "itemCard" => limits::CAROUSEL_ITEMS,
+            "listCard" => limits::CAROUSEL_LIST_CARDS,
+            other => {
+                error(
+                    errors,
+                    join(path, "type"),
+                    format!("알 수 없는 캐로셀 타입 `{}`", other),
+                );
+                limits::CAROUSEL_ITEMS
+            }
+        };
+        item_count(errors, path, "items", carousel.items.len(), 1, max);
+
+        for (i, card) in carousel.items.iter().enumerate() {
+            let item_path = format!("{}[{}]", join(path, "items"), i);
+            if card.id() != carousel.r#type {
+                error(
+                    errors,
+                    item_path.clone(),
+                    format!(
+                        "캐로셀 타입은 `{}`인데 `{}`입니다",
+                        carousel.r#type,
+                        card.id()
+                    ),
+                );
+            }
+            card.validate_at(&item_path, errors);
+        }
+
+        if let Some(header) = &carousel.header {
+            let header_path = join(path, "header");
+            unknown(errors, &header_path, &header.unknown_fields);
+            required(errors, &header_path, "title", &header.title);
+            header
+                .thumbnail
+                .validate_at(&join(&header_path, "thumbnail"), errors);
+        }
+    }
+}
+/***** Validate *****/
+
+/***** Fit *****/
+impl Fit for Template {
+    fn fit(&mut self) {
+        self.template.outputs.fit();
+        self.template.quick_replies.fit();
+    }
+}
+
+impl Fit for Types {
+    fn fit(&mut self) {
+        match self {
+            Types::List(list) => list.fit(),
+            Types::Basic(basic) => basic.fit(),
+            Types::Commerce(commerce) => commerce.fit(),
+            Types::Item(item) => item.fit(),
+            Types::SimpleTxt(text) => text.fit(),
+            Types::SimpleImg(_) => {}
+            Types::Carousel(carousel) => carousel.fit(),
+        }
+    }
+}
+
+impl Fit for QuickReply {
+    fn fit(&mut self) {
+        text::fit(&mut self.label, limits::QUICK_REPLY_LABEL);
+    }
+}
+
+impl Fit for ListItem {
+    fn fit(&mut self) {
+        text::fit(&mut self.title, limits::LIST_ITEM_TITLE);
+        text::fit_opt(&mut self.description, limits::LIST_ITEM_DESCRIPTION);
+    }
+}
+
+impl Fit for ListCardContent {
+    fn fit(&mut self) {
+        text::fit(&mut self.header.title, limits::LIST_ITEM_TITLE);
+        self.items.fit();
+        self.buttons.fit();
+    }
+}
+
+impl Fit for ListCard {
+    fn fit(&mut self) {
+        self.list_card.fit();
+    }
+}
+
+impl Fit for SimpleText {
+    fn fit(&mut self) {
+        text::fit(&mut self.simple_text.text, limits::SIMPLE_TEXT);
+    }
+}
+
+impl Fit for Carousel {
+    fn fit(&mut self) {
+        self.carousel.items.fit();
+    }
+}
+/***** Fit *****/
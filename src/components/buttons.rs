@@ -1,14 +1,20 @@
 //! Button
 //! struct Button, enum ButtonType
+use crate::check::{self, CheckError};
+use crate::limits;
+use crate::text::{self, Fit};
+use crate::url::{self, Place};
+use crate::validate::*;
+use serde::de::Error;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::{Map, Value};
 use std::collections::HashMap;
 
 #[derive(Serialize, Debug, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
-/// [Button](https://i.kakao.com/docs/skill-response-format#button): Call, Text, Link, Share
-/// ButtonType::Call, ButtonType::Text, ButtonType::Link, ButtonType::Share
+/// [Button](https://i.kakao.com/docs/skill-response-format#button): Call, Text, Link, Share, Block
+/// ButtonType::Call, ButtonType::Text, ButtonType::Link, ButtonType::Share, ButtonType::Block
 ///
 /// 라벨 지정은 필수입니다.
 ///
@@ -18,44 +24,57 @@ use std::collections::HashMap;
 ///
 /// let mut list_card = ListCard::new("리스트 카드 제목!"); // 제목
 ///
-/// list_card.add_button(Button::new(ButtonType::Text).set_label("그냥 텍스트 버튼")); // 메시지 버튼
+/// list_card.push_button(Button::new(ButtonType::Text).with_label("그냥 텍스트 버튼")); // 메시지 버튼
 ///
-/// list_card.add_button(
+/// list_card.push_button(
 ///   Button::new(ButtonType::Link)
-///     .set_label("link label")
-///     .set_link("https://google.com"),
+///     .with_label("link label")
+///     .with_link("https://google.com"),
 /// ); // 링크 버튼
 ///
-/// list_card.add_button(
+/// list_card.push_button(
 ///   Button::new(ButtonType::Share)
-///     .set_label("share label")
-///     .set_msg("카톡에 보이는 메시지"),
+///     .with_label("share label")
+///     .with_msg("카톡에 보이는 메시지"),
 /// ); // 공유 버튼
 ///
-/// list_card.add_button(
+/// list_card.push_button(
 ///   Button::new(ButtonType::Call)
-///     .set_label("call label")
-///     .set_number("010-1234-5678"),
+///     .with_label("call label")
+///     .with_number("010-1234-5678"),
 /// ); // 전화 버튼
 ///
 /// ```
 pub struct Button {
-    label: String,
-    action: String,
+    #[cfg_attr(feature = "schema", schemars(length(min = 1)))]
+    pub(crate) label: String,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(schema_with = "crate::schema::button_action")
+    )]
+    pub(crate) action: String,
     #[serde(skip_serializing_if = "Button::is_string_dead")]
-    phone_number: Option<String>,
+    pub(crate) phone_number: Option<String>,
     #[serde(skip_serializing_if = "Button::is_string_dead")]
-    web_link_url: Option<String>,
+    pub(crate) web_link_url: Option<String>,
     #[serde(skip_serializing_if = "Button::is_string_dead")]
-    message_text: Option<String>,
+    pub(crate) message_text: Option<String>,
+    #[serde(skip_serializing_if = "Button::is_string_dead")]
+    pub(crate) block_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) extra: Option<Map<String, Value>>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
-/// 버튼 타입 (Call, Share, Link, Text)
+/// 버튼 타입 (Call, Share, Link, Text, Block)
 pub enum ButtonType {
     Call,
     Share,
     Link,
     Text,
+    Block,
 }
 
 impl Button {
@@ -67,6 +86,7 @@ impl Button {
             ButtonType::Share => "share",
             ButtonType::Link => "webLink",
             ButtonType::Text => "message",
+            ButtonType::Block => "block",
         };
         Button {
             action: action.to_string(),
@@ -82,7 +102,8 @@ impl Button {
         }
     }
 
-    pub fn link<S: Into<String>>(label: S, url: S) -> Self {
+    /// `url`에는 [Url](crate::url::Url)도 넘길 수 있습니다.
+    pub fn link<L: Into<String>, U: Into<String>>(label: L, url: U) -> Self {
         Button {
             action: "webLink".into(),
             label: label.into(),
@@ -91,6 +112,31 @@ impl Button {
         }
     }
 
+    /// URL을 검사하는 [link](Self::link)
+    pub fn try_link<L: Into<String>, U: Into<String>>(
+        label: L,
+        url: U,
+    ) -> Result<Self, CheckError> {
+        let url = url.into();
+        check::check_url(&url)?;
+        Ok(Button::link(label, url))
+    }
+
+    /// 카카오맵 검색 결과를 여는 링크 버튼
+    pub fn kakao_map_search<S: Into<String>>(label: S, query: &str) -> Self {
+        Button::link(label, url::kakao_map_search(query))
+    }
+
+    /// 카카오맵 길찾기 링크 버튼: `from`이 없으면 현재 위치에서 출발
+    pub fn kakao_map_route<S: Into<String>>(label: S, from: Option<&Place>, to: &Place) -> Self {
+        Button::link(label, url::kakao_map_route(from, to))
+    }
+
+    /// 카카오톡 채널 1:1 채팅 링크 버튼
+    pub fn kakao_channel_chat<S: Into<String>>(label: S, channel_id: &str) -> Self {
+        Button::link(label, url::kakao_channel_chat(channel_id))
+    }
+
     pub fn share<S: Into<String>>(label: S) -> Self {
         Button {
             action: "share".into(),
@@ -99,6 +145,16 @@ impl Button {
         }
     }
 
+    /// 블록 연결 버튼: blockId로 지정한 블록을 호출합니다.
+    pub fn block<S: Into<String>>(label: S, block_id: S) -> Self {
+        Button {
+            action: "block".into(),
+            label: label.into(),
+            block_id: Some(block_id.into()),
+            ..Default::default()
+        }
+    }
+
     pub fn call<S: Into<String>>(label: S, number: S) -> Self {
         Button {
             action: "phone".into(),
@@ -108,34 +164,89 @@ impl Button {
         }
     }
 
-    pub fn set_number<S: Into<String>>(mut self, number: S) -> Self {
+    /// 번호를 검사해서 `010-1234-5678` 꼴로 바꾸는 [call](Self::call)
+    pub fn try_call<S: Into<String>>(label: S, number: S) -> Result<Self, CheckError> {
+        let number = check::normalize_phone(&number.into())?;
+        Ok(Button::call(label.into(), number))
+    }
+
+    pub fn put_number<S: Into<String>>(&mut self, number: S) -> &mut Self {
         self.phone_number = Some(number.into());
         self
     }
 
-    pub fn set_label<S: Into<String>>(mut self, label: S) -> Self {
+    pub fn put_label<S: Into<String>>(&mut self, label: S) -> &mut Self {
         self.label = label.into();
         self
     }
 
-    pub fn set_msg<S: Into<String>>(mut self, message: S) -> Self {
+    pub fn put_msg<S: Into<String>>(&mut self, message: S) -> &mut Self {
         self.message_text = Some(message.into());
         self
     }
 
-    pub fn set_link<S: Into<String>>(mut self, link: S) -> Self {
+    pub fn put_link<S: Into<String>>(&mut self, link: S) -> &mut Self {
         self.web_link_url = Some(link.into());
         self
     }
 
+    pub fn put_block_id<S: Into<String>>(&mut self, id: S) -> &mut Self {
+        self.block_id = Some(id.into());
+        self
+    }
+
+    /// 다음 스킬 요청의 action.clientExtra로 전달됩니다.
+    pub fn put_extra<S: Into<String>, V: Into<Value>>(&mut self, key: S, value: V) -> &mut Self {
+        self.extra
+            .get_or_insert_with(Map::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// 누르면 발화를 보내는 버튼인지: message, block
+    pub fn sends_message(&self) -> bool {
+        matches!(self.action.as_str(), "message" | "block")
+    }
+
     fn is_string_dead(field: &Option<String>) -> bool {
         match field {
             Some(s) => s.is_empty(),
             None => true,
         }
     }
+
+    #[deprecated(since = "0.4.0", note = "with_number를 쓰세요")]
+    pub fn set_number<S: Into<String>>(self, number: S) -> Self {
+        self.with_number(number)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_label을 쓰세요")]
+    pub fn set_label<S: Into<String>>(self, label: S) -> Self {
+        self.with_label(label)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_msg를 쓰세요")]
+    pub fn set_msg<S: Into<String>>(self, message: S) -> Self {
+        self.with_msg(message)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_link를 쓰세요")]
+    pub fn set_link<S: Into<String>>(self, link: S) -> Self {
+        self.with_link(link)
+    }
 }
 
+/// 알고 있는 필드: 나머지는 unknown_fields에 그대로 보관
+const BUTTON_FIELDS: [&str; 7] = [
+    "label",
+    "action",
+    "webLinkUrl",
+    "messageText",
+    "phoneNumber",
+    "blockId",
+    "extra",
+];
+
 impl<'de> Deserialize<'de> for Button {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -154,7 +265,14 @@ impl<'de> Deserialize<'de> for Button {
             Some("share") => Button::new(ButtonType::Share),
             Some("message") => Button::new(ButtonType::Text),
             Some("phone") => Button::new(ButtonType::Call),
-            _ => panic!("Unknown button type"),
+            Some("block") => Button::new(ButtonType::Block),
+            Some(action) => {
+                return Err(D::Error::custom(format!(
+                    "unknown button action `{}`",
+                    action
+                )))
+            }
+            None => return Err(D::Error::missing_field("action")),
         };
 
         if let Some(l) = keys.get("label") {
@@ -169,7 +287,75 @@ impl<'de> Deserialize<'de> for Button {
         if let Some(l) = keys.get("phoneNumber") {
             button.phone_number = Some(l.to_string());
         }
+        if let Some(l) = keys.get("blockId") {
+            button.block_id = Some(l.to_string());
+        }
+        if let Some(Value::Object(extra)) = text.get("extra") {
+            button.extra = Some(extra.clone());
+        }
+        for (key, value) in text {
+            if !BUTTON_FIELDS.contains(&key.as_str()) {
+                button.unknown_fields.insert(key, value);
+            }
+        }
 
         Ok(button)
     }
 }
+
+with!(Button {
+    with_number => put_number(number: impl Into<String>);
+    with_label => put_label(label: impl Into<String>);
+    with_msg => put_msg(message: impl Into<String>);
+    with_link => put_link(link: impl Into<String>);
+    with_block_id => put_block_id(id: impl Into<String>);
+    with_extra => put_extra(key: impl Into<String>, value: impl Into<Value>);
+});
+
+getters!(Button {
+    str label => label;
+    str action => action;
+    opt_str phone_number => phone_number;
+    opt_str web_link_url => web_link_url;
+    opt_str message_text => message_text;
+    opt_str block_id => block_id;
+    opt extra, extra_mut: Map<String, Value> => extra;
+});
+
+unknown_fields!(Button);
+
+impl Validate for Button {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        unknown(errors, path, &self.unknown_fields);
+        required(errors, path, "label", &self.label);
+        max_chars(errors, path, "label", &self.label, limits::BUTTON_LABEL);
+
+        let field = |value: &Option<String>| value.as_deref().unwrap_or("").to_string();
+        match self.action.as_str() {
+            "webLink" => required(errors, path, "webLinkUrl", &field(&self.web_link_url)),
+            "phone" => required(errors, path, "phoneNumber", &field(&self.phone_number)),
+            "block" => required(errors, path, "blockId", &field(&self.block_id)),
+            _ => {}
+        }
+        format(
+            errors,
+            path,
+            "webLinkUrl",
+            self.web_link_url.as_deref(),
+            check::check_url,
+        );
+        format(
+            errors,
+            path,
+            "phoneNumber",
+            self.phone_number.as_deref(),
+            |number| check::normalize_phone(number).map(drop),
+        );
+    }
+}
+
+impl Fit for Button {
+    fn fit(&mut self) {
+        text::fit(&mut self.label, limits::BUTTON_LABEL);
+    }
+}
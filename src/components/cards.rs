@@ -1,19 +1,129 @@
 use crate::components::basics::*;
 use crate::components::buttons::Button;
-use serde::{Deserialize, Serialize};
+use crate::limits;
+use crate::money::{Currency, Price};
+use crate::text::{self, Fit};
+use crate::validate::*;
+use serde::de::{DeserializeSeed, Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
 
 /***** Buttons *****/
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+// untagged: 모르는 필드도 받으므로 필수 필드가 많은 카드부터 시도합니다.
+// (ItemCard의 thumbnail만 보고 BasicCard로 읽지 않도록)
 pub enum Card {
-    Basic(BasicCardContent), // 144 bytes
     Commerce(CommerceCardContent),
     Item(ItemCardContent), // 360 bytes: Too big?
+    List(ListCardContent),
+    Basic(BasicCardContent), // 144 bytes
+}
+
+impl Card {
+    /// 캐로셀 type에 쓰이는 카드 종류: basicCard, commerceCard, itemCard, listCard
+    pub fn id(&self) -> &'static str {
+        match self {
+            Card::Basic(_) => "basicCard",
+            Card::Commerce(_) => "commerceCard",
+            Card::Item(_) => "itemCard",
+            Card::List(_) => "listCard",
+        }
+    }
+
+    /// 캐로셀 type에 맞는 카드로 읽습니다.
+    pub(crate) fn deserialize_as<'de, D>(card_type: &str, deserializer: D) -> Result<Card, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match card_type {
+            "basicCard" => BasicCardContent::deserialize(deserializer).map(Card::Basic),
+            "commerceCard" => CommerceCardContent::deserialize(deserializer).map(Card::Commerce),
+            "itemCard" => ItemCardContent::deserialize(deserializer).map(Card::Item),
+            "listCard" => ListCardContent::deserialize(deserializer).map(Card::List),
+            other => Err(D::Error::custom(format!(
+                "unknown carousel type `{}`, expected one of {}",
+                other,
+                CARD_TYPES.join(", ")
+            ))),
+        }
+    }
+
+    pub fn buttons(&self) -> &[Button] {
+        match self {
+            Card::Basic(basic) => &basic.buttons,
+            Card::Commerce(commerce) => &commerce.buttons,
+            Card::Item(item) => &item.buttons,
+            Card::List(list) => &list.buttons,
+        }
+    }
+
+    pub fn buttons_mut(&mut self) -> &mut Vec<Button> {
+        match self {
+            Card::Basic(basic) => &mut basic.buttons,
+            Card::Commerce(commerce) => &mut commerce.buttons,
+            Card::Item(item) => &mut item.buttons,
+            Card::List(list) => &mut list.buttons,
+        }
+    }
+}
+
+/// 캐로셀 type으로 쓸 수 있는 카드 종류
+pub(crate) const CARD_TYPES: [&str; 4] = ["basicCard", "commerceCard", "itemCard", "listCard"];
+
+/// 캐로셀 items: type을 알고 있으면 카드마다 시도하지 않고 바로 읽습니다.
+pub(crate) struct CardsSeed<'a>(pub(crate) &'a str);
+
+impl<'de> DeserializeSeed<'de> for CardsSeed<'_> {
+    type Value = Vec<Card>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Vec<Card>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for CardsSeed<'_> {
+    type Value = Vec<Card>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a list of {}", self.0)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Vec<Card>, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut cards =
+            Vec::with_capacity(seq.size_hint().unwrap_or(0).min(limits::CAROUSEL_ITEMS));
+        while let Some(card) = seq.next_element_seed(CardSeed(self.0))? {
+            cards.push(card);
+        }
+        Ok(cards)
+    }
+}
+
+struct CardSeed<'a>(&'a str);
+
+impl<'de> DeserializeSeed<'de> for CardSeed<'_> {
+    type Value = Card;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Card, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Card::deserialize_as(self.0, deserializer)
+    }
 }
 
 /***** BasicCard *****/
 #[derive(Serialize, Deserialize, Debug)]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 /// [BasicCard](https://i.kakao.com/docs/skill-response-format#basiccard): title, description, thumbnail, buttons
 ///
 /// # Examples
@@ -27,17 +137,20 @@ pub enum Card {
 /// let mut result = Template::new();
 ///
 /// let basic_card = BasicCard::new()
-///     .set_title("제목입니다.")
-///     .set_thumbnail(
+///     .with_title("제목입니다.")
+///     .with_thumbnail(
 ///         "http://k.kakaocdn.net/dn/APR96/btqqH7zLanY/kD5mIPX7TdD2NAxgP29cC0/1x1.jpg"
 ///     );
 ///
-/// result.add_output(basic_card.build());
+/// result.push_output(basic_card.build());
 ///
 /// ```
 pub struct BasicCard {
     #[serde(rename = "basicCard")]
     pub content: BasicCardContent,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 impl Default for BasicCard {
@@ -54,23 +167,11 @@ impl BasicCard {
                 description: None,
                 thumbnail: ThumbNail::new("".to_string()),
                 buttons: Vec::new(),
+                unknown_fields: Map::new(),
             },
+            unknown_fields: Map::new(),
         }
     }
-    pub fn add_button(mut self, btn: Button) -> Self {
-        self.content.buttons.push(btn);
-        self
-    }
-
-    pub fn set_desc<S: Into<String>>(mut self, desc: S) -> Self {
-        self.content.description = Some(desc.into());
-        self
-    }
-    pub fn set_thumbnail<S: Into<String>>(mut self, url: S) -> Self {
-        self.content.thumbnail.image_url = url.into();
-        self
-    }
-
     // 단독이면 build, Carousel은 build_card
     pub fn build(self) -> Types {
         Types::Basic(self)
@@ -80,31 +181,48 @@ impl BasicCard {
         Card::Basic(self.content)
     }
 
-    pub fn set_title<S: Into<String>>(mut self, title: S) -> Self {
+    pub fn put_title<S: Into<String>>(&mut self, title: S) -> &mut Self {
         self.content.title = Some(title.into());
         self
     }
 
-    pub fn set_description<S: Into<String>>(mut self, desc: S) -> Self {
+    pub fn put_description<S: Into<String>>(&mut self, desc: S) -> &mut Self {
         self.content.description = Some(desc.into());
         self
     }
-    pub fn set_link<S: Into<String>>(mut self, link: S) -> Self {
-        self.content.thumbnail.link = Some(Link { web: link.into() });
+
+    pub fn put_thumbnail<S: Into<String>>(&mut self, url: S) -> &mut Self {
+        self.content.thumbnail.put_image_url(url);
+        self
+    }
+
+    pub fn put_link<S: Into<String>>(&mut self, link: S) -> &mut Self {
+        self.content.thumbnail.put_link(link);
         self
     }
-    pub fn set_fixed_ratio(mut self, fixed: bool) -> Self {
-        self.content.thumbnail.fixed_ratio = fixed;
+
+    pub fn put_fixed_ratio(&mut self, fixed: bool) -> &mut Self {
+        self.content.thumbnail.put_fixed_ratio(fixed);
         self
     }
 
-    pub fn set_width(mut self, _width: i32) -> Self {
-        self.content.thumbnail.width = Some(_width);
+    pub fn put_width(&mut self, width: i32) -> &mut Self {
+        self.content.thumbnail.put_width(width);
         self
     }
 
-    pub fn set_height(mut self, _height: i32) -> Self {
-        self.content.thumbnail.height = Some(_height);
+    pub fn put_height(&mut self, height: i32) -> &mut Self {
+        self.content.thumbnail.put_height(height);
+        self
+    }
+
+    pub fn push_button(&mut self, btn: Button) -> &mut Self {
+        self.content.buttons.push(btn);
+        self
+    }
+
+    pub fn extend_buttons<I: IntoIterator<Item = Button>>(&mut self, buttons: I) -> &mut Self {
+        self.content.buttons.extend(buttons);
         self
     }
 
@@ -112,26 +230,104 @@ impl BasicCard {
     pub fn id() -> String {
         "basicCard".to_string()
     }
+
+    #[deprecated(since = "0.4.0", note = "with_button을 쓰세요")]
+    pub fn add_button(self, btn: Button) -> Self {
+        self.with_button(btn)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_description을 쓰세요")]
+    pub fn set_desc<S: Into<String>>(self, desc: S) -> Self {
+        self.with_description(desc)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_title을 쓰세요")]
+    pub fn set_title<S: Into<String>>(self, title: S) -> Self {
+        self.with_title(title)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_description을 쓰세요")]
+    pub fn set_description<S: Into<String>>(self, desc: S) -> Self {
+        self.with_description(desc)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_thumbnail을 쓰세요")]
+    pub fn set_thumbnail<S: Into<String>>(self, url: S) -> Self {
+        self.with_thumbnail(url)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_link를 쓰세요")]
+    pub fn set_link<S: Into<String>>(self, link: S) -> Self {
+        self.with_link(link)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_fixed_ratio를 쓰세요")]
+    pub fn set_fixed_ratio(self, fixed: bool) -> Self {
+        self.with_fixed_ratio(fixed)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_width를 쓰세요")]
+    pub fn set_width(self, width: i32) -> Self {
+        self.with_width(width)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_height를 쓰세요")]
+    pub fn set_height(self, height: i32) -> Self {
+        self.with_height(height)
+    }
 }
 
+with!(BasicCard {
+    with_title => put_title(title: impl Into<String>);
+    with_description => put_description(desc: impl Into<String>);
+    with_thumbnail => put_thumbnail(url: impl Into<String>);
+    with_link => put_link(link: impl Into<String>);
+    with_fixed_ratio => put_fixed_ratio(fixed: bool);
+    with_width => put_width(width: i32);
+    with_height => put_height(height: i32);
+    with_button => push_button(btn: Button);
+    with_buttons => extend_buttons(buttons: impl IntoIterator<Item = Button>);
+});
+
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
 pub struct BasicCardContent {
     #[serde(skip_serializing_if = "Option::is_none")]
-    title: Option<String>,
+    pub(crate) title: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    description: Option<String>,
-    thumbnail: ThumbNail, // 필수
+    pub(crate) description: Option<String>,
+    pub(crate) thumbnail: ThumbNail, // 필수
+    #[cfg_attr(
+        feature = "schema",
+        schemars(length(max = "limits::BASIC_CARD_BUTTONS"))
+    )]
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    buttons: Vec<Button>,
+    pub(crate) buttons: Vec<Button>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
+getters!(BasicCard {
+    opt_str title => content.title;
+    opt_str description => content.description;
+    val thumbnail, thumbnail_mut: ThumbNail => content.thumbnail;
+    vec buttons, buttons_mut: Button => content.buttons;
+});
+
+getters!(BasicCardContent {
+    opt_str title => title;
+    opt_str description => description;
+    val thumbnail, thumbnail_mut: ThumbNail => thumbnail;
+    vec buttons, buttons_mut: Button => buttons;
+});
+
 /***** BasicCard *****/
 
 /***** CommerceCard *****/
 #[derive(Serialize, Deserialize, Debug)]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 /// [CommerceCard](https://i.kakao.com/docs/skill-response-format#commercecard): description, price, currency, discount, discountRate, discountedPrice, thumbnails, buttons
 ///
 /// # Examples
@@ -145,18 +341,22 @@ pub struct BasicCardContent {
 /// let mut result = Template::new();
 ///
 /// let commerce_card = CommerceCard::new()
-///     .set_price(5000)
-///     .set_desc("1 DESC")
-///     .set_currency("WON")
-///     .set_thumbnail(
+///     .with_price(5000)
+///     .with_description("1 DESC")
+///     .with_currency("won")
+///     .with_discount_rate(10) // discountedPrice: 4500
+///     .with_thumbnail(
 ///         "http://k.kakaocdn.net/dn/APR96/btqqH7zLanY/kD5mIPX7TdD2NAxgP29cC0/1x1.jpg"
 ///     );
 ///
-/// result.add_output(commerce_card.build());
+/// result.push_output(commerce_card.build());
 /// ```
 pub struct CommerceCard {
     #[serde(rename = "commerceCard")]
     pub content: CommerceCardContent,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 impl Default for CommerceCard {
@@ -172,30 +372,18 @@ impl CommerceCard {
         CommerceCard {
             content: CommerceCardContent {
                 description: "".to_string(),
-                price: 0,
-                currency: "".to_string(),
+                price: Price::default(),
+                currency: Currency::Other(String::new()),
                 discount: None,
                 discount_rate: None,
                 discounted_price: None,
                 thumbnails: Vec::new(),
                 buttons: Vec::new(),
+                unknown_fields: Map::new(),
             },
+            unknown_fields: Map::new(),
         }
     }
-    pub fn add_button(mut self, btn: Button) -> Self {
-        self.content.buttons.push(btn);
-        self
-    }
-
-    pub fn set_desc<S: Into<String>>(mut self, desc: S) -> Self {
-        self.content.description = desc.into();
-        self
-    }
-    pub fn set_thumbnail<S: Into<String>>(mut self, url: S) -> Self {
-        self.content.thumbnails.push(ThumbNail::new(url.into()));
-        self
-    }
-
     #[inline]
     pub fn build(self) -> Types {
         Types::Commerce(self)
@@ -206,24 +394,83 @@ impl CommerceCard {
         Card::Commerce(self.content)
     }
 
-    pub fn set_price(mut self, price: i32) -> Self {
-        self.content.price = price;
+    pub fn put_description<S: Into<String>>(&mut self, desc: S) -> &mut Self {
+        self.content.description = desc.into();
         self
     }
 
-    pub fn set_currency<S: Into<String>>(mut self, currency: S) -> Self {
+    /// 커머스 카드는 썸네일 1개만 지원합니다. 이미 있으면 바꿉니다.
+    pub fn put_thumbnail<S: Into<String>>(&mut self, url: S) -> &mut Self {
+        self.content.thumbnails = vec![ThumbNail::new(url)];
+        self
+    }
+
+    /// 할인이 있으면 할인가도 다시 계산합니다.
+    pub fn put_price<P: Into<Price>>(&mut self, price: P) -> &mut Self {
+        let content = &mut self.content;
+        content.price = price.into();
+        if let Some(discount) = content.discount {
+            content.discounted_price = Some(content.price.apply_discount(discount));
+        } else if let Some(rate) = content.discount_rate {
+            content.discounted_price = Some(content.price.apply_rate(rate));
+        }
+        self
+    }
+
+    /// `"won"`만 [Currency::Won]이 됩니다.
+    pub fn put_currency<C: Into<Currency>>(&mut self, currency: C) -> &mut Self {
         self.content.currency = currency.into();
         self
     }
 
-    pub fn set_discount(mut self, discount: i32) -> Self {
-        self.content.discount = Some(discount);
+    /// 할인 금액: discountedPrice를 함께 계산하고 discountRate는 지웁니다.
+    pub fn put_discount<P: Into<Price>>(&mut self, discount: P) -> &mut Self {
+        let content = &mut self.content;
+        let discount = discount.into();
+        content.discount = Some(discount);
+        content.discount_rate = None;
+        content.discounted_price = Some(content.price.apply_discount(discount));
+        self
+    }
+
+    /// 할인율(%): discountedPrice를 함께 계산(원 단위 버림)하고 discount는 지웁니다.
+    pub fn put_discount_rate(&mut self, rate: u8) -> &mut Self {
+        let content = &mut self.content;
+        content.discount_rate = Some(rate);
+        content.discount = None;
+        content.discounted_price = Some(content.price.apply_rate(rate));
         self
     }
 
-    pub fn set_discount_rate_price(mut self, rate: i32, priced: i32) -> Self {
+    /// 할인가를 직접 정합니다. 할인율과 맞지 않으면 검사에서 걸립니다.
+    pub fn put_discount_rate_price<P: Into<Price>>(&mut self, rate: u8, priced: P) -> &mut Self {
         self.content.discount_rate = Some(rate);
-        self.content.discounted_price = Some(priced);
+        self.content.discount = None;
+        self.content.discounted_price = Some(priced.into());
+        self
+    }
+
+    /// [CommerceCardContent::sale_price]
+    pub fn sale_price(&self) -> Option<Price> {
+        self.content.sale_price()
+    }
+
+    /// [CommerceCardContent::price_text]
+    pub fn price_text(&self) -> String {
+        self.content.price_text()
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.content.currency
+    }
+
+    pub fn push_button(&mut self, btn: Button) -> &mut Self {
+        self.content.buttons.push(btn);
+        self
+    }
+
+    pub fn extend_buttons<I: IntoIterator<Item = Button>>(&mut self, buttons: I) -> &mut Self {
+        self.content.buttons.extend(buttons);
         self
     }
 
@@ -231,32 +478,149 @@ impl CommerceCard {
     pub fn id() -> String {
         "commerceCard".to_string()
     }
+
+    #[deprecated(since = "0.4.0", note = "with_button을 쓰세요")]
+    pub fn add_button(self, btn: Button) -> Self {
+        self.with_button(btn)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_description을 쓰세요")]
+    pub fn set_desc<S: Into<String>>(self, desc: S) -> Self {
+        self.with_description(desc)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_thumbnail을 쓰세요")]
+    pub fn set_thumbnail<S: Into<String>>(self, url: S) -> Self {
+        self.with_thumbnail(url)
+    }
+
+    /// 음수면 panic합니다.
+    #[deprecated(since = "0.4.0", note = "with_price를 쓰세요")]
+    pub fn set_price(self, price: i32) -> Self {
+        self.with_price(old_price("price", price))
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_currency를 쓰세요")]
+    pub fn set_currency<C: Into<Currency>>(self, currency: C) -> Self {
+        self.with_currency(currency)
+    }
+
+    /// 음수면 panic합니다.
+    #[deprecated(since = "0.4.0", note = "with_discount를 쓰세요")]
+    pub fn set_discount(self, discount: i32) -> Self {
+        self.with_discount(old_price("discount", discount))
+    }
+
+    /// 할인율이 0~100이 아니거나 할인가가 음수면 panic합니다.
+    #[deprecated(since = "0.4.0", note = "with_discount_rate_price를 쓰세요")]
+    pub fn set_discount_rate_price(self, rate: i32, priced: i32) -> Self {
+        let rate = u8::try_from(rate)
+            .ok()
+            .filter(|rate| *rate <= 100)
+            .unwrap_or_else(|| panic!("discountRate는 0~100이어야 합니다: {}", rate));
+        self.with_discount_rate_price(rate, old_price("discountedPrice", priced))
+    }
+}
+
+/// 0.3의 `i32` 가격: 음수는 고치지 않고 바로 알립니다.
+fn old_price(field: &str, value: i32) -> u32 {
+    u32::try_from(value).unwrap_or_else(|_| panic!("{}는 음수가 될 수 없습니다: {}", field, value))
 }
 
+with!(CommerceCard {
+    with_description => put_description(desc: impl Into<String>);
+    with_thumbnail => put_thumbnail(url: impl Into<String>);
+    with_price => put_price(price: impl Into<Price>);
+    with_currency => put_currency(currency: impl Into<Currency>);
+    with_discount => put_discount(discount: impl Into<Price>);
+    with_discount_rate => put_discount_rate(rate: u8);
+    with_discount_rate_price => put_discount_rate_price(rate: u8, priced: impl Into<Price>);
+    with_button => push_button(btn: Button);
+    with_buttons => extend_buttons(buttons: impl IntoIterator<Item = Button>);
+});
+
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
 pub struct CommerceCardContent {
-    description: String,
-    price: i32,
-    currency: String, // 필수
+    pub(crate) description: String,
+    pub(crate) price: Price,
+    #[cfg_attr(feature = "schema", schemars(schema_with = "crate::schema::currency"))]
+    pub(crate) currency: Currency, // 필수
     #[serde(skip_serializing_if = "Option::is_none")]
-    discount: Option<i32>,
+    pub(crate) discount: Option<Price>,
+    #[cfg_attr(feature = "schema", schemars(range(max = 100)))]
     #[serde(skip_serializing_if = "Option::is_none")]
-    discount_rate: Option<i32>,
+    pub(crate) discount_rate: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    discounted_price: Option<i32>,
+    pub(crate) discounted_price: Option<Price>,
+    #[cfg_attr(feature = "schema", schemars(length(equal = 1)))]
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    thumbnails: Vec<ThumbNail>, // 필수, 1개만 지원
+    pub(crate) thumbnails: Vec<ThumbNail>, // 필수, 1개만 지원
+    #[cfg_attr(
+        feature = "schema",
+        schemars(length(min = 1, max = "limits::COMMERCE_CARD_BUTTONS"))
+    )]
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    buttons: Vec<Button>, // 필수
+    pub(crate) buttons: Vec<Button>, // 필수
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
+}
+
+getters!(CommerceCard {
+    str description => content.description;
+    copy price: Price => content.price;
+    copy discount: Option<Price> => content.discount;
+    copy discount_rate: Option<u8> => content.discount_rate;
+    copy discounted_price: Option<Price> => content.discounted_price;
+    vec thumbnails, thumbnails_mut: ThumbNail => content.thumbnails;
+    vec buttons, buttons_mut: Button => content.buttons;
+});
+
+getters!(CommerceCardContent {
+    str description => description;
+    copy price: Price => price;
+    copy discount: Option<Price> => discount;
+    copy discount_rate: Option<u8> => discount_rate;
+    copy discounted_price: Option<Price> => discounted_price;
+    vec thumbnails, thumbnails_mut: ThumbNail => thumbnails;
+    vec buttons, buttons_mut: Button => buttons;
+});
+
+impl CommerceCardContent {
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    /// 할인가: discountedPrice, discount, discountRate 순으로 계산
+    pub fn sale_price(&self) -> Option<Price> {
+        self.discounted_price
+            .or_else(|| self.discount.map(|d| self.price.apply_discount(d)))
+            .or_else(|| self.discount_rate.map(|rate| self.price.apply_rate(rate)))
+    }
+
+    /// 텍스트 응답용 가격: "35,000원 → 29,750원 (15% 할인)", 할인이 없으면 "35,000원"
+    pub fn price_text(&self) -> String {
+        let price = self.currency.format(self.price);
+        match self.sale_price() {
+            Some(sale) => {
+                let rate = self
+                    .discount_rate
+                    .map(|rate| format!(" ({}% 할인)", rate))
+                    .unwrap_or_default();
+                format!("{} → {}{}", price, self.currency.format(sale), rate)
+            }
+            None => price,
+        }
+    }
 }
 
 /***** CommerceCard *****/
 
 /***** ItemCard *****/
 #[derive(Serialize, Deserialize, Debug)]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 /// [ItemCard](https://i.kakao.com/docs/skill-response-format#itemcard): thumbnail, head, imageTitle, itemList,
 ///     itemListAlignment, itemListSummary, title, description, buttons, buttonLayout
 ///
@@ -268,33 +632,37 @@ pub struct CommerceCardContent {
 /// use kakao_rs::components::basics::Template;
 /// use kakao_rs::components::cards::ItemCard;
 /// use kakao_rs::components::buttons::{Button, ButtonType};
+/// use kakao_rs::url::Url;
 ///
 /// let mut result = Template::new();
 ///
 /// let item_card = ItemCard::new()
-///     .set_title("title")
-///     .set_desc("desc")
-///     .set_thumbnail(
+///     .with_title("title")
+///     .with_description("desc")
+///     .with_thumbnail(
 ///         "http://dev-mk.kakao.com/dn/bot/scripts/with_barcode_blue_1x1.png"
 ///     )
-///     .set_thumbnail_width(800)
-///     .set_thumbnail_height(800)
-///     .set_image_title("DOFQTK")
-///     .set_image_desc("Boarding Number")
-///     .set_item_list_alignment("right")
-///     .set_item_list_summary("total", "$4,032.54")
-///     .add_button(Button::new(ButtonType::Link)
-///             .set_label("View Boarding Pass")
-///             .set_link("https://namu.wiki/w/%EB%82%98%EC%97%B0(TWICE)"),
+///     .with_thumbnail_width(800)
+///     .with_thumbnail_height(800)
+///     .with_image_title("DOFQTK")
+///     .with_image_desc("Boarding Number")
+///     .with_item_list_alignment("right")
+///     .with_item_list_summary("total", "$4,032.54")
+///     .with_button(Button::new(ButtonType::Link)
+///             .with_label("View Boarding Pass")
+///             .with_link(Url::new("https://namu.wiki").with_segments(["w", "나연(TWICE)"])),
 ///     )
-///     .set_button_layout("vertical");
+///     .with_button_layout("vertical");
 ///
-/// result.add_output(item_card.build());
+/// result.push_output(item_card.build());
 ///
 /// ```
 pub struct ItemCard {
     #[serde(rename = "itemCard")]
     pub content: ItemCardContent,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 impl Default for ItemCard {
@@ -319,7 +687,9 @@ impl ItemCard {
                 description: None,
                 buttons: Vec::new(),
                 button_layout: None,
+                unknown_fields: Map::new(),
             },
+            unknown_fields: Map::new(),
         }
     }
 
@@ -331,113 +701,269 @@ impl ItemCard {
         Card::Item(self.content)
     }
 
-    pub fn add_button(mut self, btn: Button) -> Self {
-        self.content.buttons.push(btn);
-        self
-    }
-    pub fn set_title<S: Into<String>>(mut self, _title: S) -> Self {
-        self.content.title = Some(_title.into());
+    pub fn put_title<S: Into<String>>(&mut self, title: S) -> &mut Self {
+        self.content.title = Some(title.into());
         self
     }
-    pub fn set_desc<S: Into<String>>(mut self, desc: S) -> Self {
+
+    pub fn put_description<S: Into<String>>(&mut self, desc: S) -> &mut Self {
         self.content.description = Some(desc.into());
         self
     }
-    pub fn set_button_layout<S: Into<String>>(mut self, layout: S) -> Self {
+
+    pub fn put_button_layout<S: Into<String>>(&mut self, layout: S) -> &mut Self {
         self.content.button_layout = Some(layout.into());
         self
     }
-    pub fn set_item_list_alignment<S: Into<String>>(mut self, align: S) -> Self {
+
+    pub fn put_item_list_alignment<S: Into<String>>(&mut self, align: S) -> &mut Self {
         self.content.item_list_alignment = Some(align.into());
         self
     }
-    pub fn set_item_list_summary<S: Into<String>>(mut self, _title: S, _desc: S) -> Self {
+
+    pub fn put_item_list_summary<T: Into<String>, D: Into<String>>(
+        &mut self,
+        title: T,
+        desc: D,
+    ) -> &mut Self {
         self.content.item_list_summary = Some(ItemListSummary {
-            title: _title.into(),
-            description: _desc.into(),
+            title: title.into(),
+            description: desc.into(),
+            unknown_fields: Map::new(),
         });
         self
     }
-    pub fn set_thumbnail<S: Into<String>>(mut self, url: S) -> Self {
+
+    pub fn put_thumbnail<S: Into<String>>(&mut self, url: S) -> &mut Self {
         self.content.thumbnail = Some(ThumbNail::new(url));
         self
     }
 
-    pub fn set_head<S: Into<String>>(mut self, _title: S) -> Self {
+    pub fn put_head<S: Into<String>>(&mut self, title: S) -> &mut Self {
         self.content.head = Some(Head {
-            title: _title.into(),
+            title: title.into(),
+            unknown_fields: Map::new(),
         });
         self
     }
 
-    pub fn set_image_title<S: Into<String>>(mut self, _title: S) -> Self {
-        self.content.image_title = Some(ImageTitle::new(_title.into()));
+    pub fn put_image_title<S: Into<String>>(&mut self, title: S) -> &mut Self {
+        self.content.image_title = Some(ImageTitle::new(title));
         self
     }
 
-    pub fn set_image_desc<S: Into<String>>(mut self, _desc: S) -> Self {
+    /// put_image_title(with_image_title) 다음에 사용하세요.
+    pub fn put_image_desc<S: Into<String>>(&mut self, desc: S) -> &mut Self {
         if let Some(image) = &mut self.content.image_title {
-            image.description = Some(_desc.into());
+            image.put_description(desc);
         }
-
         self
     }
 
-    pub fn set_image<S: Into<String>>(mut self, _url: S) -> Self {
+    /// put_image_title(with_image_title) 다음에 사용하세요.
+    pub fn put_image<S: Into<String>>(&mut self, url: S) -> &mut Self {
         if let Some(image) = &mut self.content.image_title {
-            image.image_url = Some(_url.into());
+            image.put_image(url);
         }
-
         self
     }
 
-    pub fn set_thumbnail_height(mut self, _height: i32) -> Self {
+    /// put_thumbnail(with_thumbnail) 다음에 사용하세요.
+    pub fn put_thumbnail_height(&mut self, height: i32) -> &mut Self {
         if let Some(thumb) = &mut self.content.thumbnail {
-            thumb.height = Some(_height);
+            thumb.put_height(height);
         }
         self
     }
 
-    pub fn set_thumbnail_width(mut self, _width: i32) -> Self {
+    /// put_thumbnail(with_thumbnail) 다음에 사용하세요.
+    pub fn put_thumbnail_width(&mut self, width: i32) -> &mut Self {
         if let Some(thumb) = &mut self.content.thumbnail {
-            thumb.width = Some(_width);
+            thumb.put_width(width);
         }
         self
     }
+
+    pub fn push_item(&mut self, item: ItemList) -> &mut Self {
+        self.content.item_list.push(item);
+        self
+    }
+
+    pub fn extend_items<I: IntoIterator<Item = ItemList>>(&mut self, items: I) -> &mut Self {
+        self.content.item_list.extend(items);
+        self
+    }
+
+    pub fn push_button(&mut self, btn: Button) -> &mut Self {
+        self.content.buttons.push(btn);
+        self
+    }
+
+    pub fn extend_buttons<I: IntoIterator<Item = Button>>(&mut self, buttons: I) -> &mut Self {
+        self.content.buttons.extend(buttons);
+        self
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_button을 쓰세요")]
+    pub fn add_button(self, btn: Button) -> Self {
+        self.with_button(btn)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_description을 쓰세요")]
+    pub fn set_desc<S: Into<String>>(self, desc: S) -> Self {
+        self.with_description(desc)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_title을 쓰세요")]
+    pub fn set_title<S: Into<String>>(self, title: S) -> Self {
+        self.with_title(title)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_button_layout을 쓰세요")]
+    pub fn set_button_layout<S: Into<String>>(self, layout: S) -> Self {
+        self.with_button_layout(layout)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_item_list_alignment를 쓰세요")]
+    pub fn set_item_list_alignment<S: Into<String>>(self, align: S) -> Self {
+        self.with_item_list_alignment(align)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_item_list_summary를 쓰세요")]
+    pub fn set_item_list_summary<T: Into<String>, D: Into<String>>(
+        self,
+        title: T,
+        desc: D,
+    ) -> Self {
+        self.with_item_list_summary(title, desc)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_thumbnail을 쓰세요")]
+    pub fn set_thumbnail<S: Into<String>>(self, url: S) -> Self {
+        self.with_thumbnail(url)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_head를 쓰세요")]
+    pub fn set_head<S: Into<String>>(self, title: S) -> Self {
+        self.with_head(title)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_image_title을 쓰세요")]
+    pub fn set_image_title<S: Into<String>>(self, title: S) -> Self {
+        self.with_image_title(title)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_image_desc를 쓰세요")]
+    pub fn set_image_desc<S: Into<String>>(self, desc: S) -> Self {
+        self.with_image_desc(desc)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_image를 쓰세요")]
+    pub fn set_image<S: Into<String>>(self, url: S) -> Self {
+        self.with_image(url)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_thumbnail_height를 쓰세요")]
+    pub fn set_thumbnail_height(self, height: i32) -> Self {
+        self.with_thumbnail_height(height)
+    }
+
+    #[deprecated(since = "0.4.0", note = "with_thumbnail_width를 쓰세요")]
+    pub fn set_thumbnail_width(self, width: i32) -> Self {
+        self.with_thumbnail_width(width)
+    }
 }
 
+with!(ItemCard {
+    with_title => put_title(title: impl Into<String>);
+    with_description => put_description(desc: impl Into<String>);
+    with_button_layout => put_button_layout(layout: impl Into<String>);
+    with_item_list_alignment => put_item_list_alignment(align: impl Into<String>);
+    with_item_list_summary => put_item_list_summary(title: impl Into<String>, desc: impl Into<String>);
+    with_thumbnail => put_thumbnail(url: impl Into<String>);
+    with_head => put_head(title: impl Into<String>);
+    with_image_title => put_image_title(title: impl Into<String>);
+    with_image_desc => put_image_desc(desc: impl Into<String>);
+    with_image => put_image(url: impl Into<String>);
+    with_thumbnail_height => put_thumbnail_height(height: i32);
+    with_thumbnail_width => put_thumbnail_width(width: i32);
+    with_item => push_item(item: ItemList);
+    with_items => extend_items(items: impl IntoIterator<Item = ItemList>);
+    with_button => push_button(btn: Button);
+    with_buttons => extend_buttons(buttons: impl IntoIterator<Item = Button>);
+});
+
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
 pub struct ItemCardContent {
     #[serde(skip_serializing_if = "Option::is_none")]
-    thumbnail: Option<ThumbNail>,
+    pub(crate) thumbnail: Option<ThumbNail>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    head: Option<Head>,
+    pub(crate) head: Option<Head>,
     // Profile 현재 미지원
     #[serde(skip_serializing_if = "Option::is_none")]
-    image_title: Option<ImageTitle>,
-    item_list: Vec<ItemList>,
+    pub(crate) image_title: Option<ImageTitle>,
+    #[cfg_attr(feature = "schema", schemars(length(max = "limits::ITEM_CARD_ITEMS")))]
+    pub(crate) item_list: Vec<ItemList>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    item_list_alignment: Option<String>,
+    pub(crate) item_list_alignment: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    item_list_summary: Option<ItemListSummary>,
+    pub(crate) item_list_summary: Option<ItemListSummary>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    title: Option<String>,
+    pub(crate) title: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    description: Option<String>,
+    pub(crate) description: Option<String>,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(length(max = "limits::ITEM_CARD_BUTTONS"))
+    )]
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    buttons: Vec<Button>,
+    pub(crate) buttons: Vec<Button>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    button_layout: Option<String>,
+    pub(crate) button_layout: Option<String>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
+getters!(ItemCard {
+    opt thumbnail, thumbnail_mut: ThumbNail => content.thumbnail;
+    opt head, head_mut: Head => content.head;
+    opt image_title, image_title_mut: ImageTitle => content.image_title;
+    vec items, items_mut: ItemList => content.item_list;
+    opt_str item_list_alignment => content.item_list_alignment;
+    opt item_list_summary, item_list_summary_mut: ItemListSummary => content.item_list_summary;
+    opt_str title => content.title;
+    opt_str description => content.description;
+    vec buttons, buttons_mut: Button => content.buttons;
+    opt_str button_layout => content.button_layout;
+});
+
+getters!(ItemCardContent {
+    opt thumbnail, thumbnail_mut: ThumbNail => thumbnail;
+    opt head, head_mut: Head => head;
+    opt image_title, image_title_mut: ImageTitle => image_title;
+    vec items, items_mut: ItemList => item_list;
+    opt_str item_list_alignment => item_list_alignment;
+    opt item_list_summary, item_list_summary_mut: ItemListSummary => item_list_summary;
+    opt_str title => title;
+    opt_str description => description;
+    vec buttons, buttons_mut: Button => buttons;
+    opt_str button_layout => button_layout;
+});
+
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Head {
-    title: String,
+    pub(crate) title: String,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ImageTitle {
     pub title: String,
@@ -445,6 +971,9 @@ pub struct ImageTitle {
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_url: Option<String>,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
 
 impl ImageTitle {
@@ -455,19 +984,38 @@ impl ImageTitle {
             title: _title.into(),
             description: None,
             image_url: None,
+            unknown_fields: Map::new(),
         }
     }
 
-    pub fn set_title<S: Into<String>>(&mut self, title: S) {
+    pub fn put_title<S: Into<String>>(&mut self, title: S) -> &mut Self {
         self.title = title.into();
+        self
     }
 
-    pub fn set_desc<S: Into<String>>(&mut self, desc: S) {
+    pub fn put_description<S: Into<String>>(&mut self, desc: S) -> &mut Self {
         self.description = Some(desc.into());
+        self
     }
 
-    pub fn set_image<S: Into<String>>(&mut self, url: S) {
+    pub fn put_image<S: Into<String>>(&mut self, url: S) -> &mut Self {
         self.image_url = Some(url.into());
+        self
+    }
+
+    #[deprecated(since = "0.4.0", note = "put_title을 쓰세요")]
+    pub fn set_title<S: Into<String>>(&mut self, title: S) {
+        self.put_title(title);
+    }
+
+    #[deprecated(since = "0.4.0", note = "put_description을 쓰세요")]
+    pub fn set_desc<S: Into<String>>(&mut self, desc: S) {
+        self.put_description(desc);
+    }
+
+    #[deprecated(since = "0.4.0", note = "put_image를 쓰세요")]
+    pub fn set_image<S: Into<String>>(&mut self, url: S) {
+        self.put_image(url);
     }
 
     #[inline]
@@ -477,14 +1025,316 @@ impl ImageTitle {
 }
 
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ItemList {
-    title: String,
-    description: String,
+    pub(crate) title: String,
+    pub(crate) description: String,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
+}
+
+impl ItemList {
+    /// ItemList(제목, 내용)
+    #[inline]
+    pub fn new<S: Into<String>>(title: S, desc: S) -> Self {
+        ItemList {
+            title: title.into(),
+            description: desc.into(),
+            unknown_fields: Map::new(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ItemListSummary {
-    title: String,
-    description: String,
+    pub(crate) title: String,
+    pub(crate) description: String,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub(crate) unknown_fields: Map<String, Value>,
 }
+
+getters!(Head {
+    str title => title;
+});
+
+getters!(ItemList {
+    str title => title;
+    str description => description;
+});
+
+getters!(ItemListSummary {
+    str title => title;
+    str description => description;
+});
 /***** ItemCard *****/
+
+with!(ImageTitle {
+    with_title => put_title(title: impl Into<String>);
+    with_description => put_description(desc: impl Into<String>);
+    with_image => put_image(url: impl Into<String>);
+});
+
+unknown_fields!(
+    BasicCard,
+    BasicCardContent,
+    CommerceCard,
+    CommerceCardContent,
+    ItemCard,
+    ItemCardContent,
+    Head,
+    ImageTitle,
+    ItemList,
+    ItemListSummary,
+);
+
+/***** Validate *****/
+impl Validate for Card {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        match self {
+            Card::Basic(basic) => basic.validate_at(path, errors),
+            Card::Commerce(commerce) => commerce.validate_at(path, errors),
+            Card::Item(item) => item.validate_at(path, errors),
+            Card::List(list) => list.validate_items(path, errors, limits::CAROUSEL_LIST_CARD_ITEMS),
+        }
+    }
+}
+
+impl Validate for BasicCardContent {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        unknown(errors, path, &self.unknown_fields);
+        if let Some(title) = &self.title {
+            max_chars(errors, path, "title", title, limits::BASIC_CARD_TITLE);
+        }
+        if let Some(description) = &self.description {
+            max_chars(
+                errors,
+                path,
+                "description",
+                description,
+                limits::BASIC_CARD_DESCRIPTION,
+            );
+        }
+        self.thumbnail.validate_at(&join(path, "thumbnail"), errors);
+        item_count(
+            errors,
+            path,
+            "buttons",
+            self.buttons.len(),
+            0,
+            limits::BASIC_CARD_BUTTONS,
+        );
+        self.buttons.validate_at(&join(path, "buttons"), errors);
+    }
+}
+
+impl Validate for CommerceCardContent {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        unknown(errors, path, &self.unknown_fields);
+        required(errors, path, "description", &self.description);
+        max_chars(
+            errors,
+            path,
+            "description",
+            &self.description,
+            limits::COMMERCE_CARD_DESCRIPTION,
+        );
+        match &self.currency {
+            Currency::Won => {}
+            Currency::Other(currency) if currency.trim().is_empty() => {
+                required(errors, path, "currency", currency)
+            }
+            Currency::Other(currency) => error(
+                errors,
+                join(path, "currency"),
+                format!("지원하지 않는 통화입니다 (won만 가능): `{}`", currency),
+            ),
+        }
+        self.validate_discount(path, errors);
+        item_count(errors, path, "thumbnails", self.thumbnails.len(), 1, 1);
+        self.thumbnails
+            .validate_at(&join(path, "thumbnails"), errors);
+        item_count(
+            errors,
+            path,
+            "buttons",
+            self.buttons.len(),
+            1,
+            limits::COMMERCE_CARD_BUTTONS,
+        );
+        self.buttons.validate_at(&join(path, "buttons"), errors);
+    }
+}
+
+impl CommerceCardContent {
+    /// 할인 금액/할인율/할인가가 가격, 서로와 맞는지 확인합니다.
+    fn validate_discount(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        let price = self.price;
+        if let Some(rate) = self.discount_rate {
+            if rate > 100 {
+                error(
+                    errors,
+                    join(path, "discountRate"),
+                    format!("0 ~ 100%만 가능합니다 (현재 {}%)", rate),
+                );
+            }
+        }
+        if let Some(discount) = self.discount {
+            if discount > price {
+                error(
+                    errors,
+                    join(path, "discount"),
+                    format!("가격({})보다 클 수 없습니다 (현재 {})", price, discount),
+                );
+            }
+        }
+
+        let field = join(path, "discountedPrice");
+        let discounted = match self.discounted_price {
+            Some(discounted) => discounted,
+            None => {
+                if self.discount_rate.is_some() {
+                    error(errors, field, "discountRate가 있으면 필수입니다");
+                }
+                return;
+            }
+        };
+        if discounted > price {
+            error(
+                errors,
+                field,
+                format!("가격({})보다 클 수 없습니다 (현재 {})", price, discounted),
+            );
+        } else if let Some(discount) = self.discount.filter(|&d| d <= price) {
+            let expected = price.apply_discount(discount);
+            if discounted != expected {
+                error(
+                    errors,
+                    field,
+                    format!(
+                        "가격 - 할인 금액({})과 다릅니다 (현재 {})",
+                        expected, discounted
+                    ),
+                );
+            }
+        } else if let Some(rate) = self.discount_rate.filter(|&rate| rate <= 100) {
+            // 반올림/올림한 할인가도 받습니다.
+            let exact = u64::from(price.get()) * u64::from(100 - rate);
+            let given = u64::from(discounted.get()) * 100;
+            if exact.abs_diff(given) >= 100 {
+                error(
+                    errors,
+                    field,
+                    format!(
+                        "할인율 {}%를 적용한 가격({})과 다릅니다 (현재 {})",
+                        rate,
+                        price.apply_rate(rate),
+                        discounted
+                    ),
+                );
+            }
+        }
+    }
+}
+
+impl Validate for ItemCardContent {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        unknown(errors, path, &self.unknown_fields);
+        if let Some(thumbnail) = &self.thumbnail {
+            thumbnail.validate_at(&join(path, "thumbnail"), errors);
+        }
+        if let Some(head) = &self.head {
+            unknown(errors, &join(path, "head"), &head.unknown_fields);
+        }
+        if let Some(image_title) = &self.image_title {
+            unknown(
+                errors,
+                &join(path, "imageTitle"),
+                &image_title.unknown_fields,
+            );
+        }
+        for (i, item) in self.item_list.iter().enumerate() {
+            let item_path = format!("{}[{}]", join(path, "itemList"), i);
+            unknown(errors, &item_path, &item.unknown_fields);
+        }
+        if let Some(summary) = &self.item_list_summary {
+            unknown(
+                errors,
+                &join(path, "itemListSummary"),
+                &summary.unknown_fields,
+            );
+        }
+        item_count(
+            errors,
+            path,
+            "itemList",
+            self.item_list.len(),
+            0,
+            limits::ITEM_CARD_ITEMS,
+        );
+        item_count(
+            errors,
+            path,
+            "buttons",
+            self.buttons.len(),
+            0,
+            limits::ITEM_CARD_BUTTONS,
+        );
+        self.buttons.validate_at(&join(path, "buttons"), errors);
+    }
+}
+/***** Validate *****/
+
+/***** Fit *****/
+impl Fit for Card {
+    fn fit(&mut self) {
+        match self {
+            Card::Basic(basic) => basic.fit(),
+            Card::Commerce(commerce) => commerce.fit(),
+            Card::Item(item) => item.fit(),
+            Card::List(list) => list.fit(),
+        }
+    }
+}
+
+impl Fit for BasicCardContent {
+    fn fit(&mut self) {
+        text::fit_opt(&mut self.title, limits::BASIC_CARD_TITLE);
+        text::fit_opt(&mut self.description, limits::BASIC_CARD_DESCRIPTION);
+        self.buttons.fit();
+    }
+}
+
+impl Fit for CommerceCardContent {
+    fn fit(&mut self) {
+        text::fit(&mut self.description, limits::COMMERCE_CARD_DESCRIPTION);
+        self.buttons.fit();
+    }
+}
+
+impl Fit for ItemCardContent {
+    fn fit(&mut self) {
+        self.buttons.fit();
+    }
+}
+
+impl Fit for BasicCard {
+    fn fit(&mut self) {
+        self.content.fit();
+    }
+}
+
+impl Fit for CommerceCard {
+    fn fit(&mut self) {
+        self.content.fit();
+    }
+}
+
+impl Fit for ItemCard {
+    fn fit(&mut self) {
+        self.content.fit();
+    }
+}
+/***** Fit *****/
@@ -1,5 +1,143 @@
 //! # kakao-rs
 //! All components
+//!
+//! 모든 컴포넌트는 이 크레이트가 모르는 필드(`forwardable`, `profile` 등)도 받아서
+//! 직렬화할 때 그대로 다시 씁니다. 확인은 `unknown_fields()`,
+//! 검사는 [`Validate::validate_strict`](crate::validate::Validate::validate_strict)로 합니다.
+//!
+//! ### 빌더 규칙
+//!
+//! 모든 컴포넌트가 같은 이름 규칙을 따릅니다.
+//!
+//! | | 제자리 수정 (`&mut self`) | 체이닝 (`self`) |
+//! |---|---|---|
+//! | 필드 | `put_title` | `with_title` |
+//! | 목록에 하나 추가 | `push_button` | `with_button` |
+//! | 목록에 여러 개 추가 | `extend_buttons` | `with_buttons` |
+//!
+//! `set_*`는 0.3 코드가 빌드되도록 남겨 둔 deprecated 메서드뿐입니다.
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//!
+//! let card = BasicCard::new()
+//!     .with_title("공지")
+//!     .with_thumbnail("https://ajou.ac.kr/notice.png")
+//!     .with_buttons(vec![Button::text("더보기"), Button::link("열기", "https://ajou.ac.kr")]);
+//!
+//! let mut list_card = ListCard::new("공지");
+//! list_card.extend_items((1..=3).map(|i| ListItem::new(format!("{}번 공지", i))));
+//! list_card.push_button(Button::text("더보기")).put_header("전체 공지");
+//! ```
+//!
+//! ### 읽기와 고치기
+//!
+//! 필드는 같은 이름의 getter(`title()`, `buttons()`)로 읽고,
+//! 목록과 하위 컴포넌트는 `_mut` 접근자(`buttons_mut()`, `thumbnail_mut()`)로 고칩니다.
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//!
+//! let json = r#"{"version":"2.0","template":{"outputs":[{"simpleText":{"text":"공지"}}],"quickReplies":[{"action":"message","label":"다음","messageText":"다음"}]}}"#;
+//! let mut result: Template = serde_json::from_str(json).unwrap();
+//!
+//! assert_eq!(result.quick_replies()[0].label(), "다음");
+//! for qr in result.quick_replies_mut() {
+//!     qr.put_extra("from", "notice");
+//! }
+//! if let Types::SimpleTxt(text) = &mut result.outputs_mut()[0] {
+//!     text.put_text("오늘 공지");
+//! }
+//! ```
+
+/// 모르는 필드 getter
+macro_rules! unknown_fields {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl $ty {
+                /// 이 크레이트가 모르는 필드 (직렬화할 때 그대로 다시 씁니다)
+                pub fn unknown_fields(&self) -> &serde_json::Map<String, serde_json::Value> {
+                    &self.unknown_fields
+                }
+            }
+        )*
+    };
+}
+
+/// 제자리 수정 `set_*`, `push_*`, `extend_*`로 체이닝용 `with_*`를 만듭니다.
+macro_rules! with {
+    ($ty:ty { $($with:ident => $set:ident($($arg:ident: $arg_ty:ty),*);)* }) => {
+        impl $ty {
+            $(
+                #[doc = concat!("[`", stringify!($set), "`](Self::", stringify!($set), ")의 체이닝 버전")]
+                #[must_use]
+                pub fn $with(mut self, $($arg: $arg_ty),*) -> Self {
+                    self.$set($($arg),*);
+                    self
+                }
+            )*
+        }
+    };
+}
+
+/// 읽기용 getter와 목록/하위 컴포넌트의 `_mut` 접근자를 만듭니다.
+///
+/// `str`: `&str`, `opt_str`: `Option<&str>`, `copy`: 값 복사,
+/// `opt`: `Option<&T>`, `val`: `&T`, `vec`: `&[T]`. `opt`, `val`, `vec`은 `_mut` 이름을 함께 받습니다.
+macro_rules! getters {
+    ($ty:ty { $($kind:ident $name:ident $(, $name_mut:ident)? $(: $t:ty)? => $($path:ident).+;)* }) => {
+        impl $ty {
+            $(getters!(@$kind $name $(, $name_mut)? $(: $t)? => $($path).+);)*
+        }
+    };
+    (@str $name:ident => $($path:ident).+) => {
+        pub fn $name(&self) -> &str {
+            &self.$($path).+
+        }
+    };
+    (@opt_str $name:ident => $($path:ident).+) => {
+        pub fn $name(&self) -> Option<&str> {
+            self.$($path).+.as_deref()
+        }
+    };
+    (@copy $name:ident: $t:ty => $($path:ident).+) => {
+        pub fn $name(&self) -> $t {
+            self.$($path).+
+        }
+    };
+    (@opt $name:ident: $t:ty => $($path:ident).+) => {
+        pub fn $name(&self) -> Option<&$t> {
+            self.$($path).+.as_ref()
+        }
+    };
+    (@opt $name:ident, $name_mut:ident: $t:ty => $($path:ident).+) => {
+        getters!(@opt $name: $t => $($path).+);
+
+        pub fn $name_mut(&mut self) -> Option<&mut $t> {
+            self.$($path).+.as_mut()
+        }
+    };
+    (@val $name:ident, $name_mut:ident: $t:ty => $($path:ident).+) => {
+        pub fn $name(&self) -> &$t {
+            &self.$($path).+
+        }
+
+        pub fn $name_mut(&mut self) -> &mut $t {
+            &mut self.$($path).+
+        }
+    };
+    (@vec $name:ident, $name_mut:ident: $t:ty => $($path:ident).+) => {
+        pub fn $name(&self) -> &[$t] {
+            &self.$($path).+
+        }
+
+        pub fn $name_mut(&mut self) -> &mut Vec<$t> {
+            &mut self.$($path).+
+        }
+    };
+}
+
 pub mod basics;
 pub mod buttons;
 pub mod cards;
+pub mod typed;
@@ -0,0 +1,277 @@
+//! # kakao-rs
+//! 필수 필드를 다 채워야 `build()`할 수 있는 빌더
+//!
+//! 카카오가 거절하는 카드(썸네일 없는 BasicCard, 가격/통화 없는 CommerceCard, 아이템 없는 ListCard)를
+//! 컴파일할 때 막습니다. 필수 필드를 채우면 빌더의 타입이 [Missing]에서 [Set]으로 바뀌고,
+//! 모두 [Set]일 때만 `build()`, `build_card()`가 있습니다.
+//! 기존 `BasicCard::new()` 등은 그대로 쓸 수 있습니다.
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//!
+//! let card = BasicCard::builder()
+//!     .with_title("공지")
+//!     .with_thumbnail("https://ajou.ac.kr/notice.png")
+//!     .build();
+//!
+//! let mut list_card = ListCard::builder("공지").with_item(ListItem::new("1번 공지"));
+//! for i in 2..=3 {
+//!     list_card = list_card.with_item(ListItem::new(format!("{}번 공지", i)));
+//! }
+//! let list_card = list_card.build();
+//! ```
+//!
+//! 썸네일이 없으면 컴파일되지 않습니다.
+//!
+//! ```compile_fail
+//! use kakao_rs::prelude::*;
+//!
+//! let card = BasicCard::builder().with_title("공지").build();
+//! ```
+//!
+//! ```compile_fail
+//! use kakao_rs::prelude::*;
+//!
+//! let card = ListCard::builder("공지").build();
+//! ```
+//!
+//! ```compile_fail
+//! use kakao_rs::prelude::*;
+//!
+//! // 버튼이 없음
+//! let card = CommerceCard::builder("후드티")
+//!     .with_thumbnail("https://ajou.ac.kr/hood.png")
+//!     .with_price(35000, "won")
+//!     .build();
+//! ```
+use crate::components::basics::*;
+use crate::components::buttons::Button;
+use crate::components::cards::*;
+use crate::money::{Currency, Price};
+use std::marker::PhantomData;
+
+/// 아직 채우지 않은 필수 필드
+#[derive(Debug)]
+pub struct Missing;
+
+/// 채운 필수 필드
+#[derive(Debug)]
+pub struct Set;
+
+/***** BasicCard *****/
+/// [BasicCard::builder]: 썸네일(`T`)이 필수
+#[derive(Debug)]
+pub struct BasicCardBuilder<T> {
+    card: BasicCard,
+    thumbnail: PhantomData<T>,
+}
+
+impl BasicCard {
+    /// 썸네일을 정해야 build할 수 있는 빌더
+    pub fn builder() -> BasicCardBuilder<Missing> {
+        BasicCardBuilder {
+            card: BasicCard::new(),
+            thumbnail: PhantomData,
+        }
+    }
+}
+
+impl<T> BasicCardBuilder<T> {
+    pub fn with_thumbnail<S: Into<String>>(self, url: S) -> BasicCardBuilder<Set> {
+        BasicCardBuilder {
+            card: self.card.with_thumbnail(url),
+            thumbnail: PhantomData,
+        }
+    }
+
+    pub fn with_title<S: Into<String>>(mut self, title: S) -> Self {
+        self.card.put_title(title);
+        self
+    }
+
+    pub fn with_description<S: Into<String>>(mut self, desc: S) -> Self {
+        self.card.put_description(desc);
+        self
+    }
+
+    pub fn with_link<S: Into<String>>(mut self, link: S) -> Self {
+        self.card.put_link(link);
+        self
+    }
+
+    pub fn with_fixed_ratio(mut self, fixed: bool) -> Self {
+        self.card.put_fixed_ratio(fixed);
+        self
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.card.put_width(width);
+        self
+    }
+
+    pub fn with_height(mut self, height: i32) -> Self {
+        self.card.put_height(height);
+        self
+    }
+
+    pub fn with_button(mut self, btn: Button) -> Self {
+        self.card.push_button(btn);
+        self
+    }
+
+    pub fn with_buttons(mut self, buttons: impl IntoIterator<Item = Button>) -> Self {
+        self.card.extend_buttons(buttons);
+        self
+    }
+}
+
+impl BasicCardBuilder<Set> {
+    pub fn build(self) -> Types {
+        self.card.build()
+    }
+
+    /// Carousel에 추가할 때 사용하세요.
+    pub fn build_card(self) -> Card {
+        self.card.build_card()
+    }
+
+    /// 기존 빌더로 바꿉니다.
+    pub fn into_card(self) -> BasicCard {
+        self.card
+    }
+}
+/***** BasicCard *****/
+
+/***** CommerceCard *****/
+/// [CommerceCard::builder]: 썸네일(`T`), 가격과 통화(`P`), 버튼 1개 이상(`B`)이 필수
+#[derive(Debug)]
+pub struct CommerceCardBuilder<T, P, B> {
+    card: CommerceCard,
+    state: PhantomData<(T, P, B)>,
+}
+
+impl CommerceCard {
+    /// 썸네일, 가격과 통화, 버튼을 정해야 build할 수 있는 빌더
+    pub fn builder<S: Into<String>>(desc: S) -> CommerceCardBuilder<Missing, Missing, Missing> {
+        CommerceCardBuilder {
+            card: CommerceCard::new().with_description(desc),
+            state: PhantomData,
+        }
+    }
+}
+
+impl<T, P, B> CommerceCardBuilder<T, P, B> {
+    /// 커머스 카드는 썸네일 1개만 지원합니다. 다시 부르면 바꿉니다.
+    pub fn with_thumbnail<S: Into<String>>(self, url: S) -> CommerceCardBuilder<Set, P, B> {
+        CommerceCardBuilder {
+            card: self.card.with_thumbnail(url),
+            state: PhantomData,
+        }
+    }
+
+    /// 가격과 통화(won)를 함께 정합니다.
+    pub fn with_price<V: Into<Price>, C: Into<Currency>>(
+        self,
+        price: V,
+        currency: C,
+    ) -> CommerceCardBuilder<T, Set, B> {
+        CommerceCardBuilder {
+            card: self.card.with_price(price).with_currency(currency),
+            state: PhantomData,
+        }
+    }
+
+    pub fn with_button(self, btn: Button) -> CommerceCardBuilder<T, P, Set> {
+        CommerceCardBuilder {
+            card: self.card.with_button(btn),
+            state: PhantomData,
+        }
+    }
+
+    pub fn with_discount<V: Into<Price>>(mut self, discount: V) -> Self {
+        self.card.put_discount(discount);
+        self
+    }
+
+    pub fn with_discount_rate(mut self, rate: u8) -> Self {
+        self.card.put_discount_rate(rate);
+        self
+    }
+
+    pub fn with_discount_rate_price<V: Into<Price>>(mut self, rate: u8, priced: V) -> Self {
+        self.card.put_discount_rate_price(rate, priced);
+        self
+    }
+}
+
+impl CommerceCardBuilder<Set, Set, Set> {
+    pub fn build(self) -> Types {
+        self.card.build()
+    }
+
+    /// Carousel에 추가할 때 사용하세요.
+    pub fn build_card(self) -> Card {
+        self.card.build_card()
+    }
+
+    /// 기존 빌더로 바꿉니다.
+    pub fn into_card(self) -> CommerceCard {
+        self.card
+    }
+}
+/***** CommerceCard *****/
+
+/***** ListCard *****/
+/// [ListCard::builder]: 아이템 1개 이상(`I`)이 필수
+#[derive(Debug)]
+pub struct ListCardBuilder<I> {
+    card: ListCard,
+    items: PhantomData<I>,
+}
+
+impl ListCard {
+    /// 아이템을 1개 이상 넣어야 build할 수 있는 빌더
+    pub fn builder<S: Into<String>>(header: S) -> ListCardBuilder<Missing> {
+        ListCardBuilder {
+            card: ListCard::new(header),
+            items: PhantomData,
+        }
+    }
+}
+
+impl<I> ListCardBuilder<I> {
+    pub fn with_item(mut self, item: ListItem) -> ListCardBuilder<Set> {
+        self.card.push_item(item);
+        ListCardBuilder {
+            card: self.card,
+            items: PhantomData,
+        }
+    }
+
+    pub fn with_button(mut self, button: Button) -> Self {
+        self.card.push_button(button);
+        self
+    }
+
+    pub fn with_buttons(mut self, buttons: impl IntoIterator<Item = Button>) -> Self {
+        self.card.extend_buttons(buttons);
+        self
+    }
+}
+
+impl ListCardBuilder<Set> {
+    pub fn build(self) -> Types {
+        self.card.build()
+    }
+
+    /// Carousel에 추가할 때 사용하세요. (캐로셀 안에서는 items 4개까지)
+    pub fn build_card(self) -> Card {
+        self.card.build_card()
+    }
+
+    /// 기존 빌더로 바꿉니다.
+    pub fn into_card(self) -> ListCard {
+        self.card
+    }
+}
+/***** ListCard *****/
@@ -0,0 +1,875 @@
+//! # kakao-rs
+//! 파일로 정의한 응답 템플릿 (`engine` feature)
+//!
+//! 배포 없이 문구를 바꿀 수 있도록 응답을 JSON, YAML, TOML 파일로 정의합니다.
+//! 파일 모양은 응답 JSON과 같고(`template`, `version`은 생략 가능), 문자열 안의 `{{변수}}`와
+//! 목록 안의 `$each` 반복을 [Registry::render]에 넘긴 값으로 채워 [Template]을 만듭니다.
+//!
+//! ```yaml
+//! # templates/notice.yaml
+//! outputs:
+//!   - listCard:
+//!       header: { title: "{{major}} 공지" }
+//!       items:
+//!         - $each: notices        # 목록 변수
+//!           $as: notice           # 반복 변수 이름 (기본값 item), {{$index}}는 1부터
+//!           $item:
+//!             title: "{{$index}}. {{notice.title}}"
+//!             link: { web: "{{notice.url}}" }
+//! quickReplies:
+//!   - { label: 처음으로, action: message, messageText: 처음으로 }
+//! ```
+//!
+//! 문자열 전체가 `"{{price}}"` 하나이면 숫자, 불리언도 그대로 들어갑니다.
+//! 변수가 없으면 [EngineError::MissingVariable], 결과가 카카오 제한에 맞지 않으면 [EngineError::Invalid]입니다.
+//!
+//! ```
+//! use kakao_rs::engine::{Format, Registry};
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize)]
+//! struct Greeting<'a> {
+//!     name: &'a str,
+//! }
+//!
+//! let mut registry = Registry::new();
+//! registry
+//!     .add("greeting", r#"{"outputs":[{"simpleText":{"text":"{{name}}님 안녕하세요"}}]}"#, Format::Json)
+//!     .unwrap();
+//!
+//! let result = registry.render("greeting", &Greeting { name: "아주" }).unwrap();
+//! assert_eq!(
+//!     result.to_string(),
+//!     r#"{"template":{"outputs":[{"simpleText":{"text":"아주님 안녕하세요"}}]},"version":"2.0"}"#
+//! );
+//! ```
+use crate::components::basics::Template;
+use crate::validate::{Validate, ValidationError};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
+
+const EACH: &str = "$each";
+const AS: &str = "$as";
+const ITEM: &str = "$item";
+const INDEX: &str = "$index";
+
+/// 템플릿 파일 형식
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Format {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl Format {
+    /// 확장자로 찾습니다: json, yaml, yml, toml
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Format> {
+        match path.as_ref().extension()?.to_str()? {
+            "json" => Some(Format::Json),
+            "yaml" | "yml" => Some(Format::Yaml),
+            "toml" => Some(Format::Toml),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn parse(self, text: &str) -> Result<Value, String> {
+        match self {
+            Format::Json => serde_json::from_str(text).map_err(|e| e.to_string()),
+            Format::Yaml => serde_yaml::from_str(text).map_err(|e| e.to_string()),
+            Format::Toml => toml::from_str(text).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// 템플릿을 읽거나 그릴 때 생긴 오류
+#[derive(Debug)]
+pub enum EngineError {
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    /// 파일 형식 오류
+    Parse {
+        name: String,
+        message: String,
+    },
+    /// `{{`, `$each` 등 템플릿 문법 오류
+    Syntax {
+        name: String,
+        path: String,
+        message: String,
+    },
+    NotFound(String),
+    MissingVariable {
+        name: String,
+        path: String,
+        variable: String,
+    },
+    NotAList {
+        name: String,
+        path: String,
+        variable: String,
+    },
+    /// 넘긴 값을 JSON으로 바꿀 수 없을 때
+    Context(String),
+    /// 결과가 응답 형식이 아닐 때
+    Deserialize {
+        name: String,
+        message: String,
+    },
+    /// 결과가 카카오 제한에 맞지 않을 때
+    Invalid {
+        name: String,
+        errors: Vec<ValidationError>,
+    },
+}
+
+impl fmt::Display for EngineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EngineError::Io { path, error } => {
+                write!(f, "{}: 읽기 실패: {}", path.display(), error)
+            }
+            EngineError::Parse { name, message } => write!(f, "{}: {}", name, message),
+            EngineError::Syntax {
+                name,
+                path,
+                message,
+            } => write!(f, "{}: {}: {}", name, path, message),
+            EngineError::NotFound(name) => write!(f, "`{}` 템플릿이 없습니다", name),
+            EngineError::MissingVariable {
+                name,
+                path,
+                variable,
+            } => write!(f, "{}: {}: 변수가 없습니다: `{}`", name, path, variable),
+            EngineError::NotAList {
+                name,
+                path,
+                variable,
+            } => write!(f, "{}: {}: 목록이 아닙니다: `{}`", name, path, variable),
+            EngineError::Context(message) => {
+                write!(f, "값을 JSON으로 바꿀 수 없습니다: {}", message)
+            }
+            EngineError::Deserialize { name, message } => write!(f, "{}: {}", name, message),
+            EngineError::Invalid { name, errors } => {
+                write!(f, "{}: 카카오 제한에 맞지 않습니다", name)?;
+                for error in errors {
+                    write!(f, "\n  {}", error)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for EngineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EngineError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// 이름(파일 이름) → 템플릿 정의
+#[derive(Debug, Default, Clone)]
+pub struct Registry {
+    templates: HashMap<String, Value>,
+}
+
+impl Registry {
+    #[inline]
+    pub fn new() -> Self {
+        Registry {
+            templates: HashMap::new(),
+        }
+    }
+
+    /// 폴더의 json, yaml, toml 파일을 모두 읽습니다. 이름은 확장자를 뺀 파일 이름입니다.
+    pub fn from_dir<P: AsRef<Path>>(dir: P) -> Result<Registry, EngineError> {
+        let dir = dir.as_ref();
+        let io_error = |error| EngineError::Io {
+            path: dir.to_path_buf(),
+            error,
+        };
+        let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+            .map_err(io_error)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<_, _>>()
+            .map_err(io_error)?;
+        paths.sort();
+
+        let mut registry = Registry::new();
+        for path in paths {
+            if path.is_file() && Format::from_path(&path).is_some() {
+                registry.load_file(&path)?;
+            }
+        }
+        Ok(registry)
+    }
+
+    /// 파일 하나를 읽습니다. 같은 이름이 있으면 바꿉니다.
+    pub fn load_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), EngineError> {
+        let path = path.as_ref();
+        let name = stem(path);
+        let format = Format::from_path(path).ok_or_else(|| EngineError::Parse {
+            name: name.to_string(),
+            message: "json, yaml, toml 파일만 읽을 수 있습니다".to_string(),
+        })?;
+        let text = fs::read_to_string(path).map_err(|error| EngineError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        self.add(name, &text, format)
+    }
+
+    /// 문자열로 템플릿을 추가합니다. 같은 이름이 있으면 바꿉니다.
+    pub fn add<S: Into<String>>(
+        &mut self,
+        name: S,
+        text: &str,
+        format: Format,
+    ) -> Result<(), EngineError> {
+        let name = name.into();
+        let body = format.parse(text).map_err(|message| EngineError::Parse {
+            name: name.clone(),
+            message,
+        })?;
+        let body = wrap(body).map_err(|message| EngineError::Parse {
+            name: name.clone(),
+            message: message.to_string(),
+        })?;
+        check(&body, "", false).map_err(|(path, message)| EngineError::Syntax {
+            name: name.clone(),
+            path,
+            message,
+        })?;
+        self.templates.insert(name, body);
+        Ok(())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.templates.contains_key(name)
+    }
+
+    /// 등록된 템플릿 이름 (정렬)
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.templates.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    pub fn len(&self) -> usize {
+        self.templates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.templates.is_empty()
+    }
+
+    /// 변수를 채워 응답을 만들고 카카오 제한을 검사합니다.
+    pub fn render<C: Serialize + ?Sized>(
+        &self,
+        name: &str,
+        context: &C,
+    ) -> Result<Template, EngineError> {
+        render_body(name, self.body(name)?, context)
+    }
+
+    pub(crate) fn body(&self, name: &str) -> Result<&Value, EngineError> {
+        self.templates
+            .get(name)
+            .ok_or_else(|| EngineError::NotFound(name.to_string()))
+    }
+
+    /// (이름, 정의), 이름 순
+    pub(crate) fn bodies(&self) -> Vec<(&str, &Value)> {
+        let mut bodies: Vec<(&str, &Value)> = self
+            .templates
+            .iter()
+            .map(|(name, body)| (name.as_str(), body))
+            .collect();
+        bodies.sort_unstable_by_key(|&(name, _)| name);
+        bodies
+    }
+}
+
+/// 정의에 변수를 채워 응답을 만들고 카카오 제한을 검사합니다.
+pub(crate) fn render_body<C: Serialize + ?Sized>(
+    name: &str,
+    body: &Value,
+    context: &C,
+) -> Result<Template, EngineError> {
+    let root = serde_json::to_value(context).map_err(|e| EngineError::Context(e.to_string()))?;
+
+    let mut scope = Scope {
+        root: &root,
+        vars: Vec::new(),
+    };
+    let value = scope
+        .render(body, "")
+        .map_err(|error| error.into_engine(name))?;
+
+    let template: Template =
+        serde_path_to_error::deserialize(value).map_err(|e| EngineError::Deserialize {
+            name: name.to_string(),
+            message: format!("{}: {}", e.path(), e.inner()),
+        })?;
+    template.validate().map_err(|errors| EngineError::Invalid {
+        name: name.to_string(),
+        errors,
+    })?;
+    Ok(template)
+}
+
+/***** HotRegistry *****/
+/// 파일 변경 표시: (수정 시각, 크기)
+type Stamp = (Option<SystemTime>, u64);
+
+/// 폴더를 지켜보다가 바뀐 파일만 다시 읽는 [Registry]
+///
+/// 바뀐 템플릿은 다시 읽고 검사한 뒤 한 번에 바꿉니다. 새 파일에 오류가 있으면 그 템플릿은 이전 버전을 계속 씁니다.
+/// 검사는 변수를 채우지 않은 응답 모양(키 오타, 빠진 필수 필드 등)을 항상 보고,
+/// [put_sample](HotRegistry::put_sample)로 예시 값을 주면 그 값으로 그려서 카카오 제한까지 검사합니다.
+///
+/// ```no_run
+/// use kakao_rs::engine::HotRegistry;
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// let registry = Arc::new(HotRegistry::open("templates").unwrap());
+/// registry.watch(Duration::from_secs(2), |error| eprintln!("{}", error));
+///
+/// // 요청마다
+/// let result = registry.render("notice", &serde_json::json!({ "major": "sw", "notices": [] }));
+/// ```
+#[derive(Debug)]
+pub struct HotRegistry {
+    dir: PathBuf,
+    active: RwLock<Arc<Registry>>,
+    stamps: Mutex<HashMap<PathBuf, Stamp>>,
+    samples: HashMap<String, Value>,
+}
+
+impl HotRegistry {
+    /// 폴더를 처음 읽습니다. 이때는 파일 하나라도 오류가 있으면 실패합니다.
+    pub fn open<P: AsRef<Path>>(dir: P) -> Result<HotRegistry, EngineError> {
+        let dir = dir.as_ref().to_path_buf();
+        let stamps = scan(&dir)?;
+        let registry = Registry::from_dir(&dir)?;
+        for (name, body) in registry.bodies() {
+            check_shape(name, body)?;
+        }
+        Ok(HotRegistry {
+            dir,
+            active: RwLock::new(Arc::new(registry)),
+            stamps: Mutex::new(stamps),
+            samples: HashMap::new(),
+        })
+    }
+
+    /// 다시 읽을 때 `name` 템플릿을 `sample`로 그려서 검사합니다. 지금 버전도 바로 검사합니다.
+    pub fn put_sample<C: Serialize + ?Sized>(
+        &mut self,
+        name: &str,
+        sample: &C,
+    ) -> Result<&mut Self, EngineError> {
+        let sample =
+            serde_json::to_value(sample).map_err(|e| EngineError::Context(e.to_string()))?;
+        self.current().render(name, &sample)?;
+        self.samples.insert(name.to_string(), sample);
+        Ok(self)
+    }
+
+    /// [`put_sample`](Self::put_sample)의 체이닝 버전
+    pub fn with_sample<C: Serialize + ?Sized>(
+        mut self,
+        name: &str,
+        sample: &C,
+    ) -> Result<Self, EngineError> {
+        self.put_sample(name, sample)?;
+        Ok(self)
+    }
+
+    /// 지금 쓰는 템플릿 묶음. 받은 뒤에 다시 읽어도 바뀌지 않습니다.
+    pub fn current(&self) -> Arc<Registry> {
+        self.active
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// [Registry::render]와 같습니다.
+    pub fn render<C: Serialize + ?Sized>(
+        &self,
+        name: &str,
+        context: &C,
+    ) -> Result<Template, EngineError> {
+        self.current().render(name, context)
+    }
+
+    /// 바뀐 파일이 있으면 다시 읽습니다. 바뀐 것이 있으면 `Ok(true)`입니다.
+    ///
+    /// 오류가 난 파일은 이전 버전을 그대로 두고 `Err`로 알려주며, 다른 파일의 변경은 적용됩니다.
+    /// 오류가 난 파일은 다시 바뀔 때까지 읽지 않습니다.
+    pub fn reload(&self) -> Result<bool, Vec<EngineError>> {
+        let mut stamps = self.stamps.lock().unwrap_or_else(PoisonError::into_inner);
+        let scanned = scan(&self.dir).map_err(|error| vec![error])?;
+        if scanned == *stamps {
+            return Ok(false);
+        }
+
+        let mut next = Registry::clone(&self.current());
+        let mut changed = false;
+        let mut errors = Vec::new();
+        for path in stamps.keys().filter(|path| !scanned.contains_key(*path)) {
+            changed |= next.templates.remove(stem(path)).is_some();
+        }
+        let mut paths: Vec<&PathBuf> = scanned
+            .iter()
+            .filter(|&(path, stamp)| stamps.get(path) != Some(stamp))
+            .map(|(path, _)| path)
+            .collect();
+        paths.sort();
+        for path in paths {
+            match self.load(path) {
+                Ok((name, body)) => {
+                    next.templates.insert(name, body);
+                    changed = true;
+                }
+                Err(error) => errors.push(error),
+            }
+        }
+
+        *stamps = scanned;
+        if changed {
+            *self.active.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(next);
+        }
+        if errors.is_empty() {
+            Ok(changed)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// `interval`마다 [reload](HotRegistry::reload)하는 스레드를 띄웁니다.
+    /// 오류는 `on_error`로 받고, `HotRegistry`를 모두 버리면 스레드도 끝납니다.
+    pub fn watch<F>(self: &Arc<Self>, interval: Duration, mut on_error: F) -> JoinHandle<()>
+    where
+        F: FnMut(&EngineError) + Send + 'static,
+    {
+        let registry = Arc::downgrade(self);
+        thread::spawn(move || loop {
+            thread::sleep(interval);
+            let registry = match registry.upgrade() {
+                Some(registry) => registry,
+                None => return,
+            };
+            if let Err(errors) = registry.reload() {
+                errors.iter().for_each(&mut on_error);
+            }
+        })
+    }
+
+    /// 파일 하나를 따로 읽고 응답 모양과 예시 값으로 검사합니다.
+    fn load(&self, path: &Path) -> Result<(String, Value), EngineError> {
+        let mut registry = Registry::new();
+        registry.load_file(path)?;
+        let name = stem(path);
+        check_shape(name, registry.body(name)?)?;
+        if let Some(sample) = self.samples.get(name) {
+            registry.render(name, sample)?;
+        }
+        let body = registry.templates.remove(name).unwrap_or_default();
+        Ok((name.to_string(), body))
+    }
+}
+
+fn stem(path: &Path) -> &str {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default()
+}
+
+/// 변수를 채우지 않고 응답 모양을 검사합니다.
+///
+/// `$each`는 항목 하나로 펼치고 `{{변수}}`는 `x`로 바꿔서 글자 수 제한에 걸리지 않게 합니다.
+/// `"{{price}}"`처럼 변수 하나뿐인 값은 타입을 모르므로 문자열, 숫자, 불리언 순으로 넣어 보고,
+/// 모두 맞지 않으면 그 부분은 값에 따라 달라지므로 검사하지 않습니다.
+fn check_shape(name: &str, body: &Value) -> Result<(), EngineError> {
+    let guesses = [Value::from(1), Value::Bool(true)];
+    let mut holes = Vec::new();
+    let mut value = skeleton(body, "", "", &mut holes);
+    let mut tried: HashMap<String, usize> = HashMap::new();
+    let template: Template = loop {
+        let error = match serde_path_to_error::deserialize(value.clone()) {
+            Ok(template) => break template,
+            Err(error) => error,
+        };
+        let at = error.path().to_string();
+        let at = at.trim_start_matches('.');
+        // 변수 자리에서 난 오류만 다른 타입으로 다시 해 봅니다.
+        let (path, pointer) = match holes.iter().find(|(path, _)| path == at) {
+            Some(hole) => hole,
+            None => {
+                return Err(EngineError::Deserialize {
+                    name: name.to_string(),
+                    message: format!("{}: {}", at, error.inner()),
+                })
+            }
+        };
+        let count = tried.entry(path.clone()).or_default();
+        let guess = match guesses.get(*count) {
+            Some(guess) => guess.clone(),
+            None => return Ok(()),
+        };
+        *count += 1;
+        if let Some(slot) = value.pointer_mut(pointer) {
+            *slot = guess;
+        }
+    };
+    // 변수 자리는 `x`로 채웠으므로 URL/전화번호 형식은 그릴 때 검사합니다.
+    let errors: Vec<_> = match template.validate() {
+        Ok(()) => return Ok(()),
+        Err(errors) => errors
+            .into_iter()
+            .filter(|e| !e.is_format_error())
+            .collect(),
+    };
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(EngineError::Invalid {
+            name: name.to_string(),
+            errors,
+        })
+    }
+}
+
+/// [check_shape]용 응답: 변수 하나뿐인 값의 (경로, JSON pointer)를 `holes`에 모읍니다.
+fn skeleton(value: &Value, path: &str, pointer: &str, holes: &mut Vec<(String, String)>) -> Value {
+    match value {
+        Value::String(text) => match pieces(text) {
+            Ok(pieces) => {
+                if let [Piece::Var(_)] = pieces.as_slice() {
+                    holes.push((path.to_string(), pointer.to_string()));
+                }
+                let text: String = pieces
+                    .iter()
+                    .map(|piece| match piece {
+                        Piece::Text(text) => text,
+                        Piece::Var(_) => "x",
+                    })
+                    .collect();
+                Value::String(text)
+            }
+            Err(_) => value.clone(),
+        },
+        Value::Array(values) => {
+            let mut out = Vec::with_capacity(values.len());
+            for value in values {
+                let value = match value {
+                    Value::Object(map) => match each(map) {
+                        Ok(Some(each)) => each.item,
+                        _ => value,
+                    },
+                    _ => value,
+                };
+                let path = format!("{}[{}]", path, out.len());
+                let pointer = format!("{}/{}", pointer, out.len());
+                out.push(skeleton(value, &path, &pointer, holes));
+            }
+            Value::Array(out)
+        }
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| {
+                    let pointer =
+                        format!("{}/{}", pointer, key.replace('~', "~0").replace('/', "~1"));
+                    (
+                        key.clone(),
+                        skeleton(value, &join(path, key), &pointer, holes),
+                    )
+                })
+                .collect(),
+        ),
+        value => value.clone(),
+    }
+}
+
+/// 폴더의 템플릿 파일과 변경 표시
+fn scan(dir: &Path) -> Result<HashMap<PathBuf, Stamp>, EngineError> {
+    let io_error = |error| EngineError::Io {
+        path: dir.to_path_buf(),
+        error,
+    };
+    let mut stamps = HashMap::new();
+    for entry in fs::read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if Format::from_path(&path).is_none() {
+            continue;
+        }
+        // 그 사이 지워졌거나 폴더이면 건너뜁니다.
+        match fs::metadata(&path) {
+            Ok(metadata) if metadata.is_file() => {
+                stamps.insert(path, (metadata.modified().ok(), metadata.len()));
+            }
+            _ => {}
+        }
+    }
+    Ok(stamps)
+}
+/***** HotRegistry *****/
+
+/// `template`을 생략한 파일은 감싸고, `version`이 없으면 2.0을 넣습니다.
+fn wrap(body: Value) -> Result<Value, &'static str> {
+    let mut body = match body {
+        Value::Object(body) if body.contains_key("template") => body,
+        Value::Object(body) => {
+            let mut wrapped = Map::new();
+            wrapped.insert("template".to_string(), Value::Object(body));
+            wrapped
+        }
+        _ => return Err("템플릿은 객체여야 합니다"),
+    };
+    body.entry("version")
+        .or_insert_with(|| Value::String("2.0".to_string()));
+    Ok(Value::Object(body))
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// 문자열 조각: 글자 또는 `{{변수}}`
+enum Piece<'a> {
+    Text(&'a str),
+    Var(&'a str),
+}
+
+fn pieces(text: &str) -> Result<Vec<Piece<'_>>, String> {
+    let mut pieces = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        if start > 0 {
+            pieces.push(Piece::Text(&rest[..start]));
+        }
+        let end = rest[start..]
+            .find("}}")
+            .ok_or_else(|| "닫히지 않은 `{{`".to_string())?;
+        let var = rest[start + 2..start + end].trim();
+        if var.is_empty() {
+            return Err("빈 변수 `{{}}`".to_string());
+        }
+        pieces.push(Piece::Var(var));
+        rest = &rest[start + end + 2..];
+    }
+    if !rest.is_empty() {
+        pieces.push(Piece::Text(rest));
+    }
+    Ok(pieces)
+}
+
+/// 반복: `{"$each": "목록", "$as": "이름", "$item": {...}}`
+struct Each<'a> {
+    list: &'a str,
+    name: &'a str,
+    item: &'a Value,
+}
+
+fn each(map: &Map<String, Value>) -> Result<Option<Each<'_>>, String> {
+    let list = match map.get(EACH) {
+        Some(Value::String(list)) => list.trim(),
+        Some(_) => return Err("`$each`에는 목록 변수 이름을 쓰세요".to_string()),
+        None => return Ok(None),
+    };
+    let name = match map.get(AS) {
+        Some(Value::String(name)) => name.trim(),
+        Some(_) => return Err("`$as`에는 반복 변수 이름을 쓰세요".to_string()),
+        None => "item",
+    };
+    let item = map
+        .get(ITEM)
+        .ok_or_else(|| "`$each`에는 `$item`이 필요합니다".to_string())?;
+    if let Some(key) = map
+        .keys()
+        .find(|key| ![EACH, AS, ITEM].contains(&key.as_str()))
+    {
+        return Err(format!("`$each`와 함께 쓸 수 없는 키 `{}`", key));
+    }
+    Ok(Some(Each { list, name, item }))
+}
+
+/// 읽을 때 문법을 미리 검사합니다.
+fn check(value: &Value, path: &str, in_list: bool) -> Result<(), (String, String)> {
+    match value {
+        Value::String(text) => pieces(text).map(|_| ()).map_err(|e| (path.to_string(), e)),
+        Value::Array(values) => values
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, value)| check(value, &format!("{}[{}]", path, i), true)),
+        Value::Object(map) => match each(map).map_err(|e| (path.to_string(), e))? {
+            Some(_) if !in_list => Err((
+                path.to_string(),
+                "`$each`는 목록 안에서만 쓸 수 있습니다".to_string(),
+            )),
+            Some(each) => check(each.item, path, false),
+            None => map
+                .iter()
+                .try_for_each(|(key, value)| check(value, &join(path, key), false)),
+        },
+        _ => Ok(()),
+    }
+}
+
+enum RenderError {
+    Missing(String, String),
+    NotAList(String, String),
+    Syntax(String, String),
+}
+
+impl RenderError {
+    fn into_engine(self, name: &str) -> EngineError {
+        let name = name.to_string();
+        match self {
+            RenderError::Missing(path, variable) => EngineError::MissingVariable {
+                name,
+                path,
+                variable,
+            },
+            RenderError::NotAList(path, variable) => EngineError::NotAList {
+                name,
+                path,
+                variable,
+            },
+            RenderError::Syntax(path, message) => EngineError::Syntax {
+                name,
+                path,
+                message,
+            },
+        }
+    }
+}
+
+/// 넘긴 값과 반복 변수
+struct Scope<'a> {
+    root: &'a Value,
+    vars: Vec<(String, Value)>,
+}
+
+impl Scope<'_> {
+    /// `notice.title`, `notices.0.title`: 반복 변수를 먼저 찾습니다.
+    fn lookup(&self, variable: &str) -> Option<&Value> {
+        let mut keys = variable.split('.');
+        let first = keys.next()?;
+        let mut value = match self.vars.iter().rev().find(|(name, _)| name == first) {
+            Some((_, value)) => value,
+            None => self.root.get(first)?,
+        };
+        for key in keys {
+            value = match value {
+                Value::Array(values) => values.get(key.parse::<usize>().ok()?)?,
+                value => value.get(key)?,
+            };
+        }
+        Some(value)
+    }
+
+    fn render(&mut self, value: &Value, path: &str) -> Result<Value, RenderError> {
+        match value {
+            Value::String(text) => self.interpolate(text, path),
+            Value::Array(values) => {
+                let mut out = Vec::with_capacity(values.len());
+                for value in values {
+                    let each = match value {
+                        Value::Object(map) => {
+                            each(map).map_err(|e| RenderError::Syntax(path.to_string(), e))?
+                        }
+                        _ => None,
+                    };
+                    match each {
+                        Some(each) => self.repeat(&each, path, &mut out)?,
+                        None => {
+                            let item_path = format!("{}[{}]", path, out.len());
+                            out.push(self.render(value, &item_path)?);
+                        }
+                    }
+                }
+                Ok(Value::Array(out))
+            }
+            Value::Object(map) => {
+                let mut out = Map::with_capacity(map.len());
+                for (key, value) in map {
+                    out.insert(key.clone(), self.render(value, &join(path, key))?);
+                }
+                Ok(Value::Object(out))
+            }
+            value => Ok(value.clone()),
+        }
+    }
+
+    fn repeat(&mut self, each: &Each, path: &str, out: &mut Vec<Value>) -> Result<(), RenderError> {
+        let items = match self.lookup(each.list) {
+            Some(Value::Array(items)) => items.clone(),
+            Some(_) => {
+                return Err(RenderError::NotAList(
+                    path.to_string(),
+                    each.list.to_string(),
+                ))
+            }
+            None => {
+                return Err(RenderError::Missing(
+                    path.to_string(),
+                    each.list.to_string(),
+                ))
+            }
+        };
+        for (i, item) in items.into_iter().enumerate() {
+            self.vars.push((each.name.to_string(), item));
+            self.vars.push((INDEX.to_string(), Value::from(i + 1)));
+            let item_path = format!("{}[{}]", path, out.len());
+            let rendered = self.render(each.item, &item_path);
+            self.vars.truncate(self.vars.len() - 2);
+            out.push(rendered?);
+        }
+        Ok(())
+    }
+
+    fn interpolate(&self, text: &str, path: &str) -> Result<Value, RenderError> {
+        let pieces = pieces(text).map_err(|e| RenderError::Syntax(path.to_string(), e))?;
+        let lookup = |variable: &str| {
+            self.lookup(variable)
+                .ok_or_else(|| RenderError::Missing(path.to_string(), variable.to_string()))
+        };
+
+        // "{{price}}" 하나뿐이면 숫자도 그대로
+        if let [Piece::Var(variable)] = pieces.as_slice() {
+            return lookup(variable).cloned();
+        }
+
+        let mut out = String::with_capacity(text.len());
+        for piece in pieces {
+            match piece {
+                Piece::Text(text) => out.push_str(text),
+                Piece::Var(variable) => match lookup(variable)? {
+                    Value::String(text) => out.push_str(text),
+                    Value::Null => {}
+                    value => out.push_str(&value.to_string()),
+                },
+            }
+        }
+        Ok(Value::String(out))
+    }
+}
@@ -0,0 +1,101 @@
+//! # kakao-rs
+//! 스킬 요청을 받아 [Template]을 돌려주는 Handler와 간단한 Router
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//!
+//! let router = Router::new()
+//!     .utterance("공지", |_: &SkillRequest| {
+//!         let mut result = Template::new();
+//!         result.push_output(SimpleText::new("오늘 공지입니다.").build());
+//!         result
+//!     })
+//!     .block("major-block-id", |req: &SkillRequest| {
+//!         let mut result = Template::new();
+//!         result.push_output(SimpleText::new(format!("{} 공지", req.utterance())).build());
+//!         result
+//!     });
+//!
+//! let result = router.handle(&SkillRequest::new("공지"));
+//! assert_eq!(result.template.outputs.len(), 1);
+//! ```
+use crate::components::basics::*;
+use crate::request::SkillRequest;
+use std::collections::HashMap;
+
+/// 스킬 요청 하나를 처리해 응답 [Template]을 만듭니다.
+///
+/// `Fn(&SkillRequest) -> Template` 클로저는 모두 Handler입니다.
+pub trait Handler {
+    fn handle(&self, request: &SkillRequest) -> Template;
+}
+
+impl<F> Handler for F
+where
+    F: Fn(&SkillRequest) -> Template,
+{
+    fn handle(&self, request: &SkillRequest) -> Template {
+        self(request)
+    }
+}
+
+/// 블록 id → 발화문 → fallback 순서로 Handler를 찾습니다.
+pub struct Router {
+    blocks: HashMap<String, Box<dyn Handler>>,
+    utterances: HashMap<String, Box<dyn Handler>>,
+    fallback: Option<Box<dyn Handler>>,
+}
+
+impl Default for Router {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Router {
+    #[inline]
+    pub fn new() -> Self {
+        Router {
+            blocks: HashMap::new(),
+            utterances: HashMap::new(),
+            fallback: None,
+        }
+    }
+
+    /// userRequest.block.id가 일치할 때
+    pub fn block<S: Into<String>, H: Handler + 'static>(mut self, id: S, handler: H) -> Self {
+        self.blocks.insert(id.into(), Box::new(handler));
+        self
+    }
+
+    /// userRequest.utterance가 정확히 일치할 때
+    pub fn utterance<S: Into<String>, H: Handler + 'static>(mut self, text: S, handler: H) -> Self {
+        self.utterances.insert(text.into(), Box::new(handler));
+        self
+    }
+
+    /// 아무것도 일치하지 않을 때
+    pub fn fallback<H: Handler + 'static>(mut self, handler: H) -> Self {
+        self.fallback = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Handler for Router {
+    fn handle(&self, request: &SkillRequest) -> Template {
+        let handler = self
+            .blocks
+            .get(request.block_id())
+            .or_else(|| self.utterances.get(request.utterance()))
+            .or(self.fallback.as_ref());
+
+        match handler {
+            Some(handler) => handler.handle(request),
+            None => {
+                let mut result = Template::new();
+                result.push_output(SimpleText::new("알 수 없는 요청입니다.").build());
+                result
+            }
+        }
+    }
+}
@@ -0,0 +1,411 @@
+//! # kakao-rs
+//! `userRequest.lang`에 맞춘 응답 번역
+//!
+//! 언어별 [Catalog]에 `키 → 문구`를 넣고, 응답 문자열에는 `@{키}`를 씁니다.
+//! [Translator::localize]가 말풍선, 카드 제목, 버튼, 바로가기의 `@{키}`를 요청 언어의 문구로 바꿉니다.
+//! 문구가 없으면 한국어(ko), 한국어에도 없으면 키를 그대로 씁니다.
+//!
+//! 문구 안의 `{{이름}}`은 [Translator::format]으로 채웁니다.
+//! `engine` feature를 켜면 파일 템플릿에도 `@{키}`를 쓸 수 있고([Locales::render]),
+//! [Locales::check_registry]로 템플릿이 쓰는 키가 모든 언어에 있는지 검사합니다.
+//!
+//! ```
+//! use kakao_rs::i18n::{Catalog, Locales};
+//! use kakao_rs::prelude::*;
+//!
+//! let locales = Locales::new()
+//!     .add("ko", Catalog::from_json(r#"{"menu": {"notice": "공지", "meal": "학식"}}"#).unwrap())
+//!     .add("en", Catalog::new().add("menu.notice", "Notices"));
+//!
+//! let mut result = Template::new();
+//! result.push_quick_reply(QuickReply::new("@{menu.notice}", "@{menu.notice}"));
+//! result.push_quick_reply(QuickReply::new("@{menu.meal}", "@{menu.meal}"));
+//!
+//! let mut request = SkillRequest::new("menu");
+//! request.user_request.lang = Some("en-US".to_string());
+//! locales.for_request(&request).localize(&mut result);
+//! assert!(result.to_string().contains(r#""label":"Notices""#));
+//! assert!(result.to_string().contains(r#""label":"학식""#)); // 영어가 없어서 한국어
+//! ```
+use crate::components::basics::Template;
+use crate::request::SkillRequest;
+use serde_json::{Map, Value};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+/// 문구가 없을 때 쓰는 언어
+pub const DEFAULT_LANG: &str = "ko";
+
+/// 한 언어의 `키 → 문구`
+#[derive(Debug, Default, Clone)]
+pub struct Catalog {
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    #[inline]
+    pub fn new() -> Self {
+        Catalog {
+            messages: HashMap::new(),
+        }
+    }
+
+    pub fn add<K: Into<String>, V: Into<String>>(mut self, key: K, text: V) -> Self {
+        self.messages.insert(key.into(), text.into());
+        self
+    }
+
+    /// 중첩된 객체는 점으로 이은 키가 됩니다: `{"menu": {"notice": "공지"}}` → `menu.notice`
+    pub fn from_value(value: Value) -> Result<Catalog, String> {
+        let mut catalog = Catalog::new();
+        match value {
+            Value::Object(map) => catalog.flatten(map, "")?,
+            _ => return Err("문구 목록은 객체여야 합니다".to_string()),
+        }
+        Ok(catalog)
+    }
+
+    pub fn from_json(text: &str) -> Result<Catalog, String> {
+        Catalog::from_value(serde_json::from_str(text).map_err(|e| e.to_string())?)
+    }
+
+    fn flatten(&mut self, map: Map<String, Value>, prefix: &str) -> Result<(), String> {
+        for (key, value) in map {
+            let key = if prefix.is_empty() {
+                key
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            match value {
+                Value::Object(map) => self.flatten(map, &key)?,
+                Value::String(text) => {
+                    self.messages.insert(key, text);
+                }
+                _ => return Err(format!("{}: 문구는 문자열이어야 합니다", key)),
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.messages.contains_key(key)
+    }
+
+    /// 등록된 키 (정렬)
+    pub fn keys(&self) -> Vec<&str> {
+        let mut keys: Vec<&str> = self.messages.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        keys
+    }
+
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+}
+
+/// 언어 코드 → [Catalog]
+#[derive(Debug, Default, Clone)]
+pub struct Locales {
+    catalogs: HashMap<String, Catalog>,
+}
+
+impl Locales {
+    #[inline]
+    pub fn new() -> Self {
+        Locales {
+            catalogs: HashMap::new(),
+        }
+    }
+
+    /// 언어 코드는 소문자로 저장합니다. (`en`, `en-us`, `ja`)
+    pub fn add<S: AsRef<str>>(mut self, lang: S, catalog: Catalog) -> Self {
+        self.catalogs.insert(normalize(lang.as_ref()), catalog);
+        self
+    }
+
+    /// 폴더의 `언어.json`, `언어.yaml`, `언어.toml` 파일을 읽습니다. (`ko.yaml`, `en.json`)
+    #[cfg(feature = "engine")]
+    pub fn from_dir<P: AsRef<std::path::Path>>(
+        dir: P,
+    ) -> Result<Locales, crate::engine::EngineError> {
+        use crate::engine::{EngineError, Format};
+        use std::fs;
+
+        let dir = dir.as_ref();
+        let io_error = |path: &std::path::Path, error| EngineError::Io {
+            path: path.to_path_buf(),
+            error,
+        };
+        let mut locales = Locales::new();
+        for entry in fs::read_dir(dir).map_err(|e| io_error(dir, e))? {
+            let path = entry.map_err(|e| io_error(dir, e))?.path();
+            let (format, lang) = match (
+                Format::from_path(&path),
+                path.file_stem().and_then(|stem| stem.to_str()),
+            ) {
+                (Some(format), Some(lang)) if path.is_file() => (format, lang.to_string()),
+                _ => continue,
+            };
+            let text = fs::read_to_string(&path).map_err(|e| io_error(&path, e))?;
+            let catalog = format
+                .parse(&text)
+                .and_then(Catalog::from_value)
+                .map_err(|message| EngineError::Parse {
+                    name: lang.clone(),
+                    message,
+                })?;
+            locales = locales.add(lang, catalog);
+        }
+        Ok(locales)
+    }
+
+    /// 등록된 언어 (정렬)
+    pub fn langs(&self) -> Vec<&str> {
+        let mut langs: Vec<&str> = self.catalogs.keys().map(String::as_str).collect();
+        langs.sort_unstable();
+        langs
+    }
+
+    pub fn catalog(&self, lang: &str) -> Option<&Catalog> {
+        self.catalogs.get(&normalize(lang))
+    }
+
+    /// `en-US` → `en-us`, 없으면 `en`, 그것도 없으면 한국어
+    pub fn translator(&self, lang: &str) -> Translator<'_> {
+        let lang = normalize(lang);
+        let lang = if self.catalogs.contains_key(&lang) {
+            lang
+        } else {
+            match lang.split('-').next() {
+                Some(primary) if self.catalogs.contains_key(primary) => primary.to_string(),
+                _ => DEFAULT_LANG.to_string(),
+            }
+        };
+        Translator {
+            locales: self,
+            lang,
+        }
+    }
+
+    /// `userRequest.lang`에 맞는 [Translator]. lang이 없으면 한국어입니다.
+    pub fn for_request(&self, request: &SkillRequest) -> Translator<'_> {
+        self.translator(request.lang().unwrap_or(DEFAULT_LANG))
+    }
+
+    /// `key`가 없는 언어 (정렬)
+    pub fn missing(&self, key: &str) -> Vec<&str> {
+        let mut langs: Vec<&str> = self
+            .catalogs
+            .iter()
+            .filter(|(_, catalog)| !catalog.contains(key))
+            .map(|(lang, _)| lang.as_str())
+            .collect();
+        langs.sort_unstable();
+        langs
+    }
+
+    /// 응답이 쓰는 `@{키}`가 모든 언어에 있는지 검사합니다.
+    pub fn check_template(&self, name: &str, template: &Template) -> Result<(), Vec<MissingKey>> {
+        let value = serde_json::to_value(template).unwrap_or_default();
+        self.check_keys(name, &keys(&value))
+    }
+
+    /// 등록된 파일 템플릿이 쓰는 `@{키}`가 모든 언어에 있는지 검사합니다.
+    #[cfg(feature = "engine")]
+    pub fn check_registry(
+        &self,
+        registry: &crate::engine::Registry,
+    ) -> Result<(), Vec<MissingKey>> {
+        let mut missing = Vec::new();
+        for (name, body) in registry.bodies() {
+            if let Err(errors) = self.check_keys(name, &keys(body)) {
+                missing.extend(errors);
+            }
+        }
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+
+    /// `@{키}`를 `lang` 문구로 바꾼 뒤 변수를 채워 파일 템플릿을 그립니다.
+    /// 문구 안에도 `{{변수}}`를 쓸 수 있습니다.
+    #[cfg(feature = "engine")]
+    pub fn render<C: serde::Serialize + ?Sized>(
+        &self,
+        registry: &crate::engine::Registry,
+        name: &str,
+        lang: &str,
+        context: &C,
+    ) -> Result<Template, crate::engine::EngineError> {
+        let mut body = registry.body(name)?.clone();
+        self.translator(lang).replace(&mut body);
+        crate::engine::render_body(name, &body, context)
+    }
+
+    fn check_keys(&self, name: &str, keys: &BTreeSet<String>) -> Result<(), Vec<MissingKey>> {
+        let missing: Vec<MissingKey> = keys
+            .iter()
+            .flat_map(|key| {
+                self.missing(key).into_iter().map(move |lang| MissingKey {
+                    template: name.to_string(),
+                    lang: lang.to_string(),
+                    key: key.clone(),
+                })
+            })
+            .collect();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+}
+
+/// 한 언어로 정해진 번역기
+#[derive(Debug, Clone)]
+pub struct Translator<'a> {
+    locales: &'a Locales,
+    lang: String,
+}
+
+impl Translator<'_> {
+    /// 실제로 쓰는 언어 (`en-US` 요청이면 `en`)
+    pub fn lang(&self) -> &str {
+        &self.lang
+    }
+
+    /// 문구. 없으면 한국어, 그것도 없으면 키를 그대로 돌려줍니다.
+    pub fn t<'k>(&'k self, key: &'k str) -> &'k str {
+        [self.lang.as_str(), DEFAULT_LANG]
+            .iter()
+            .find_map(|lang| self.locales.catalogs.get(*lang)?.get(key))
+            .unwrap_or(key)
+    }
+
+    /// 문구의 `{{이름}}`을 채웁니다.
+    ///
+    /// ```
+    /// use kakao_rs::i18n::{Catalog, Locales};
+    ///
+    /// let locales = Locales::new().add("en", Catalog::new().add("seats", "{{count}} seats left"));
+    /// assert_eq!(locales.translator("en").format("seats", &[("count", &3)]), "3 seats left");
+    /// ```
+    pub fn format(&self, key: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+        let mut text = self.t(key).to_string();
+        for (name, value) in args {
+            text = text.replace(&format!("{{{{{}}}}}", name), &value.to_string());
+        }
+        text
+    }
+
+    /// 응답의 `@{키}`를 모두 문구로 바꿉니다.
+    pub fn localize(&self, template: &mut Template) {
+        let mut value = match serde_json::to_value(&*template) {
+            Ok(value) => value,
+            Err(_) => return,
+        };
+        if !self.replace(&mut value) {
+            return;
+        }
+        if let Ok(localized) = serde_json::from_value(value) {
+            *template = localized;
+        }
+    }
+
+    /// 바꾼 것이 있으면 true
+    fn replace(&self, value: &mut Value) -> bool {
+        match value {
+            Value::String(text) if text.contains(OPEN) => {
+                *text = self.replace_text(text);
+                true
+            }
+            Value::Array(values) => values
+                .iter_mut()
+                .fold(false, |changed, value| self.replace(value) | changed),
+            Value::Object(map) => map
+                .values_mut()
+                .fold(false, |changed, value| self.replace(value) | changed),
+            _ => false,
+        }
+    }
+
+    fn replace_text(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some((before, key, after)) = next_key(rest) {
+            out.push_str(before);
+            out.push_str(self.t(key));
+            rest = after;
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+/// 어떤 언어에 없는 키
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissingKey {
+    pub template: String,
+    pub lang: String,
+    pub key: String,
+}
+
+impl fmt::Display for MissingKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} 문구가 없습니다: `{}`",
+            self.template, self.lang, self.key
+        )
+    }
+}
+
+impl std::error::Error for MissingKey {}
+
+const OPEN: &str = "@{";
+
+fn normalize(lang: &str) -> String {
+    lang.trim().replace('_', "-").to_ascii_lowercase()
+}
+
+/// `앞 @{키} 뒤` → (앞, 키, 뒤)
+fn next_key(text: &str) -> Option<(&str, &str, &str)> {
+    let start = text.find(OPEN)?;
+    let end = start + text[start..].find('}')?;
+    Some((
+        &text[..start],
+        text[start + OPEN.len()..end].trim(),
+        &text[end + 1..],
+    ))
+}
+
+/// 값 안의 모든 `@{키}`
+fn keys(value: &Value) -> BTreeSet<String> {
+    fn collect(value: &Value, keys: &mut BTreeSet<String>) {
+        match value {
+            Value::String(text) => {
+                let mut rest = text.as_str();
+                while let Some((_, key, after)) = next_key(rest) {
+                    keys.insert(key.to_string());
+                    rest = after;
+                }
+            }
+            Value::Array(values) => values.iter().for_each(|value| collect(value, keys)),
+            Value::Object(map) => map.values().for_each(|value| collect(value, keys)),
+            _ => {}
+        }
+    }
+    let mut keys = BTreeSet::new();
+    collect(value, &mut keys);
+    keys
+}
@@ -0,0 +1,235 @@
+//! # kakao-rs
+//! 받침에 맞는 조사 고르기: 은/는, 이/가, 을/를, 과/와, 으로/로, 이에요/예요, 이나/나, 이면/면 등
+//!
+//! 숫자는 한국어로 읽는 소리(1 → 일, 10 → 십), 영어는 대략적인 발음(Google → 구글, API → 에이피아이)으로 판단합니다.
+//! 알 수 없으면 "을(를)"처럼 두 가지를 함께 씁니다.
+//!
+//! ```
+//! use kakao_rs::josa;
+//! use kakao_rs::prelude::*;
+//!
+//! assert_eq!(josa::attach("사과", "을"), "사과를");
+//! assert_eq!(josa::attach("서울", "으로"), "서울로");
+//!
+//! let name = "소프트웨어학과";
+//! let text = SimpleText::new(josa!("{}을 선택했어요. {}이 맞나요?", name, 3));
+//! assert_eq!(text.text(), "소프트웨어학과를 선택했어요. 3이 맞나요?");
+//! ```
+use std::fmt::{self, Write};
+
+/// (받침 있을 때, 없을 때, 알 수 없을 때)
+const PARTICLES: [(&str, &str, &str); 18] = [
+    ("이에요", "예요", "이에요(예요)"),
+    ("으로서", "로서", "(으)로서"),
+    ("으로써", "로써", "(으)로써"),
+    ("으로", "로", "(으)로"),
+    ("이라고", "라고", "(이)라고"),
+    ("이라서", "라서", "(이)라서"),
+    ("이라면", "라면", "(이)라면"),
+    ("이든지", "든지", "(이)든지"),
+    ("이랑", "랑", "(이)랑"),
+    ("이나", "나", "(이)나"),
+    ("이면", "면", "(이)면"),
+    ("이며", "며", "(이)며"),
+    ("이든", "든", "(이)든"),
+    ("이야", "야", "(이)야"),
+    ("은", "는", "은(는)"),
+    ("이", "가", "이(가)"),
+    ("을", "를", "을(를)"),
+    ("과", "와", "과(와)"),
+];
+
+type Particle = (&'static str, &'static str, &'static str);
+
+/// [josa!]에서 값 끝을 표시하는 문자 (사용자 영역)
+#[doc(hidden)]
+pub const MARK: char = '\u{E000}';
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Ending {
+    /// 받침 없음
+    Vowel,
+    /// ㄹ 받침 (으로 → 로)
+    Rieul,
+    /// 그 밖의 받침
+    Consonant,
+    Unknown,
+}
+
+/// `word` 뒤에 올 조사를 고릅니다. `particle`은 "을", "를", "을(를)" 중 아무거나 쓰면 됩니다.
+/// 모르는 조사는 그대로 돌려줍니다.
+pub fn particle<'a>(word: &str, particle: &'a str) -> &'a str {
+    match PARTICLES
+        .iter()
+        .find(|&&entry| forms(entry).iter().any(|form| form == particle))
+    {
+        Some(&entry) => choose(entry, ending(word)),
+        None => particle,
+    }
+}
+
+/// `word` + 알맞은 조사
+pub fn attach(word: &str, particle: &str) -> String {
+    format!("{}{}", word, self::particle(word, particle))
+}
+
+fn choose((with, without, unknown): Particle, ending: Ending) -> &'static str {
+    match ending {
+        // ㄹ 받침 뒤에는 "로"
+        Ending::Rieul if with.starts_with("으로") => without,
+        Ending::Rieul | Ending::Consonant => with,
+        Ending::Vowel => without,
+        Ending::Unknown => unknown,
+    }
+}
+
+/// 템플릿에 쓸 수 있는 모양: "을(를)", "를(을)", "(으)로", "(이)나", "을", "를"
+fn forms((with, without, unknown): Particle) -> [String; 5] {
+    [
+        unknown.to_string(),
+        format!("{}({})", with, without),
+        format!("{}({})", without, with),
+        with.to_string(),
+        without.to_string(),
+    ]
+}
+
+fn ending(word: &str) -> Ending {
+    // 끝의 괄호, 따옴표, 문장부호는 건너뜁니다.
+    let word = word.trim_end_matches(|c: char| !c.is_alphanumeric());
+    let last = match word.chars().last() {
+        Some(last) => last,
+        None => return Ending::Unknown,
+    };
+    match last {
+        '가'..='힣' => match (last as u32 - '가' as u32) % 28 {
+            0 => Ending::Vowel,
+            8 => Ending::Rieul,
+            _ => Ending::Consonant,
+        },
+        '0'..='9' => number(word),
+        'a'..='z' | 'A'..='Z' => english(word),
+        _ => Ending::Unknown,
+    }
+}
+
+/// 한국어로 읽은 끝소리: 3 → 삼, 10 → 십, 1,000 → 천
+fn number(word: &str) -> Ending {
+    let digits: Vec<u8> = word
+        .bytes()
+        .rev()
+        .take_while(|b| b.is_ascii_digit() || *b == b',')
+        .filter(u8::is_ascii_digit)
+        .collect();
+    let zeros = digits.iter().take_while(|&&d| d == b'0').count();
+    if zeros == digits.len() {
+        return Ending::Consonant; // 영
+    }
+    match zeros {
+        0 => match digits[0] {
+            b'1' | b'7' | b'8' => Ending::Rieul,        // 일, 칠, 팔
+            b'2' | b'4' | b'5' | b'9' => Ending::Vowel, // 이, 사, 오, 구
+            _ => Ending::Consonant,                     // 삼, 육
+        },
+        1..=11 => Ending::Consonant, // 십, 백, 천, 만, 억
+        12..=15 => Ending::Vowel,    // 조
+        _ => Ending::Unknown,
+    }
+}
+
+/// 영어 발음 추정: 대문자 약어는 알파벳 이름(L → 엘), 단어는 끝 철자
+fn english(word: &str) -> Ending {
+    let letters: Vec<char> = word
+        .chars()
+        .rev()
+        .take_while(char::is_ascii_alphabetic)
+        .collect();
+    if letters.iter().all(char::is_ascii_uppercase) {
+        return match letters[0] {
+            'L' | 'R' => Ending::Rieul,
+            'M' | 'N' => Ending::Consonant,
+            _ => Ending::Vowel,
+        };
+    }
+
+    let last = letters[0].to_ascii_lowercase();
+    let before = letters.get(1).map(char::to_ascii_lowercase);
+    let after_vowel = matches!(before, Some('a' | 'e' | 'i' | 'o' | 'u'));
+    match (before, last) {
+        (_, 'l') | (Some('l'), 'e') => Ending::Rieul, // 메일, 구글
+        (_, 'm' | 'n') | (Some('n'), 'g') => Ending::Consonant, // 팀, 파이썬, 킹
+        (Some('c'), 'k') => Ending::Consonant,        // 백
+        (_, 'k' | 'p' | 't' | 'b' | 'c') if after_vowel => Ending::Consonant, // 북, 컵, 봇, 웹, 맥
+        _ => Ending::Vowel,
+    }
+}
+
+/// [josa!] 값 뒤에 표시를 붙입니다.
+#[doc(hidden)]
+pub struct Marked<'a, T: ?Sized>(pub &'a T);
+
+impl<T: fmt::Display + ?Sized> fmt::Display for Marked<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // 정밀도, 폭 같은 서식은 값에 그대로 적용
+        fmt::Display::fmt(self.0, f)?;
+        f.write_char(MARK)
+    }
+}
+
+/// 표시 바로 뒤의 조사를 앞 값에 맞게 고치고 표시를 지웁니다.
+///
+/// 조사 뒤가 공백, 문장부호, 끝일 때만 고칩니다. ("{}이나"의 "이"를 "가"로 바꾸지 않도록)
+/// 여러 모양이 맞으면 가장 긴 것을 씁니다.
+#[doc(hidden)]
+pub fn resolve(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut parts = text.split(MARK);
+    if let Some(first) = parts.next() {
+        out.push_str(first);
+    }
+    for part in parts {
+        let ending = ending(&out);
+        let found = PARTICLES
+            .iter()
+            .flat_map(|&entry| forms(entry).map(|form| (form, entry)))
+            .filter(|(form, _)| {
+                part.strip_prefix(form.as_str())
+                    .is_some_and(|rest| rest.chars().next().map_or(true, |c| !c.is_alphanumeric()))
+            })
+            .max_by_key(|(form, _)| form.len())
+            .map(|(form, entry)| (form.len(), choose(entry, ending)));
+        match found {
+            Some((len, particle)) => {
+                out.push_str(particle);
+                out.push_str(&part[len..]);
+            }
+            None => out.push_str(part),
+        }
+    }
+    out
+}
+
+/// `format!`처럼 쓰되, 값 바로 뒤의 조사를 받침에 맞게 고칩니다.
+///
+/// 위치 인자(`"{}을", name`)와 이름 인자(`"{name}을", name = name`)를 지원합니다.
+/// 템플릿에는 "을", "를", "을(를)" 중 아무거나 쓰면 됩니다.
+///
+/// ```
+/// use kakao_rs::josa;
+///
+/// assert_eq!(josa!("{}를 선택했어요", "학과"), "학과를 선택했어요");
+/// assert_eq!(josa!("{}를 선택했어요", "전공"), "전공을 선택했어요");
+/// assert_eq!(
+///     josa!("{who}와 {place}으로 가요", who = "Google", place = "서울"),
+///     "Google과 서울로 가요"
+/// );
+/// ```
+#[macro_export]
+macro_rules! josa {
+    ($fmt:expr, $($name:ident = $arg:expr),+ $(,)?) => {
+        $crate::josa::resolve(&format!($fmt, $($name = $crate::josa::Marked(&$arg)),+))
+    };
+    ($fmt:expr $(, $arg:expr)* $(,)?) => {
+        $crate::josa::resolve(&format!($fmt $(, $crate::josa::Marked(&$arg))*))
+    };
+}
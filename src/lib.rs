@@ -5,7 +5,7 @@
 //! 지원하는 메시지 유형
 //!  - **SimpleText** && **SimpleImage**
 //!  - **ListCard**
-//!  - **Carousel** (BasicCard || CommerceCard || ItemCard)
+//!  - **Carousel** (BasicCard || CommerceCard || ItemCard || ListCard)
 //!  - **BasicCard**
 //!  - **CommerceCard**
 //!  - **ItemCard**
@@ -13,8 +13,9 @@
 //! ### Carousel(BasicCards) + SimpleText 예제
 //!
 //! ```rust
+//! # use kakao_rs::prelude::*;
 //! let mut result = Template::new();
-//! result.add_qr(QuickReply::new(
+//! result.push_quick_reply(QuickReply::new(
 //!     "빠른 응답",
 //!     "빠른 응답 ㅋㅋ",
 //! ));
@@ -23,18 +24,18 @@
 //!
 //! for i in 0..5 {
 //!     let basic_card = BasicCard::new()
-//!         .set_title(format!("{}번", i))
-//!         .set_thumbnail(
+//!         .with_title(format!("{}번", i))
+//!         .with_thumbnail(
 //!             "http://k.kakaocdn.net/dn/APR96/btqqH7zLanY/kD5mIPX7TdD2NAxgP29cC0/1x1.jpg"
 //!         );
 //!
-//!     carousel.add_card(basic_card.build_card());
+//!     carousel.push_card(basic_card.build_card());
 //! }
 //!
-//! result.add_output(carousel.build());
+//! result.push_output(carousel.build());
 //!
 //! let simple_text = SimpleText::new("심플 텍스트 테스트");
-//! result.add_output(simple_text.build());
+//! result.push_output(simple_text.build());
 //!
 //! ```
 //!
@@ -43,8 +44,29 @@
 #[macro_use]
 extern crate serde_json;
 
+pub mod check;
+#[macro_use]
 pub mod components;
+#[cfg(feature = "engine")]
+pub mod engine;
+pub mod handler;
+pub mod i18n;
+pub mod josa;
+pub mod limits;
+pub mod money;
+pub mod paginate;
 pub mod prelude;
+pub mod record;
+pub mod render;
+pub mod request;
+pub mod scenario;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod split;
+pub mod text;
+pub mod url;
+pub mod validate;
+pub mod visit;
 
 // pub use crate::components::basics::*;
 // pub use crate::components::buttons::*;
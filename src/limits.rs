@@ -0,0 +1,50 @@
+//! # kakao-rs
+//! [응답 타입별 제한](https://i.kakao.com/docs/skill-response-format)
+//!
+//! 글자 수는 바이트가 아니라 글자 단위입니다.
+
+/// template.outputs: 1 ~ 3개
+pub const OUTPUTS: usize = 3;
+/// template.quickReplies: 최대 10개
+pub const QUICK_REPLIES: usize = 10;
+/// QuickReply.label: 최대 14자
+pub const QUICK_REPLY_LABEL: usize = 14;
+
+/// Button.label: 최대 14자
+pub const BUTTON_LABEL: usize = 14;
+
+/// SimpleText.text: 최대 1000자
+pub const SIMPLE_TEXT: usize = 1000;
+
+/// BasicCard.title: 최대 50자
+pub const BASIC_CARD_TITLE: usize = 50;
+/// BasicCard.description: 최대 230자
+pub const BASIC_CARD_DESCRIPTION: usize = 230;
+/// BasicCard.buttons: 최대 3개
+pub const BASIC_CARD_BUTTONS: usize = 3;
+
+/// CommerceCard.description: 최대 40자
+pub const COMMERCE_CARD_DESCRIPTION: usize = 40;
+/// CommerceCard.buttons: 최대 3개
+pub const COMMERCE_CARD_BUTTONS: usize = 3;
+
+/// ItemCard.itemList: 최대 10개
+pub const ITEM_CARD_ITEMS: usize = 10;
+/// ItemCard.buttons: 최대 3개
+pub const ITEM_CARD_BUTTONS: usize = 3;
+
+/// ListCard.items: 1 ~ 5개
+pub const LIST_CARD_ITEMS: usize = 5;
+/// 캐로셀 안의 ListCard.items: 1 ~ 4개
+pub const CAROUSEL_LIST_CARD_ITEMS: usize = 4;
+/// ListCard.buttons: 최대 2개
+pub const LIST_CARD_BUTTONS: usize = 2;
+/// ListCard.header.title, ListItem.title: 최대 50자
+pub const LIST_ITEM_TITLE: usize = 50;
+/// ListItem.description: 최대 50자
+pub const LIST_ITEM_DESCRIPTION: usize = 50;
+
+/// Carousel.items: 최대 10개
+pub const CAROUSEL_ITEMS: usize = 10;
+/// ListCard 캐로셀의 items: 최대 5개
+pub const CAROUSEL_LIST_CARDS: usize = 5;
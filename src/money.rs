@@ -0,0 +1,160 @@
+//! # kakao-rs
+//! CommerceCard 가격과 통화
+//!
+//! [Price]는 음수가 될 수 없고, [Currency]는 카카오가 받는 `won`을 따로 구분합니다.
+//! 할인가는 [CommerceCard](crate::components::cards::CommerceCard)가 계산하고
+//! [Validate](crate::validate::Validate)가 세 할인 필드가 서로 맞는지 확인합니다.
+//!
+//! ```
+//! use kakao_rs::money::{Currency, Price};
+//! use kakao_rs::prelude::*;
+//!
+//! let card = CommerceCard::new()
+//!     .with_price(35000)
+//!     .with_currency(Currency::Won)
+//!     .with_discount_rate(15);
+//! assert_eq!(card.discounted_price(), Some(Price::new(29750)));
+//! assert_eq!(card.price_text(), "35,000원 → 29,750원 (15% 할인)");
+//!
+//! assert_eq!(Currency::from("WON"), Currency::Other("WON".to_string()));
+//! assert_eq!(Currency::Won.format(Price::new(5000)), "5,000원");
+//! ```
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/***** Price *****/
+/// 가격, 할인 금액, 할인가: 0 이상의 정수
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct Price(u32);
+
+impl Price {
+    pub const fn new(amount: u32) -> Self {
+        Price(amount)
+    }
+
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
+    /// 할인율(%)을 적용한 가격 (원 단위 버림)
+    pub fn apply_rate(self, rate: u8) -> Price {
+        let rate = u64::from(rate.min(100));
+        Price((u64::from(self.0) * (100 - rate) / 100) as u32)
+    }
+
+    /// 할인 금액을 뺀 가격: 할인 금액이 더 크면 0
+    pub fn apply_discount(self, discount: Price) -> Price {
+        Price(self.0.saturating_sub(discount.0))
+    }
+}
+
+impl From<u32> for Price {
+    fn from(amount: u32) -> Self {
+        Price(amount)
+    }
+}
+
+impl PartialEq<u32> for Price {
+    fn eq(&self, other: &u32) -> bool {
+        self.0 == *other
+    }
+}
+
+/// 세 자리마다 쉼표: 5000 -> "5,000"
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&thousands(i64::from(self.0)))
+    }
+}
+/***** Price *****/
+
+/***** Currency *****/
+/// 통화: 카카오는 `won`만 받습니다.
+///
+/// 문자열은 대소문자를 그대로 둡니다. `"WON"`은 [Currency::Other]가 되고 검사에서 걸립니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Currency {
+    Won,
+    Other(String),
+}
+
+impl Currency {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Currency::Won => "won",
+            Currency::Other(currency) => currency,
+        }
+    }
+
+    /// 5000 -> "5,000원", 통화가 `won`이 아니면 "5,000 USD"
+    pub fn format(&self, price: Price) -> String {
+        match self {
+            Currency::Won => format!("{}원", price),
+            Currency::Other(currency) if currency.is_empty() => price.to_string(),
+            Currency::Other(currency) => format!("{} {}", price, currency),
+        }
+    }
+}
+
+impl From<&str> for Currency {
+    fn from(currency: &str) -> Self {
+        match currency {
+            "won" => Currency::Won,
+            _ => Currency::Other(currency.to_string()),
+        }
+    }
+}
+
+impl From<String> for Currency {
+    fn from(currency: String) -> Self {
+        match currency.as_str() {
+            "won" => Currency::Won,
+            _ => Currency::Other(currency),
+        }
+    }
+}
+
+impl PartialEq<str> for Currency {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Currency::from)
+    }
+}
+/***** Currency *****/
+
+/// 세 자리마다 쉼표: -5000 -> "-5,000"
+pub fn thousands(n: i64) -> String {
+    let digits = n.unsigned_abs().to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+    if n < 0 {
+        out.push('-');
+    }
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
@@ -0,0 +1,203 @@
+//! # kakao-rs
+//! 긴 목록을 ListCard 여러 장으로 나눕니다.
+//!
+//! 다음 페이지가 있으면 "다음 페이지" 바로가기를 붙이고, 페이지 번호(1부터)를 extra에 담습니다.
+//! 다음 스킬 요청에서는 [Paginator::requested_page]로 읽으면 됩니다.
+//!
+//! ```
+//! use kakao_rs::paginate::Paginator;
+//! use kakao_rs::prelude::*;
+//!
+//! let notices: Vec<String> = (1..=30).map(|i| format!("{}번 공지", i)).collect();
+//! let paginator = Paginator::new("공지");
+//!
+//! let request = SkillRequest::new("공지");
+//! let page = paginator.requested_page(&request); // 1
+//! let result = paginator.page(notices.iter().map(|n| ListItem::new(n.as_str())), page);
+//!
+//! assert!(result.validate().is_ok());
+//! assert_eq!(result.template.quick_replies[0].label(), "다음 페이지");
+//! assert_eq!(result.template.quick_replies[0].extra().unwrap()["page"], 2);
+//! ```
+use crate::components::basics::*;
+use crate::limits;
+use crate::request::SkillRequest;
+use serde_json::Value;
+
+/// 페이지 번호를 담는 extra, context param 키
+pub const PAGE_KEY: &str = "page";
+
+/// 목록 하나를 페이지로 나누는 설정
+pub struct Paginator {
+    title: String,
+    per_page: usize,
+    next_label: String,
+    empty_text: String,
+    block_id: Option<String>,
+    context: Option<String>,
+}
+
+impl Paginator {
+    /// Paginator(ListCard 제목): 한 장에 5개, 바로가기 "다음 페이지"
+    #[inline]
+    pub fn new<S: Into<String>>(title: S) -> Self {
+        Paginator {
+            title: title.into(),
+            per_page: limits::LIST_CARD_ITEMS,
+            next_label: "다음 페이지".to_string(),
+            empty_text: "목록이 비어 있습니다.".to_string(),
+            block_id: None,
+            context: None,
+        }
+    }
+
+    /// ListCard 한 장의 항목 수 (1 ~ 5, 캐로셀 안에서는 최대 4)
+    pub fn put_per_page(&mut self, per_page: usize) -> &mut Self {
+        self.per_page = per_page.clamp(1, limits::LIST_CARD_ITEMS);
+        self
+    }
+
+    pub fn put_next_label<S: Into<String>>(&mut self, label: S) -> &mut Self {
+        self.next_label = label.into();
+        self
+    }
+
+    /// 항목이 없을 때 보낼 SimpleText
+    pub fn put_empty_text<S: Into<String>>(&mut self, text: S) -> &mut Self {
+        self.empty_text = text.into();
+        self
+    }
+
+    /// "다음 페이지"를 발화 대신 이 블록으로 보냅니다.
+    pub fn put_block_id<S: Into<String>>(&mut self, id: S) -> &mut Self {
+        self.block_id = Some(id.into());
+        self
+    }
+
+    /// 다음 페이지 번호를 이 이름의 context에도 담습니다.
+    /// (직접 "다음 페이지"라고 입력해도 이어서 볼 수 있습니다)
+    pub fn put_context<S: Into<String>>(&mut self, name: S) -> &mut Self {
+        self.context = Some(name.into());
+        self
+    }
+
+    /// ListCard 한 장짜리 페이지
+    pub fn page<I>(&self, items: I, page: usize) -> Template
+    where
+        I: IntoIterator<Item = ListItem>,
+    {
+        let items: Vec<ListItem> = items.into_iter().collect();
+        let cards = chunk(items, self.per_page);
+        let pages = cards.len();
+        let page = page.clamp(1, pages.max(1));
+
+        let mut result = Template::new();
+        match cards.into_iter().nth(page - 1) {
+            Some(items) => result.push_output(self.list_card(items, page, pages).build()),
+            None => result.push_output(SimpleText::new(self.empty_text.as_str()).build()),
+        };
+        self.next(&mut result, page, pages);
+        result
+    }
+
+    /// ListCard 캐로셀 페이지: 한 페이지에 카드 5장, 카드마다 최대 4개
+    pub fn carousel<I>(&self, items: I, page: usize) -> Template
+    where
+        I: IntoIterator<Item = ListItem>,
+    {
+        let items: Vec<ListItem> = items.into_iter().collect();
+        let per_card = self.per_page.min(limits::CAROUSEL_LIST_CARD_ITEMS);
+        let mut cards = chunk(items, per_card);
+        let total = cards.len();
+        let pages = total.div_ceil(limits::CAROUSEL_LIST_CARDS);
+        let page = page.clamp(1, pages.max(1));
+
+        let mut result = Template::new();
+        if cards.is_empty() {
+            result.push_output(SimpleText::new(self.empty_text.as_str()).build());
+        } else {
+            let first = (page - 1) * limits::CAROUSEL_LIST_CARDS;
+            let mut carousel = Carousel::new().with_type(ListCard::id());
+            for (i, items) in cards
+                .drain(..)
+                .enumerate()
+                .skip(first)
+                .take(limits::CAROUSEL_LIST_CARDS)
+            {
+                carousel.push_card(self.list_card(items, i + 1, total).build_card());
+            }
+            result.push_output(carousel.build());
+        }
+        self.next(&mut result, page, pages);
+        result
+    }
+
+    /// 바로가기 extra → context 순서로 요청한 페이지를 찾습니다. (없으면 1)
+    pub fn requested_page(&self, request: &SkillRequest) -> usize {
+        let from_extra = request
+            .client_extra(PAGE_KEY)
+            .and_then(|value| match value {
+                Value::Number(n) => n.as_u64().map(|n| n as usize),
+                Value::String(s) => s.parse().ok(),
+                _ => None,
+            });
+        let from_context = || {
+            let name = self.context.as_ref()?;
+            request.context(name)?.param(PAGE_KEY)?.parse().ok()
+        };
+        from_extra.or_else(from_context).unwrap_or(1).max(1)
+    }
+
+    /// 제목에 "(2/6)"을 붙입니다.
+    fn list_card(&self, items: Vec<ListItem>, n: usize, total: usize) -> ListCard {
+        let title = if total > 1 {
+            format!("{} ({}/{})", self.title, n, total)
+        } else {
+            self.title.clone()
+        };
+        let mut list_card = ListCard::new(title);
+        for item in items {
+            list_card.push_item(item);
+        }
+        list_card
+    }
+
+    fn next(&self, result: &mut Template, page: usize, pages: usize) {
+        if page < pages {
+            let mut qr = QuickReply::new(self.next_label.as_str(), self.next_label.as_str())
+                .with_extra(PAGE_KEY, page + 1);
+            if let Some(id) = &self.block_id {
+                qr.put_action("block").put_block_id(id.as_str());
+            }
+            result.push_quick_reply(qr);
+        }
+
+        if let Some(name) = &self.context {
+            // 마지막 페이지면 lifeSpan 0으로 지웁니다.
+            let context = if page < pages {
+                ContextValue::new(name.as_str(), 1)
+                    .with_param(PAGE_KEY.to_string(), (page + 1).to_string())
+            } else {
+                ContextValue::new(name.as_str(), 0)
+            };
+            result.push_context(context);
+        }
+    }
+}
+
+with!(Paginator {
+    with_per_page => put_per_page(per_page: usize);
+    with_next_label => put_next_label(label: impl Into<String>);
+    with_empty_text => put_empty_text(text: impl Into<String>);
+    with_block_id => put_block_id(id: impl Into<String>);
+    with_context => put_context(name: impl Into<String>);
+});
+
+fn chunk(items: Vec<ListItem>, size: usize) -> Vec<Vec<ListItem>> {
+    let mut chunks = Vec::with_capacity(items.len().div_ceil(size));
+    let mut items = items.into_iter().peekable();
+    while items.peek().is_some() {
+        chunks.push(items.by_ref().take(size).collect());
+    }
+    chunks
+}
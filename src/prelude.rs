@@ -15,3 +15,17 @@ pub use crate::components::basics::*;
 pub use crate::components::buttons::*;
 #[doc(no_inline)]
 pub use crate::components::cards::*;
+#[doc(no_inline)]
+pub use crate::handler::{Handler, Router};
+#[doc(no_inline)]
+pub use crate::paginate::Paginator;
+#[doc(no_inline)]
+pub use crate::request::SkillRequest;
+#[doc(no_inline)]
+pub use crate::scenario::{ButtonAt, Scenario, Session, UserAction};
+#[doc(no_inline)]
+pub use crate::split::TextSplitter;
+#[doc(no_inline)]
+pub use crate::text::Fit;
+#[doc(no_inline)]
+pub use crate::validate::{Validate, ValidationError};
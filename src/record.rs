@@ -0,0 +1,309 @@
+//! # kakao-rs
+//! 실제 트래픽을 JSONL로 기록하고, 기록을 현재 Handler에 다시 돌려 보는 회귀 테스트 도구
+//!
+//! 한 줄에 `{"request": 스킬 요청, "response": 응답 Template}` 하나씩 기록합니다.
+//! 유저 키(user.id, plusfriendUserKey 등)는 [MaskKey]로 HMAC을 구해 가려서 기록합니다.
+//! 기록은 `kakao-sim <스킬 URL> --replay <파일>`로 스킬 서버에 다시 보내 볼 수도 있습니다.
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//! use kakao_rs::record::{Recorder, Replay};
+//!
+//! let handler = |req: &SkillRequest| {
+//!     let mut result = Template::new();
+//!     result.push_output(SimpleText::new(req.utterance()).build());
+//!     result
+//! };
+//!
+//! let recorder = Recorder::new(handler, Vec::new());
+//! recorder.handle(&SkillRequest::new("공지").with_user("실제 유저 키"));
+//! let jsonl = recorder.into_inner();
+//!
+//! let replay = Replay::from_reader(jsonl.as_slice()).unwrap();
+//! assert!(replay.run(&handler).is_clean());
+//! ```
+use crate::components::basics::Template;
+use crate::handler::Handler;
+use crate::request::SkillRequest;
+use hmac_sha256::HMAC;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// JSONL 한 줄: 요청과 그때의 응답
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Exchange {
+    pub request: SkillRequest,
+    pub response: Value,
+}
+
+/***** Recorder *****/
+/// Handler를 감싸서 모든 요청/응답을 `writer`에 JSONL로 남기는 미들웨어
+pub struct Recorder<H, W: Write> {
+    handler: H,
+    writer: Mutex<W>,
+    masking: bool,
+    key: MaskKey,
+}
+
+impl<H: Handler, W: Write> Recorder<H, W> {
+    /// 기본적으로 유저 키를 [MaskKey::random]으로 가립니다.
+    #[inline]
+    pub fn new(handler: H, writer: W) -> Self {
+        Recorder {
+            handler,
+            writer: Mutex::new(writer),
+            masking: true,
+            key: MaskKey::random(),
+        }
+    }
+
+    pub fn put_masking(&mut self, masking: bool) -> &mut Self {
+        self.masking = masking;
+        self
+    }
+
+    /// [`put_masking`](Self::put_masking)의 체이닝 버전
+    #[must_use]
+    pub fn with_masking(mut self, masking: bool) -> Self {
+        self.put_masking(masking);
+        self
+    }
+
+    /// 유저 키를 가릴 키: 여러 번 기록해도 같은 유저를 같은 값으로 가립니다.
+    pub fn put_mask_key(&mut self, key: MaskKey) -> &mut Self {
+        self.key = key;
+        self
+    }
+
+    /// [`put_mask_key`](Self::put_mask_key)의 체이닝 버전
+    #[must_use]
+    pub fn with_mask_key(mut self, key: MaskKey) -> Self {
+        self.put_mask_key(key);
+        self
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn record(&self, request: &SkillRequest, response: &Template) -> io::Result<()> {
+        let mut request = request.clone();
+        if self.masking {
+            mask_user_keys(&mut request, &self.key);
+        }
+        let exchange = Exchange {
+            request,
+            response: response.build(),
+        };
+
+        let mut writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        serde_json::to_writer(&mut *writer, &exchange)?;
+        writeln!(writer)?;
+        writer.flush()
+    }
+}
+
+impl<H: Handler, W: Write> Handler for Recorder<H, W> {
+    fn handle(&self, request: &SkillRequest) -> Template {
+        let response = self.handler.handle(request);
+        // 기록 실패로 응답까지 실패하면 안 됩니다.
+        let _ = self.record(request, &response);
+        response
+    }
+}
+/***** Recorder *****/
+
+/***** Masking *****/
+/// 유저 키를 가리는 HMAC-SHA256 키
+///
+/// 키를 모르면 가린 값에서 원래 유저 키를 맞춰 볼 수 없습니다.
+/// 기록 파일끼리 같은 유저를 이어 보려면 같은 키를 쓰세요.
+#[derive(Clone)]
+pub struct MaskKey(Vec<u8>);
+
+impl MaskKey {
+    pub fn new<K: Into<Vec<u8>>>(key: K) -> Self {
+        MaskKey(key.into())
+    }
+
+    /// OS 난수로 만든 32바이트 키: OS 난수를 읽지 못하면 panic합니다.
+    pub fn random() -> Self {
+        let mut key = vec![0; 32];
+        getrandom::getrandom(&mut key).expect("OS 난수를 읽지 못했습니다");
+        MaskKey(key)
+    }
+
+    /// `masked:<HMAC 앞 16바이트>`: 이미 `masked:`로 시작해도 다시 가립니다.
+    pub fn mask(&self, value: &str) -> String {
+        let mac = HMAC::mac(value.as_bytes(), &self.0);
+        let mut masked = String::from("masked:");
+        for byte in &mac[..16] {
+            masked.push_str(&format!("{:02x}", byte));
+        }
+        masked
+    }
+}
+
+impl fmt::Debug for MaskKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MaskKey(..)")
+    }
+}
+
+/// user.id와 properties의 유저 키를 `masked:<해시>`로 바꿉니다.
+///
+/// 같은 키로는 같은 유저 키가 항상 같은 값으로 가려지므로 기록 안에서 유저 구분은 유지됩니다.
+pub fn mask_user_keys(request: &mut SkillRequest, key: &MaskKey) {
+    let user = &mut request.user_request.user;
+    if !user.id.is_empty() {
+        user.id = key.mask(&user.id);
+    }
+    for (name, value) in user.properties.iter_mut() {
+        if is_user_key(name) {
+            if let Value::String(s) = value {
+                *s = key.mask(s);
+            }
+        }
+    }
+}
+
+fn is_user_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    key.ends_with("userkey") || key.ends_with("userid") || key == "id"
+}
+/***** Masking *****/
+
+/***** Replay *****/
+#[derive(Debug)]
+pub enum ReplayError {
+    Io(io::Error),
+    /// 줄 번호는 1부터
+    Parse {
+        line: usize,
+        source: serde_json::Error,
+    },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReplayError::Io(e) => write!(f, "{}", e),
+            ReplayError::Parse { line, source } => write!(f, "line {}: {}", line, source),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl From<io::Error> for ReplayError {
+    fn from(e: io::Error) -> Self {
+        ReplayError::Io(e)
+    }
+}
+
+/// 기록된 JSONL을 읽어 현재 Handler에 다시 보내 봅니다.
+pub struct Replay {
+    exchanges: Vec<(usize, Exchange)>,
+}
+
+impl Replay {
+    /// 빈 줄은 건너뜁니다.
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self, ReplayError> {
+        let mut exchanges = Vec::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let exchange = serde_json::from_str(&line).map_err(|source| ReplayError::Parse {
+                line: i + 1,
+                source,
+            })?;
+            exchanges.push((i + 1, exchange));
+        }
+        Ok(Replay { exchanges })
+    }
+
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, ReplayError> {
+        Replay::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    pub fn exchanges(&self) -> impl Iterator<Item = &Exchange> {
+        self.exchanges.iter().map(|(_, exchange)| exchange)
+    }
+
+    pub fn run<H: Handler + ?Sized>(&self, handler: &H) -> ReplayReport {
+        self.run_with(|request| handler.handle(request).build())
+    }
+
+    /// 응답 JSON을 직접 만들어 비교합니다. (예: 스킬 서버에 HTTP로 보내기)
+    pub fn run_with<F: FnMut(&SkillRequest) -> Value>(&self, mut respond: F) -> ReplayReport {
+        let changed = self
+            .exchanges
+            .iter()
+            .filter_map(|(line, exchange)| {
+                let current = respond(&exchange.request);
+                if current == exchange.response {
+                    None
+                } else {
+                    Some(Changed {
+                        line: *line,
+                        utterance: exchange.request.utterance().to_string(),
+                        recorded: exchange.response.clone(),
+                        current,
+                    })
+                }
+            })
+            .collect();
+
+        ReplayReport {
+            total: self.exchanges.len(),
+            changed,
+        }
+    }
+}
+
+/// 응답이 바뀐 기록
+#[derive(Debug)]
+pub struct Changed {
+    pub line: usize,
+    pub utterance: String,
+    pub recorded: Value,
+    pub current: Value,
+}
+
+#[derive(Debug)]
+pub struct ReplayReport {
+    pub total: usize,
+    pub changed: Vec<Changed>,
+}
+
+impl ReplayReport {
+    /// 바뀐 응답이 없으면 true
+    pub fn is_clean(&self) -> bool {
+        self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for ReplayReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{}개 중 {}개 응답이 바뀜",
+            self.total,
+            self.changed.len()
+        )?;
+        for changed in &self.changed {
+            writeln!(f, "- line {} `{}`", changed.line, changed.utterance)?;
+            writeln!(f, "  recorded: {}", changed.recorded)?;
+            writeln!(f, "  current:  {}", changed.current)?;
+        }
+        Ok(())
+    }
+}
+/***** Replay *****/
@@ -0,0 +1,373 @@
+//! 카카오톡 말풍선처럼 보이는 HTML 미리보기
+//!
+//! 외부 파일 없이 열리는 HTML 한 장을 만듭니다.
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//!
+//! let mut result = Template::new();
+//! result.push_output(SimpleText::new("안녕하세요").build());
+//!
+//! let html = result.render_html();
+//! assert!(html.contains("안녕하세요"));
+//! ```
+use crate::components::basics::*;
+use crate::components::buttons::Button;
+use crate::components::cards::*;
+use std::fmt::Write;
+
+const STYLE: &str = r#"
+body { margin: 0; background: #b2c7d9; font-family: -apple-system, "Apple SD Gothic Neo", "Malgun Gothic", sans-serif; font-size: 14px; color: #191919; }
+.kakao-gallery { display: flex; flex-wrap: wrap; gap: 24px; padding: 24px; align-items: flex-start; }
+.kakao-phone { width: 360px; background: #b2c7d9; border-radius: 12px; }
+.kakao-phone > h2 { margin: 0; padding: 10px 14px; font-size: 13px; background: #a9bdce; border-radius: 12px 12px 0 0; word-break: break-all; }
+.kakao-chat { padding: 14px 10px; }
+.kakao-error { margin: 14px; padding: 10px; background: #fff0f0; color: #c00; border-radius: 8px; white-space: pre-wrap; }
+.kakao-row { display: flex; gap: 8px; margin-bottom: 8px; }
+.kakao-profile { flex: none; width: 36px; height: 36px; border-radius: 14px; background: #fee500; }
+.kakao-outputs { min-width: 0; flex: 1; }
+.kakao-bubble { display: inline-block; max-width: 260px; margin-bottom: 6px; padding: 8px 10px; background: #fff; border-radius: 4px 14px 14px 14px; white-space: pre-wrap; word-break: break-all; }
+.kakao-image { max-width: 240px; margin-bottom: 6px; border-radius: 10px; overflow: hidden; display: block; }
+.kakao-image img { display: block; width: 100%; }
+.kakao-carousel { display: flex; gap: 8px; overflow-x: auto; scroll-snap-type: x mandatory; padding-bottom: 6px; margin-bottom: 6px; }
+.kakao-carousel > .kakao-card { flex: none; scroll-snap-align: start; }
+.kakao-carousel-header { flex: none; width: 240px; background: #fff; border-radius: 10px; padding: 10px; }
+.kakao-card { width: 240px; margin-bottom: 6px; background: #fff; border-radius: 10px; overflow: hidden; }
+.kakao-card img { display: block; width: 100%; background: #eee; }
+.kakao-card .body { padding: 10px 12px; }
+.kakao-card .title { font-weight: bold; margin-bottom: 4px; white-space: pre-wrap; word-break: break-all; }
+.kakao-card .desc { color: #666; white-space: pre-wrap; word-break: break-all; }
+.kakao-card .head { padding: 10px 12px; font-weight: bold; border-bottom: 1px solid #eee; }
+.kakao-list-item { display: flex; gap: 8px; padding: 8px 12px; border-top: 1px solid #f2f2f2; }
+.kakao-list-item .text { flex: 1; min-width: 0; }
+.kakao-list-item img { width: 40px; height: 40px; border-radius: 4px; object-fit: cover; }
+.kakao-price .original { color: #999; text-decoration: line-through; margin-right: 4px; }
+.kakao-price .rate { color: #f25b4c; font-weight: bold; margin-right: 4px; }
+.kakao-price .final { font-weight: bold; font-size: 16px; }
+.kakao-item-row { display: flex; justify-content: space-between; gap: 8px; padding: 2px 12px; }
+.kakao-item-row .label { color: #999; }
+.kakao-item-summary { font-weight: bold; border-top: 1px solid #eee; padding-top: 6px; margin-top: 4px; }
+.kakao-buttons { display: flex; flex-direction: column; gap: 4px; padding: 8px 12px 10px; }
+.kakao-buttons.horizontal { flex-direction: row; }
+.kakao-button { flex: 1; display: block; padding: 8px; background: #f5f5f5; border-radius: 6px; text-align: center; color: #191919; text-decoration: none; }
+.kakao-quick-replies { display: flex; gap: 6px; overflow-x: auto; padding: 4px 0 4px 44px; }
+.kakao-quick-reply { flex: none; padding: 6px 12px; background: #fff; border: 1px solid #ddd; border-radius: 16px; font-size: 13px; }
+"#;
+
+impl Template {
+    /// 카카오톡 말풍선 모양의 HTML 페이지
+    pub fn render_html(&self) -> String {
+        page("kakao-rs preview", &phone(None, &fragment(self)))
+    }
+}
+
+/// 스타일이 들어간 HTML 문서로 감쌉니다.
+pub fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"ko\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape(title),
+        STYLE,
+        body
+    )
+}
+
+/// 채팅방 하나: 제목(파일 이름 등)과 내용
+pub fn phone(title: Option<&str>, content: &str) -> String {
+    let title = title
+        .map(|title| format!("<h2>{}</h2>", escape(title)))
+        .unwrap_or_default();
+    format!(
+        "<div class=\"kakao-phone\">{}<div class=\"kakao-chat\">{}</div></div>\n",
+        title, content
+    )
+}
+
+/// 응답을 불러올 수 없을 때 보여줄 내용
+pub fn error(message: &str) -> String {
+    format!("<div class=\"kakao-error\">{}</div>", escape(message))
+}
+
+/// 스타일 없이 말풍선 부분만
+pub fn fragment(template: &Template) -> String {
+    let mut out = String::new();
+    out.push_str(
+        "<div class=\"kakao-row\"><div class=\"kakao-profile\"></div><div class=\"kakao-outputs\">",
+    );
+    for output in &template.template.outputs {
+        self::output(&mut out, output);
+    }
+    out.push_str("</div></div>");
+
+    if !template.template.quick_replies.is_empty() {
+        out.push_str("<div class=\"kakao-quick-replies\">");
+        for qr in &template.template.quick_replies {
+            let _ = write!(
+                out,
+                "<span class=\"kakao-quick-reply\" title=\"{}\">{}</span>",
+                escape(&qr.message_text),
+                escape(&qr.label)
+            );
+        }
+        out.push_str("</div>");
+    }
+    out
+}
+
+/// HTML 특수문자 처리
+pub(crate) fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// http(s) 링크만 href/src로 씁니다.
+fn safe_url(url: &str) -> Option<String> {
+    let lower = url.trim().to_ascii_lowercase();
+    if lower.starts_with("http://") || lower.starts_with("https://") {
+        Some(escape(url.trim()))
+    } else {
+        None
+    }
+}
+
+fn image(out: &mut String, url: &str) {
+    if let Some(url) = safe_url(url) {
+        let _ = write!(out, "<img src=\"{}\" alt=\"\" loading=\"lazy\">", url);
+    }
+}
+
+fn output(out: &mut String, output: &Types) {
+    match output {
+        Types::SimpleTxt(simple) => {
+            let _ = write!(
+                out,
+                "<div class=\"kakao-bubble\">{}</div>",
+                escape(&simple.simple_text.text)
+            );
+        }
+        Types::SimpleImg(simple) => {
+            out.push_str("<div class=\"kakao-image\">");
+            if let Some(url) = safe_url(&simple.simple_image.image_url) {
+                let _ = write!(
+                    out,
+                    "<img src=\"{}\" alt=\"{}\">",
+                    url,
+                    escape(&simple.simple_image.alt_text)
+                );
+            }
+            out.push_str("</div>");
+        }
+        Types::List(list) => list_card(out, &list.list_card),
+        Types::Basic(basic) => basic_card(out, &basic.content),
+        Types::Commerce(commerce) => commerce_card(out, &commerce.content),
+        Types::Item(item) => item_card(out, &item.content),
+        Types::Carousel(carousel) => self::carousel(out, &carousel.carousel),
+    }
+}
+
+fn card(out: &mut String, card: &Card) {
+    match card {
+        Card::Basic(basic) => basic_card(out, basic),
+        Card::Commerce(commerce) => commerce_card(out, commerce),
+        Card::Item(item) => item_card(out, item),
+        Card::List(list) => list_card(out, list),
+    }
+}
+
+fn carousel(out: &mut String, carousel: &CarouselContent) {
+    out.push_str("<div class=\"kakao-carousel\">");
+    if let Some(header) = &carousel.header {
+        out.push_str("<div class=\"kakao-carousel-header\">");
+        let _ = write!(
+            out,
+            "<div class=\"title\"><b>{}</b></div><div class=\"desc\">{}</div>",
+            escape(&header.title),
+            escape(&header.description)
+        );
+        out.push_str("</div>");
+    }
+    for item in &carousel.items {
+        card(out, item);
+    }
+    out.push_str("</div>");
+}
+
+fn buttons(out: &mut String, buttons: &[Button], layout: Option<&str>) {
+    if buttons.is_empty() {
+        return;
+    }
+    let class = if layout == Some("horizontal") {
+        "kakao-buttons horizontal"
+    } else {
+        "kakao-buttons"
+    };
+    let _ = write!(out, "<div class=\"{}\">", class);
+    for button in buttons {
+        let href = match button.action.as_str() {
+            "webLink" => button.web_link_url.as_deref().and_then(safe_url),
+            "phone" => button
+                .phone_number
+                .as_deref()
+                .map(|number| format!("tel:{}", escape(number))),
+            _ => None,
+        };
+        match href {
+            Some(href) => {
+                let _ = write!(
+                    out,
+                    "<a class=\"kakao-button\" href=\"{}\" target=\"_blank\" rel=\"noopener\">{}</a>",
+                    href,
+                    escape(&button.label)
+                );
+            }
+            None => {
+                let _ = write!(
+                    out,
+                    "<span class=\"kakao-button\" data-action=\"{}\">{}</span>",
+                    escape(&button.action),
+                    escape(&button.label)
+                );
+            }
+        }
+    }
+    out.push_str("</div>");
+}
+
+fn list_card(out: &mut String, list: &ListCardContent) {
+    out.push_str("<div class=\"kakao-card\">");
+    let _ = write!(
+        out,
+        "<div class=\"head\">{}</div>",
+        escape(&list.header.title)
+    );
+    for item in &list.items {
+        out.push_str("<div class=\"kakao-list-item\"><div class=\"text\">");
+        let _ = write!(out, "<div class=\"title\">{}</div>", escape(&item.title));
+        if let Some(description) = &item.description {
+            let _ = write!(out, "<div class=\"desc\">{}</div>", escape(description));
+        }
+        out.push_str("</div>");
+        if let Some(url) = &item.image_url {
+            image(out, url);
+        }
+        out.push_str("</div>");
+    }
+    buttons(out, &list.buttons, Some("horizontal"));
+    out.push_str("</div>");
+}
+
+fn basic_card(out: &mut String, basic: &BasicCardContent) {
+    out.push_str("<div class=\"kakao-card\">");
+    image(out, &basic.thumbnail.image_url);
+    if basic.title.is_some() || basic.description.is_some() {
+        out.push_str("<div class=\"body\">");
+        if let Some(title) = &basic.title {
+            let _ = write!(out, "<div class=\"title\">{}</div>", escape(title));
+        }
+        if let Some(description) = &basic.description {
+            let _ = write!(out, "<div class=\"desc\">{}</div>", escape(description));
+        }
+        out.push_str("</div>");
+    }
+    buttons(out, &basic.buttons, None);
+    out.push_str("</div>");
+}
+
+fn commerce_card(out: &mut String, commerce: &CommerceCardContent) {
+    out.push_str("<div class=\"kakao-card\">");
+    if let Some(thumbnail) = commerce.thumbnails.first() {
+        image(out, &thumbnail.image_url);
+    }
+    out.push_str("<div class=\"body\">");
+    let _ = write!(
+        out,
+        "<div class=\"desc\">{}</div>",
+        escape(&commerce.description)
+    );
+
+    let won = |price| escape(&commerce.currency.format(price));
+    let discounted = commerce.sale_price();
+
+    out.push_str("<div class=\"kakao-price\">");
+    match discounted {
+        Some(discounted) => {
+            let _ = write!(
+                out,
+                "<span class=\"original\">{}</span>",
+                won(commerce.price)
+            );
+            if let Some(rate) = commerce.discount_rate {
+                let _ = write!(out, "<span class=\"rate\">{}%</span>", rate);
+            }
+            let _ = write!(out, "<span class=\"final\">{}</span>", won(discounted));
+        }
+        None => {
+            let _ = write!(out, "<span class=\"final\">{}</span>", won(commerce.price));
+        }
+    }
+    out.push_str("</div></div>");
+    buttons(out, &commerce.buttons, None);
+    out.push_str("</div>");
+}
+
+fn item_card(out: &mut String, item: &ItemCardContent) {
+    out.push_str("<div class=\"kakao-card\">");
+    if let Some(head) = &item.head {
+        let _ = write!(out, "<div class=\"head\">{}</div>", escape(&head.title));
+    }
+    if let Some(thumbnail) = &item.thumbnail {
+        image(out, &thumbnail.image_url);
+    }
+    out.push_str("<div class=\"body\">");
+    if let Some(image_title) = &item.image_title {
+        let _ = write!(
+            out,
+            "<div class=\"title\">{}</div>",
+            escape(&image_title.title)
+        );
+        if let Some(description) = &image_title.description {
+            let _ = write!(out, "<div class=\"desc\">{}</div>", escape(description));
+        }
+    }
+    out.push_str("</div>");
+
+    for row in &item.item_list {
+        let _ = write!(
+            out,
+            "<div class=\"kakao-item-row\"><span class=\"label\">{}</span><span>{}</span></div>",
+            escape(&row.title),
+            escape(&row.description)
+        );
+    }
+    if let Some(summary) = &item.item_list_summary {
+        let _ = write!(
+            out,
+            "<div class=\"kakao-item-row kakao-item-summary\"><span>{}</span><span>{}</span></div>",
+            escape(&summary.title),
+            escape(&summary.description)
+        );
+    }
+    if item.title.is_some() || item.description.is_some() {
+        out.push_str("<div class=\"body\">");
+        if let Some(title) = &item.title {
+            let _ = write!(out, "<div class=\"title\">{}</div>", escape(title));
+        }
+        if let Some(description) = &item.description {
+            let _ = write!(out, "<div class=\"desc\">{}</div>", escape(description));
+        }
+        out.push_str("</div>");
+    }
+    buttons(out, &item.buttons, item.button_layout.as_deref());
+    out.push_str("</div>");
+}
@@ -0,0 +1,12 @@
+//! # kakao-rs
+//! Template을 사람이 읽을 수 있는 형태로 그립니다.
+//!
+//!  - **terminal**: 로그, CLI, 테스트 실패 메시지용 박스 카드
+//!  - **html**: 카카오톡 말풍선 모양의 미리보기 페이지
+//!  - **text**: 메일, 슬랙, 문자용 일반 텍스트/마크다운
+pub mod html;
+pub mod terminal;
+pub mod text;
+
+pub use terminal::TerminalRenderer;
+pub use text::TextRenderer;
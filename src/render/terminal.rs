@@ -0,0 +1,386 @@
+//! 터미널용 박스 카드 렌더러
+//!
+//! 한글 등 두 칸을 차지하는 글자도 폭을 맞춰 그립니다.
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//!
+//! let mut result = Template::new();
+//! result.push_output(SimpleText::new("안녕하세요").build());
+//! result.push_quick_reply(QuickReply::new("오늘", "오늘 공지 보여줘"));
+//!
+//! println!("{}", result.render_terminal());
+//! // ┌────────────────────────────────────┐
+//! // │ 안녕하세요                         │
+//! // └────────────────────────────────────┘
+//! // (1 오늘)
+//! ```
+use crate::components::basics::*;
+use crate::components::buttons::Button;
+use crate::components::cards::*;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// 박스 안쪽 폭(칸 수)과 버튼/바로가기 번호 표시를 정할 수 있습니다.
+///
+/// 번호는 [`scenario::choices`](crate::scenario::choices)와 같은 순서이고, 링크와 전화 버튼에는 붙지 않습니다.
+pub struct TerminalRenderer {
+    width: usize,
+    numbering: bool,
+}
+
+impl Default for TerminalRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TerminalRenderer {
+    /// 기본 폭 36칸, 번호 표시
+    #[inline]
+    pub fn new() -> Self {
+        TerminalRenderer {
+            width: 36,
+            numbering: true,
+        }
+    }
+
+    /// 박스 안쪽 폭 (최소 10칸)
+    pub fn put_width(&mut self, width: usize) -> &mut Self {
+        self.width = width.max(10);
+        self
+    }
+
+    pub fn put_numbering(&mut self, numbering: bool) -> &mut Self {
+        self.numbering = numbering;
+        self
+    }
+
+    pub fn render(&self, template: &Template) -> String {
+        let mut canvas = Canvas {
+            out: String::new(),
+            width: self.width,
+            numbering: self.numbering,
+            counter: 0,
+        };
+        for output in &template.template.outputs {
+            canvas.output(output);
+        }
+        canvas.quick_replies(&template.template.quick_replies);
+        canvas.out
+    }
+}
+
+with!(TerminalRenderer {
+    with_width => put_width(width: usize);
+    with_numbering => put_numbering(numbering: bool);
+});
+
+impl Template {
+    /// 기본 설정의 [TerminalRenderer]로 그립니다.
+    pub fn render_terminal(&self) -> String {
+        TerminalRenderer::new().render(self)
+    }
+}
+
+/// 문자열이 차지하는 칸 수
+pub(crate) fn display_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// 폭에 맞춰 줄바꿈: 단어 단위로, 너무 긴 단어는 글자 단위로 자릅니다.
+pub(crate) fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let paragraph = paragraph.replace('\t', " ");
+        let body = paragraph.trim_start_matches(' ');
+        // 들여쓰기는 이어지는 줄에도 유지
+        let indent = " ".repeat((paragraph.len() - body.len()).min(width / 2));
+        let width = width - indent.len();
+        let start = lines.len();
+
+        let mut line = String::new();
+        let mut line_width = 0;
+        for word in body.split(' ') {
+            let word_width = display_width(word);
+            let space = usize::from(!line.is_empty());
+            if line_width + space + word_width <= width {
+                if space == 1 {
+                    line.push(' ');
+                }
+                line.push_str(word);
+                line_width += space + word_width;
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            for c in word.chars() {
+                let w = c.width().unwrap_or(0);
+                if line_width + w > width {
+                    lines.push(std::mem::take(&mut line));
+                    line_width = 0;
+                }
+                line.push(c);
+                line_width += w;
+            }
+        }
+        lines.push(line);
+        for line in &mut lines[start..] {
+            line.insert_str(0, &indent);
+        }
+    }
+    lines
+}
+
+struct Canvas {
+    out: String,
+    width: usize,
+    numbering: bool,
+    counter: usize,
+}
+
+impl Canvas {
+    fn top(&mut self) {
+        self.border('┌', '┐');
+    }
+
+    fn separator(&mut self) {
+        self.border('├', '┤');
+    }
+
+    fn bottom(&mut self) {
+        self.border('└', '┘');
+    }
+
+    fn border(&mut self, left: char, right: char) {
+        self.out.push(left);
+        self.out.push_str(&"─".repeat(self.width + 2));
+        self.out.push(right);
+        self.out.push('\n');
+    }
+
+    fn text(&mut self, text: &str) {
+        for line in wrap(text, self.width) {
+            let pad = self.width.saturating_sub(display_width(&line));
+            self.out.push_str("│ ");
+            self.out.push_str(&line);
+            self.out.push_str(&" ".repeat(pad));
+            self.out.push_str(" │\n");
+        }
+    }
+
+    /// 왼쪽 제목, 오른쪽 설명
+    fn columns(&mut self, left: &str, right: &str) {
+        let used = display_width(left) + display_width(right);
+        if used < self.width && !left.contains('\n') && !right.contains('\n') {
+            let line = format!("{}{}{}", left, " ".repeat(self.width - used), right);
+            self.text(&line);
+        } else {
+            self.text(&format!("{}: {}", left, right));
+        }
+    }
+
+    fn caption(&mut self, text: &str) {
+        self.out.push_str(text);
+        self.out.push('\n');
+    }
+
+    fn thumbnail(&mut self, thumbnail: &ThumbNail) {
+        if !thumbnail.image_url.is_empty() {
+            self.text(&format!("[이미지] {}", thumbnail.image_url));
+        }
+    }
+
+    fn buttons(&mut self, buttons: &[Button]) {
+        if buttons.is_empty() {
+            return;
+        }
+        self.separator();
+        for button in buttons {
+            // 발화를 보내는 버튼만 번호를 붙입니다.
+            let numbered = self.numbering && button.sends_message();
+            let label = if numbered {
+                self.counter += 1;
+                format!("[{}] {}", self.counter, button.label)
+            } else {
+                format!("[{}]", button.label)
+            };
+            let target = match button.action.as_str() {
+                "webLink" => button
+                    .web_link_url
+                    .as_deref()
+                    .map(|url| format!(" -> {}", url)),
+                "phone" => button
+                    .phone_number
+                    .as_deref()
+                    .map(|n| format!(" (전화 {})", n)),
+                "share" => Some(" (공유)".to_string()),
+                _ => None,
+            };
+            self.text(&format!("{}{}", label, target.unwrap_or_default()));
+        }
+    }
+
+    fn output(&mut self, output: &Types) {
+        match output {
+            Types::SimpleTxt(simple) => {
+                self.top();
+                self.text(&simple.simple_text.text);
+                self.bottom();
+            }
+            Types::SimpleImg(image) => {
+                self.top();
+                self.text(&format!("[이미지] {}", image.simple_image.alt_text));
+                self.text(&image.simple_image.image_url);
+                self.bottom();
+            }
+            Types::List(list) => self.list(&list.list_card),
+            Types::Basic(basic) => self.basic(&basic.content),
+            Types::Commerce(commerce) => self.commerce(&commerce.content),
+            Types::Item(item) => self.item(&item.content),
+            Types::Carousel(carousel) => self.carousel(&carousel.carousel),
+        }
+    }
+
+    fn card(&mut self, card: &Card) {
+        match card {
+            Card::Basic(basic) => self.basic(basic),
+            Card::Commerce(commerce) => self.commerce(commerce),
+            Card::Item(item) => self.item(item),
+            Card::List(list) => self.list(list),
+        }
+    }
+
+    fn carousel(&mut self, carousel: &CarouselContent) {
+        if let Some(header) = &carousel.header {
+            self.top();
+            self.text(&header.title);
+            if !header.description.is_empty() {
+                self.separator();
+                self.text(&header.description);
+            }
+            self.bottom();
+        }
+        let total = carousel.items.len();
+        for (i, card) in carousel.items.iter().enumerate() {
+            self.caption(&format!("< {}/{} >", i + 1, total));
+            self.card(card);
+        }
+    }
+
+    fn list(&mut self, list: &ListCardContent) {
+        self.top();
+        self.text(&list.header.title);
+        self.separator();
+        for item in &list.items {
+            self.text(&format!("* {}", item.title));
+            if let Some(description) = &item.description {
+                self.text(&format!("  {}", description));
+            }
+        }
+        self.buttons(&list.buttons);
+        self.bottom();
+    }
+
+    fn basic(&mut self, basic: &BasicCardContent) {
+        self.top();
+        self.thumbnail(&basic.thumbnail);
+        if let Some(title) = &basic.title {
+            self.text(title);
+        }
+        if let Some(description) = &basic.description {
+            self.text(description);
+        }
+        self.buttons(&basic.buttons);
+        self.bottom();
+    }
+
+    fn commerce(&mut self, commerce: &CommerceCardContent) {
+        self.top();
+        for thumbnail in &commerce.thumbnails {
+            self.thumbnail(thumbnail);
+        }
+        self.text(&commerce.description);
+
+        let won = |price| commerce.currency.format(price);
+        match commerce.sale_price() {
+            Some(discounted) => {
+                let rate = commerce
+                    .discount_rate
+                    .map(|rate| format!(" ({}%)", rate))
+                    .unwrap_or_default();
+                self.text(&format!(
+                    "정가 {} / 할인가 {}{}",
+                    won(commerce.price),
+                    won(discounted),
+                    rate
+                ));
+            }
+            None => self.text(&won(commerce.price)),
+        }
+        self.buttons(&commerce.buttons);
+        self.bottom();
+    }
+
+    fn item(&mut self, item: &ItemCardContent) {
+        self.top();
+        if let Some(head) = &item.head {
+            self.text(&head.title);
+            self.separator();
+        }
+        if let Some(thumbnail) = &item.thumbnail {
+            self.thumbnail(thumbnail);
+        }
+        if let Some(image_title) = &item.image_title {
+            self.text(&image_title.title);
+            if let Some(description) = &image_title.description {
+                self.text(description);
+            }
+        }
+        if !item.item_list.is_empty() {
+            self.separator();
+            for row in &item.item_list {
+                self.columns(&row.title, &row.description);
+            }
+        }
+        if let Some(summary) = &item.item_list_summary {
+            self.separator();
+            self.columns(&summary.title, &summary.description);
+        }
+        if item.title.is_some() || item.description.is_some() {
+            self.separator();
+            if let Some(title) = &item.title {
+                self.text(title);
+            }
+            if let Some(description) = &item.description {
+                self.text(description);
+            }
+        }
+        self.buttons(&item.buttons);
+        self.bottom();
+    }
+
+    fn quick_replies(&mut self, quick_replies: &[QuickReply]) {
+        let mut line = String::new();
+        for qr in quick_replies {
+            self.counter += 1;
+            let chip = if self.numbering {
+                format!("({} {})", self.counter, qr.label)
+            } else {
+                format!("({})", qr.label)
+            };
+            if !line.is_empty() && display_width(&line) + 1 + display_width(&chip) > self.width + 4
+            {
+                self.caption(&std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&chip);
+        }
+        if !line.is_empty() {
+            self.caption(&line);
+        }
+    }
+}
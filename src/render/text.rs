@@ -0,0 +1,342 @@
+//! 메일, 슬랙, 문자 알림용 텍스트 렌더러
+//!
+//! 리스트 카드, 캐러셀 등을 읽기 쉬운 한국어 텍스트로 풀어 씁니다.
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//! use kakao_rs::render::TextRenderer;
+//!
+//! let mut result = Template::new();
+//! let mut list_card = ListCard::new("공지");
+//! list_card.push_item(ListItem::new("수강신청 안내").with_link("https://ajou.ac.kr"));
+//! result.push_output(list_card.build());
+//!
+//! assert_eq!(result.to_plain_text(), "공지\n• 수강신청 안내 (https://ajou.ac.kr)");
+//! assert_eq!(
+//!     result.to_markdown(),
+//!     "**공지**\n- [수강신청 안내](https://ajou.ac.kr)"
+//! );
+//!
+//! let text = TextRenderer::new().with_links(false).render(&result);
+//! assert_eq!(text, "공지\n• 수강신청 안내");
+//! ```
+use crate::components::basics::*;
+use crate::components::buttons::Button;
+use crate::components::cards::*;
+
+/// 출력 형식과 포함할 내용을 정할 수 있습니다.
+pub struct TextRenderer {
+    markdown: bool,
+    bullet: String,
+    separator: String,
+    links: bool,
+    images: bool,
+    buttons: bool,
+    quick_replies: bool,
+}
+
+impl Default for TextRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextRenderer {
+    /// 일반 텍스트, 링크/버튼/바로가기 포함, 이미지 제외
+    #[inline]
+    pub fn new() -> Self {
+        TextRenderer {
+            markdown: false,
+            bullet: "• ".to_string(),
+            separator: "\n\n".to_string(),
+            links: true,
+            images: false,
+            buttons: true,
+            quick_replies: true,
+        }
+    }
+
+    /// 마크다운 (굵은 제목, `[라벨](URL)` 링크)
+    #[inline]
+    pub fn markdown() -> Self {
+        TextRenderer {
+            markdown: true,
+            bullet: "- ".to_string(),
+            ..Self::new()
+        }
+    }
+
+    /// 리스트 항목 앞 기호 (기본 "• ", 마크다운 "- ")
+    pub fn put_bullet<S: Into<String>>(&mut self, bullet: S) -> &mut Self {
+        self.bullet = bullet.into();
+        self
+    }
+
+    /// 말풍선, 캐러셀 카드 사이 구분 (기본 빈 줄)
+    pub fn put_separator<S: Into<String>>(&mut self, separator: S) -> &mut Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// 링크 URL 표시
+    pub fn put_links(&mut self, links: bool) -> &mut Self {
+        self.links = links;
+        self
+    }
+
+    /// 이미지 URL 표시
+    pub fn put_images(&mut self, images: bool) -> &mut Self {
+        self.images = images;
+        self
+    }
+
+    pub fn put_buttons(&mut self, buttons: bool) -> &mut Self {
+        self.buttons = buttons;
+        self
+    }
+
+    pub fn put_quick_replies(&mut self, quick_replies: bool) -> &mut Self {
+        self.quick_replies = quick_replies;
+        self
+    }
+
+    pub fn render(&self, template: &Template) -> String {
+        let mut blocks: Vec<String> = Vec::new();
+        for output in &template.template.outputs {
+            match output {
+                Types::Carousel(carousel) => blocks.extend(self.carousel(&carousel.carousel)),
+                output => blocks.push(self.output(output)),
+            }
+        }
+
+        let quick_replies = &template.template.quick_replies;
+        if self.quick_replies && !quick_replies.is_empty() {
+            let labels: Vec<String> = quick_replies
+                .iter()
+                .map(|qr| self.escape(&qr.label))
+                .collect();
+            blocks.push(format!("바로가기: {}", labels.join(", ")));
+        }
+
+        blocks.retain(|block| !block.is_empty());
+        blocks.join(&self.separator)
+    }
+
+    /// 마크다운 특수문자 처리
+    fn escape(&self, text: &str) -> String {
+        if !self.markdown {
+            return text.to_string();
+        }
+        let mut out = String::with_capacity(text.len());
+        for c in text.chars() {
+            if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']' | '~') {
+                out.push('\\');
+            }
+            out.push(c);
+        }
+        out
+    }
+
+    fn bold(&self, text: &str) -> String {
+        if self.markdown {
+            format!("**{}**", self.escape(text))
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// 링크가 있으면 "제목 (URL)" 또는 "[제목](URL)"
+    fn link(&self, label: &str, url: Option<&str>) -> String {
+        match url.filter(|url| self.links && !url.is_empty()) {
+            Some(url) if self.markdown => format!("[{}]({})", self.escape(label), url),
+            Some(url) => format!("{} ({})", label, url),
+            None => self.escape(label),
+        }
+    }
+
+    fn image(&self, lines: &mut Vec<String>, alt: &str, url: &str) {
+        if !self.images || url.is_empty() {
+            return;
+        }
+        if self.markdown {
+            lines.push(format!("![{}]({})", self.escape(alt), url));
+        } else if alt.is_empty() {
+            lines.push(format!("[이미지] {}", url));
+        } else {
+            lines.push(format!("[이미지] {} ({})", alt, url));
+        }
+    }
+
+    fn buttons(&self, lines: &mut Vec<String>, buttons: &[Button]) {
+        if !self.buttons {
+            return;
+        }
+        for button in buttons {
+            let line = match button.action.as_str() {
+                "webLink" => self.link(&button.label, button.web_link_url.as_deref()),
+                "phone" => match &button.phone_number {
+                    Some(number) => format!("{} (전화 {})", self.escape(&button.label), number),
+                    None => self.escape(&button.label),
+                },
+                _ => self.escape(&button.label),
+            };
+            lines.push(format!("▶ {}", line));
+        }
+    }
+
+    fn output(&self, output: &Types) -> String {
+        match output {
+            Types::SimpleTxt(simple) => self.escape(&simple.simple_text.text),
+            Types::SimpleImg(simple) => {
+                let mut lines = Vec::new();
+                if self.images {
+                    self.image(
+                        &mut lines,
+                        &simple.simple_image.alt_text,
+                        &simple.simple_image.image_url,
+                    );
+                } else {
+                    lines.push(format!(
+                        "[이미지] {}",
+                        self.escape(&simple.simple_image.alt_text)
+                    ));
+                }
+                lines.join("\n")
+            }
+            Types::List(list) => self.list(&list.list_card),
+            Types::Basic(basic) => self.basic(&basic.content),
+            Types::Commerce(commerce) => self.commerce(&commerce.content),
+            Types::Item(item) => self.item(&item.content),
+            Types::Carousel(carousel) => self.carousel(&carousel.carousel).join(&self.separator),
+        }
+    }
+
+    fn card(&self, card: &Card) -> String {
+        match card {
+            Card::Basic(basic) => self.basic(basic),
+            Card::Commerce(commerce) => self.commerce(commerce),
+            Card::Item(item) => self.item(item),
+            Card::List(list) => self.list(list),
+        }
+    }
+
+    /// 캐러셀은 카드마다 "(1/3)"을 붙여 따로 씁니다.
+    fn carousel(&self, carousel: &CarouselContent) -> Vec<String> {
+        let mut blocks = Vec::new();
+        if let Some(header) = &carousel.header {
+            let mut lines = vec![self.bold(&header.title)];
+            if !header.description.is_empty() {
+                lines.push(self.escape(&header.description));
+            }
+            blocks.push(lines.join("\n"));
+        }
+        let total = carousel.items.len();
+        for (i, card) in carousel.items.iter().enumerate() {
+            let card = self.card(card);
+            if total > 1 {
+                blocks.push(format!("({}/{}) {}", i + 1, total, card));
+            } else {
+                blocks.push(card);
+            }
+        }
+        blocks
+    }
+
+    fn list(&self, list: &ListCardContent) -> String {
+        let mut lines = vec![self.bold(&list.header.title)];
+        for item in &list.items {
+            let link = item.link.as_ref().and_then(Link::url);
+            let mut line = format!("{}{}", self.bullet, self.link(&item.title, link));
+            if let Some(description) = &item.description {
+                line.push_str(" - ");
+                line.push_str(&self.escape(description));
+            }
+            lines.push(line);
+        }
+        self.buttons(&mut lines, &list.buttons);
+        lines.join("\n")
+    }
+
+    fn basic(&self, basic: &BasicCardContent) -> String {
+        let mut lines = Vec::new();
+        if let Some(title) = &basic.title {
+            lines.push(self.bold(title));
+        }
+        if let Some(description) = &basic.description {
+            lines.push(self.escape(description));
+        }
+        self.image(&mut lines, "", &basic.thumbnail.image_url);
+        self.buttons(&mut lines, &basic.buttons);
+        lines.join("\n")
+    }
+
+    fn commerce(&self, commerce: &CommerceCardContent) -> String {
+        let mut lines = vec![self.bold(&commerce.description)];
+        lines.push(commerce.price_text());
+        for thumbnail in &commerce.thumbnails {
+            self.image(&mut lines, "", &thumbnail.image_url);
+        }
+        self.buttons(&mut lines, &commerce.buttons);
+        lines.join("\n")
+    }
+
+    fn item(&self, item: &ItemCardContent) -> String {
+        let mut lines = Vec::new();
+        if let Some(head) = &item.head {
+            lines.push(self.bold(&head.title));
+        }
+        if let Some(image_title) = &item.image_title {
+            lines.push(self.escape(&image_title.title));
+            if let Some(description) = &image_title.description {
+                lines.push(self.escape(description));
+            }
+        }
+        if let Some(thumbnail) = &item.thumbnail {
+            self.image(&mut lines, "", &thumbnail.image_url);
+        }
+        for row in &item.item_list {
+            lines.push(format!(
+                "{}{}: {}",
+                self.bullet,
+                self.escape(&row.title),
+                self.escape(&row.description)
+            ));
+        }
+        if let Some(summary) = &item.item_list_summary {
+            lines.push(format!(
+                "{}: {}",
+                self.bold(&summary.title),
+                self.escape(&summary.description)
+            ));
+        }
+        if let Some(title) = &item.title {
+            lines.push(self.bold(title));
+        }
+        if let Some(description) = &item.description {
+            lines.push(self.escape(description));
+        }
+        self.buttons(&mut lines, &item.buttons);
+        lines.join("\n")
+    }
+}
+
+with!(TextRenderer {
+    with_bullet => put_bullet(bullet: impl Into<String>);
+    with_separator => put_separator(separator: impl Into<String>);
+    with_links => put_links(links: bool);
+    with_images => put_images(images: bool);
+    with_buttons => put_buttons(buttons: bool);
+    with_quick_replies => put_quick_replies(quick_replies: bool);
+});
+
+impl Template {
+    /// 기본 설정의 [TextRenderer]로 쓴 일반 텍스트
+    pub fn to_plain_text(&self) -> String {
+        TextRenderer::new().render(self)
+    }
+
+    /// 기본 설정의 [TextRenderer::markdown]으로 쓴 마크다운
+    pub fn to_markdown(&self) -> String {
+        TextRenderer::markdown().render(self)
+    }
+}
@@ -0,0 +1,212 @@
+//! # kakao-rs
+//! 스킬 서버로 들어오는 [스킬 페이로드](https://i.kakao.com/docs/skill-payload-format)
+//!
+//! ```
+//! use kakao_rs::request::SkillRequest;
+//!
+//! let data = r#"{"intent":{"id":"a","name":"공지"},"userRequest":{"timezone":"Asia/Seoul","params":{},"block":{"id":"a","name":"공지"},"utterance":"오늘 공지","lang":"ko","user":{"id":"abc","type":"botUserKey","properties":{}}},"bot":{"id":"b","name":"아주봇"},"action":{"name":"공지","clientExtra":null,"params":{},"id":"c","detailParams":{}}}"#;
+//! let request: SkillRequest = serde_json::from_str(data).unwrap();
+//!
+//! assert_eq!(request.utterance(), "오늘 공지");
+//! ```
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+// 카카오가 필드를 추가하는 경우가 많아서 요청 쪽은 deny_unknown_fields를 쓰지 않습니다.
+
+/***** Request *****/
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+/// [SkillPayload](https://i.kakao.com/docs/skill-payload-format): intent, userRequest, bot, action, contexts
+pub struct SkillRequest {
+    #[serde(default)]
+    pub intent: Intent,
+    pub user_request: UserRequest,
+    #[serde(default)]
+    pub bot: Bot,
+    #[serde(default)]
+    pub action: Action,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub contexts: Vec<Context>,
+}
+
+impl SkillRequest {
+    /// SkillRequest(발화문)
+    #[inline]
+    pub fn new<S: Into<String>>(utterance: S) -> Self {
+        SkillRequest {
+            user_request: UserRequest {
+                utterance: utterance.into(),
+                timezone: "Asia/Seoul".to_string(),
+                lang: Some("ko".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// 유저 발화문: userRequest.utterance
+    pub fn utterance(&self) -> &str {
+        &self.user_request.utterance
+    }
+
+    /// 현재 블록 id: userRequest.block.id
+    pub fn block_id(&self) -> &str {
+        &self.user_request.block.id
+    }
+
+    /// 유저 언어: userRequest.lang (ko, en 등)
+    pub fn lang(&self) -> Option<&str> {
+        self.user_request.lang.as_deref()
+    }
+
+    /// 버튼/바로가기 응답의 extra: action.clientExtra
+    pub fn client_extra<S: AsRef<str>>(&self, key: S) -> Option<&Value> {
+        self.action
+            .client_extra
+            .as_ref()
+            .and_then(|extra| extra.get(key.as_ref()))
+    }
+
+    /// 이름으로 context 찾기
+    pub fn context<S: AsRef<str>>(&self, name: S) -> Option<&Context> {
+        self.contexts.iter().find(|c| c.name == name.as_ref())
+    }
+
+    pub fn put_block<S: Into<String>, N: Into<String>>(&mut self, id: S, name: N) -> &mut Self {
+        let (id, name) = (id.into(), name.into());
+        self.intent = Intent {
+            id: id.clone(),
+            name: name.clone(),
+        };
+        self.user_request.block = Block { id, name };
+        self
+    }
+
+    pub fn put_user<S: Into<String>>(&mut self, id: S) -> &mut Self {
+        self.user_request.user.id = id.into();
+        self
+    }
+}
+
+with!(SkillRequest {
+    with_block => put_block(id: impl Into<String>, name: impl Into<String>);
+    with_user => put_user(id: impl Into<String>);
+});
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Intent {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct UserRequest {
+    #[serde(default)]
+    pub timezone: String,
+    #[serde(default)]
+    pub params: HashMap<String, Value>,
+    #[serde(default)]
+    pub block: Block,
+    pub utterance: String,
+    #[serde(default)]
+    pub lang: Option<String>,
+    #[serde(default)]
+    pub user: User,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Block {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+/// [User](https://i.kakao.com/docs/skill-payload-format#parameter-specification): id, type (botUserKey, ...), properties
+pub struct User {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub r#type: String,
+    #[serde(default)]
+    pub properties: Map<String, Value>,
+}
+
+impl Default for User {
+    fn default() -> Self {
+        User {
+            id: String::new(),
+            r#type: "botUserKey".to_string(),
+            properties: Map::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Bot {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+/// [Action](https://i.kakao.com/docs/skill-payload-format#action): 스킬 파라미터와 clientExtra
+pub struct Action {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub params: HashMap<String, Value>,
+    #[serde(default)]
+    pub detail_params: HashMap<String, Value>,
+    #[serde(default)]
+    pub client_extra: Option<Map<String, Value>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+/// 요청에 담겨 오는 context: name, lifespan, ttl, params
+pub struct Context {
+    pub name: String,
+    #[serde(rename = "lifespan", alias = "lifeSpan", default)]
+    pub life_span: i32,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub ttl: Option<i32>,
+    #[serde(default)]
+    pub params: HashMap<String, ContextParam>,
+}
+
+impl Context {
+    /// `params[key].value`
+    pub fn param<S: AsRef<str>>(&self, key: S) -> Option<&str> {
+        self.params.get(key.as_ref()).map(|p| p.value.as_str())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ContextParam {
+    #[serde(default)]
+    pub value: String,
+    #[serde(default)]
+    pub resolved_value: String,
+}
+/***** Request *****/
@@ -0,0 +1,541 @@
+//! # kakao-rs
+//! 여러 턴의 대화를 Handler에 차례로 보내 보는 시나리오 테스트 도우미
+//!
+//! 이전 응답의 context와 바로가기/버튼의 blockId, extra를 다음 요청에 자동으로 실어 보냅니다.
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//!
+//! let router = Router::new()
+//!     .utterance("공지", |_: &SkillRequest| {
+//!         let mut result = Template::new();
+//!         result.push_output(SimpleText::new("학과를 선택하세요.").build());
+//!         result.push_quick_reply(QuickReply::new("소프트웨어학과", "소프트웨어학과").with_extra("major", "sw"));
+//!         result
+//!     })
+//!     .fallback(|req: &SkillRequest| {
+//!         let major = req.client_extra("major").and_then(|v| v.as_str()).unwrap_or("");
+//!         let mut result = Template::new();
+//!         result.push_output(SimpleText::new(format!("{} 공지입니다.", major)).build());
+//!         result
+//!     });
+//!
+//! Scenario::new(&router)
+//!     .user("공지")
+//!     .expect_text("학과를 선택하세요.")
+//!     .quick_reply("소프트웨어학과")
+//!     .expect_text("sw 공지입니다.")
+//!     .run();
+//! ```
+use crate::components::basics::*;
+use crate::components::buttons::Button;
+use crate::handler::Handler;
+use crate::request::{Context, ContextParam, SkillRequest};
+use serde_json::Value;
+use std::fmt;
+
+/// 유저가 할 수 있는 행동: 발화, 바로가기 선택, 버튼 클릭
+///
+/// 라벨로 고를 때 같은 라벨이 여러 개면 [ActionError::Ambiguous]입니다. 이때는 위치로 고르세요.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserAction {
+    Utterance(String),
+    QuickReply(String),
+    Button(String),
+    /// 몇 번째 바로가기 (0부터)
+    QuickReplyAt(usize),
+    ButtonAt(ButtonAt),
+}
+
+impl fmt::Display for UserAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UserAction::Utterance(text) => write!(f, "발화 `{}`", text),
+            UserAction::QuickReply(label) => write!(f, "바로가기 `{}`", label),
+            UserAction::Button(label) => write!(f, "버튼 `{}`", label),
+            UserAction::QuickReplyAt(index) => write!(f, "바로가기 {}번", index + 1),
+            UserAction::ButtonAt(at) => write!(f, "버튼 ({})", at),
+        }
+    }
+}
+
+/// 응답 안의 버튼 위치 (모두 0부터)
+///
+/// `card`는 캐로셀 안의 카드 순서이고, 캐로셀이 아니면 0입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ButtonAt {
+    pub output: usize,
+    pub card: usize,
+    pub button: usize,
+}
+
+impl ButtonAt {
+    #[inline]
+    pub fn new(output: usize, card: usize, button: usize) -> Self {
+        ButtonAt {
+            output,
+            card,
+            button,
+        }
+    }
+}
+
+impl fmt::Display for ButtonAt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "출력 {}, 카드 {}, 버튼 {}",
+            self.output + 1,
+            self.card + 1,
+            self.button + 1
+        )
+    }
+}
+
+/// 이전 응답으로는 다음 요청을 만들 수 없을 때
+#[derive(Debug)]
+pub enum ActionError {
+    NoResponse,
+    QuickReplyNotFound(String),
+    ButtonNotFound(String),
+    NotAMessage(String),
+    /// 같은 라벨의 버튼이나 바로가기가 여러 개
+    Ambiguous(String),
+}
+
+impl fmt::Display for ActionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ActionError::NoResponse => write!(f, "이전 응답이 없습니다"),
+            ActionError::QuickReplyNotFound(label) => {
+                write!(f, "이전 응답에 `{}` 바로가기가 없습니다", label)
+            }
+            ActionError::ButtonNotFound(label) => {
+                write!(f, "이전 응답에 `{}` 버튼이 없습니다", label)
+            }
+            ActionError::NotAMessage(label) => {
+                write!(f, "`{}` 버튼은 발화를 보내지 않습니다", label)
+            }
+            ActionError::Ambiguous(label) => {
+                write!(f, "`{}` 라벨이 여러 개입니다. 위치로 고르세요", label)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ActionError {}
+
+/// 응답의 모든 버튼과 위치: 출력, 캐로셀 카드, 버튼 순서
+pub fn buttons(template: &Template) -> Vec<(ButtonAt, &Button)> {
+    let mut buttons = Vec::new();
+    for (output, types) in template.template.outputs.iter().enumerate() {
+        match types {
+            Types::Carousel(carousel) => {
+                for (card, item) in carousel.carousel.items.iter().enumerate() {
+                    for (button, b) in item.buttons().iter().enumerate() {
+                        buttons.push((ButtonAt::new(output, card, button), b));
+                    }
+                }
+            }
+            _ => {
+                for (button, b) in types.buttons().into_iter().enumerate() {
+                    buttons.push((ButtonAt::new(output, 0, button), b));
+                }
+            }
+        }
+    }
+    buttons
+}
+
+/// 응답에서 고를 수 있는 행동: 출력 순서대로 발화를 보내는 버튼, 그 다음 바로가기
+///
+/// 링크, 전화 등 발화를 보내지 않는 버튼은 빠집니다.
+/// 터미널 렌더러가 붙이는 번호와 같은 순서입니다.
+pub fn choices(template: &Template) -> Vec<UserAction> {
+    buttons(template)
+        .into_iter()
+        .filter(|(_, button)| button.sends_message())
+        .map(|(at, _)| UserAction::ButtonAt(at))
+        .chain((0..template.template.quick_replies.len()).map(UserAction::QuickReplyAt))
+        .collect()
+}
+
+/// 라벨이 하나만 맞아야 합니다: 여러 개면 [ActionError::Ambiguous]
+fn only_one<T>(
+    mut found: impl Iterator<Item = T>,
+    label: &str,
+    not_found: fn(String) -> ActionError,
+) -> Result<T, ActionError> {
+    match (found.next(), found.next()) {
+        (Some(item), None) => Ok(item),
+        (Some(_), Some(_)) => Err(ActionError::Ambiguous(label.to_string())),
+        (None, _) => Err(not_found(label.to_string())),
+    }
+}
+
+/***** Session *****/
+/// 한 유저의 대화 상태: 살아있는 context와 마지막 응답
+///
+/// 응답의 context는 lifeSpan 만큼의 다음 요청에 실립니다.
+#[derive(Debug)]
+pub struct Session {
+    user_id: String,
+    contexts: Vec<Context>,
+    last: Option<Template>,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Session {
+    #[inline]
+    pub fn new() -> Self {
+        Session {
+            user_id: "kakao-rs-user".to_string(),
+            contexts: Vec::new(),
+            last: None,
+        }
+    }
+
+    pub fn put_user<S: Into<String>>(&mut self, id: S) -> &mut Self {
+        self.user_id = id.into();
+        self
+    }
+
+    /// 다음 요청에 실릴 context
+    pub fn contexts(&self) -> &[Context] {
+        &self.contexts
+    }
+
+    pub fn last_response(&self) -> Option<&Template> {
+        self.last.as_ref()
+    }
+
+    /// 유저 행동으로 다음 스킬 요청을 만듭니다.
+    pub fn request(&self, action: &UserAction) -> Result<SkillRequest, ActionError> {
+        let mut request = match action {
+            UserAction::Utterance(text) => SkillRequest::new(text.as_str()),
+            UserAction::QuickReply(label) => {
+                Session::pick_quick_reply(self.find_quick_reply(label)?)
+            }
+            UserAction::QuickReplyAt(index) => {
+                Session::pick_quick_reply(self.quick_reply_at(*index)?)
+            }
+            UserAction::Button(label) => Session::click(self.find_button(label)?)?,
+            UserAction::ButtonAt(at) => Session::click(self.button_at(*at)?)?,
+        }
+        .with_user(self.user_id.as_str());
+
+        request.contexts = self.contexts.clone();
+        Ok(request)
+    }
+
+    /// 응답을 받아 context를 갱신합니다.
+    pub fn absorb(&mut self, response: Template) {
+        for context in &mut self.contexts {
+            context.life_span -= 1;
+        }
+        self.contexts.retain(|c| c.life_span > 0);
+
+        if let Some(control) = &response.context {
+            for value in &control.values {
+                self.contexts.retain(|c| c.name != value.name());
+                if value.life_span() > 0 {
+                    self.contexts.push(Session::to_request_context(value));
+                }
+            }
+        }
+        self.last = Some(response);
+    }
+
+    /// request + handle + absorb
+    pub fn send<H: Handler + ?Sized>(
+        &mut self,
+        handler: &H,
+        action: &UserAction,
+    ) -> Result<&Template, ActionError> {
+        let request = self.request(action)?;
+        self.absorb(handler.handle(&request));
+        Ok(self.last.as_ref().unwrap())
+    }
+
+    fn pick_quick_reply(qr: &QuickReply) -> SkillRequest {
+        let utterance = match qr.message_text() {
+            "" => qr.label(),
+            text => text,
+        };
+        let mut request = SkillRequest::new(utterance);
+        if let Some(id) = qr.block_id() {
+            request.put_block(id, id);
+        }
+        request.action.client_extra = qr.extra().cloned();
+        request
+    }
+
+    fn click(button: &Button) -> Result<SkillRequest, ActionError> {
+        if !button.sends_message() {
+            return Err(ActionError::NotAMessage(button.label().to_string()));
+        }
+        let mut request =
+            SkillRequest::new(button.message_text().unwrap_or_else(|| button.label()));
+        if let Some(id) = button.block_id() {
+            request.put_block(id, id);
+        }
+        request.action.client_extra = button.extra().cloned();
+        Ok(request)
+    }
+
+    fn last(&self) -> Result<&Template, ActionError> {
+        self.last.as_ref().ok_or(ActionError::NoResponse)
+    }
+
+    fn find_quick_reply(&self, label: &str) -> Result<&QuickReply, ActionError> {
+        let found = self
+            .last()?
+            .template
+            .quick_replies
+            .iter()
+            .filter(|qr| qr.label() == label);
+        only_one(found, label, ActionError::QuickReplyNotFound)
+    }
+
+    fn quick_reply_at(&self, index: usize) -> Result<&QuickReply, ActionError> {
+        self.last()?
+            .template
+            .quick_replies
+            .get(index)
+            .ok_or_else(|| ActionError::QuickReplyNotFound(format!("{}번", index + 1)))
+    }
+
+    fn find_button(&self, label: &str) -> Result<&Button, ActionError> {
+        let found = buttons(self.last()?)
+            .into_iter()
+            .map(|(_, button)| button)
+            .filter(|button| button.label() == label);
+        only_one(found, label, ActionError::ButtonNotFound)
+    }
+
+    fn button_at(&self, at: ButtonAt) -> Result<&Button, ActionError> {
+        buttons(self.last()?)
+            .into_iter()
+            .find(|(position, _)| *position == at)
+            .map(|(_, button)| button)
+            .ok_or_else(|| ActionError::ButtonNotFound(at.to_string()))
+    }
+
+    fn to_request_context(value: &ContextValue) -> Context {
+        Context {
+            name: value.name().to_string(),
+            life_span: value.life_span(),
+            ttl: value.ttl(),
+            params: value
+                .params()
+                .iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        Value::String(s) => s.to_string(),
+                        other => other.to_string(),
+                    };
+                    (
+                        key.to_string(),
+                        ContextParam {
+                            resolved_value: value.clone(),
+                            value,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+with!(Session {
+    with_user => put_user(id: impl Into<String>);
+});
+/***** Session *****/
+
+/***** Scenario *****/
+type Expectation<'a> = Box<dyn Fn(&Template) -> Result<(), String> + 'a>;
+
+struct Step<'a> {
+    action: UserAction,
+    expectations: Vec<Expectation<'a>>,
+}
+
+/// 시나리오 실패: 몇 번째 단계의 어떤 행동에서 실패했는지
+#[derive(Debug)]
+pub struct ScenarioError {
+    pub step: usize,
+    pub action: UserAction,
+    pub message: String,
+}
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "step {} ({}): {}", self.step, self.action, self.message)
+    }
+}
+
+impl std::error::Error for ScenarioError {}
+
+/// 유저 행동과 응답 검사를 차례로 적고 run()으로 실행합니다.
+pub struct Scenario<'a> {
+    handler: &'a dyn Handler,
+    session: Session,
+    steps: Vec<Step<'a>>,
+}
+
+impl<'a> Scenario<'a> {
+    #[inline]
+    pub fn new<H: Handler>(handler: &'a H) -> Self {
+        Scenario {
+            handler,
+            session: Session::new(),
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn put_session(&mut self, session: Session) -> &mut Self {
+        self.session = session;
+        self
+    }
+
+    /// [`put_session`](Self::put_session)의 체이닝 버전
+    #[must_use]
+    pub fn with_session(mut self, session: Session) -> Self {
+        self.put_session(session);
+        self
+    }
+
+    /// 유저 발화
+    pub fn user<S: Into<String>>(self, utterance: S) -> Self {
+        self.step(UserAction::Utterance(utterance.into()))
+    }
+
+    /// 이전 응답의 바로가기 선택 (라벨)
+    pub fn quick_reply<S: Into<String>>(self, label: S) -> Self {
+        self.step(UserAction::QuickReply(label.into()))
+    }
+
+    /// 이전 응답의 버튼 클릭 (라벨)
+    pub fn button<S: Into<String>>(self, label: S) -> Self {
+        self.step(UserAction::Button(label.into()))
+    }
+
+    /// 이전 응답의 `index`번째 바로가기 선택 (0부터)
+    pub fn quick_reply_at(self, index: usize) -> Self {
+        self.step(UserAction::QuickReplyAt(index))
+    }
+
+    /// 이전 응답의 버튼 클릭 (위치, 모두 0부터)
+    pub fn button_at(self, output: usize, card: usize, button: usize) -> Self {
+        self.step(UserAction::ButtonAt(ButtonAt::new(output, card, button)))
+    }
+
+    pub fn step(mut self, action: UserAction) -> Self {
+        self.steps.push(Step {
+            action,
+            expectations: Vec::new(),
+        });
+        self
+    }
+
+    /// 직전 행동의 응답 검사 (assert! 등으로 panic)
+    pub fn expect<F: Fn(&Template) + 'a>(self, check: F) -> Self {
+        self.check(move |template| {
+            check(template);
+            Ok(())
+        })
+    }
+
+    /// SimpleText 중 하나가 `text`를 포함해야 합니다.
+    pub fn expect_text<S: Into<String>>(self, text: S) -> Self {
+        let text = text.into();
+        self.check(move |template| {
+            let found = template.template.outputs.iter().any(|output| match output {
+                Types::SimpleTxt(simple) => simple.text().contains(text.as_str()),
+                _ => false,
+            });
+            if found {
+                Ok(())
+            } else {
+                Err(format!("`{}` 텍스트가 없습니다", text))
+            }
+        })
+    }
+
+    /// `label` 바로가기가 있어야 합니다.
+    pub fn expect_quick_reply<S: Into<String>>(self, label: S) -> Self {
+        let label = label.into();
+        self.check(move |template| {
+            if template
+                .template
+                .quick_replies
+                .iter()
+                .any(|qr| qr.label() == label)
+            {
+                Ok(())
+            } else {
+                Err(format!("`{}` 바로가기가 없습니다", label))
+            }
+        })
+    }
+
+    /// 응답이 `name` context를 설정해야 합니다.
+    pub fn expect_context<S: Into<String>>(self, name: S) -> Self {
+        let name = name.into();
+        self.check(move |template| {
+            let found = template
+                .context
+                .as_ref()
+                .map(|control| control.values.iter().any(|v| v.name() == name))
+                .unwrap_or(false);
+            if found {
+                Ok(())
+            } else {
+                Err(format!("`{}` context가 없습니다", name))
+            }
+        })
+    }
+
+    fn check<F: Fn(&Template) -> Result<(), String> + 'a>(mut self, check: F) -> Self {
+        self.steps
+            .last_mut()
+            .expect("expect는 user/quick_reply/button 뒤에 써야 합니다")
+            .expectations
+            .push(Box::new(check));
+        self
+    }
+
+    /// 실패하면 panic 합니다.
+    pub fn run(self) -> Session {
+        match self.try_run() {
+            Ok(session) => session,
+            Err(e) => panic!("scenario failed: {}", e),
+        }
+    }
+
+    pub fn try_run(self) -> Result<Session, ScenarioError> {
+        let mut session = self.session;
+        for (i, step) in self.steps.into_iter().enumerate() {
+            let fail = |message: String| ScenarioError {
+                step: i + 1,
+                action: step.action.clone(),
+                message,
+            };
+            let response = session
+                .send(self.handler, &step.action)
+                .map_err(|e| fail(e.to_string()))?;
+            for expectation in &step.expectations {
+                expectation(response).map_err(|message| {
+                    fail(format!("{}\n{}", message, response.render_terminal()))
+                })?;
+            }
+        }
+        Ok(session)
+    }
+}
+/***** Scenario *****/
@@ -0,0 +1,52 @@
+//! # kakao-rs
+//! 응답/스킬 요청의 JSON Schema (`schema` feature)
+//!
+//! 다른 언어로 만든 응답도 이 크레이트가 받아들이는 형식인지 검사할 수 있습니다.
+//! 말풍선, 카드, 버튼 개수 제한은 [limits](crate::limits)를 따릅니다.
+//! 글자 수 제한은 [Validate](crate::validate::Validate)로 검사하세요.
+//!
+//! ```
+//! let schema = kakao_rs::schema::template();
+//! let json = serde_json::to_string_pretty(&schema).unwrap();
+//! assert!(json.contains("\"maxItems\": 3"));
+//! ```
+use crate::components::basics::Template;
+use crate::request::SkillRequest;
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{InstanceType, RootSchema, Schema, SchemaObject};
+
+pub use schemars;
+
+/// [Template] 응답 스키마
+pub fn template() -> RootSchema {
+    schemars::schema_for!(Template)
+}
+
+/// [SkillRequest] 스킬 요청 스키마
+pub fn skill_request() -> RootSchema {
+    schemars::schema_for!(SkillRequest)
+}
+
+fn string_enum(values: &[&str]) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        enum_values: Some(values.iter().map(|value| json!(value)).collect()),
+        ..Default::default()
+    }
+    .into()
+}
+
+/// Button action: webLink, message, phone, share, block
+pub(crate) fn button_action(_: &mut SchemaGenerator) -> Schema {
+    string_enum(&["webLink", "message", "phone", "share", "block"])
+}
+
+/// CommerceCard currency: won
+pub(crate) fn currency(_: &mut SchemaGenerator) -> Schema {
+    string_enum(&["won"])
+}
+
+/// QuickReply action: message, block
+pub(crate) fn quick_reply_action(_: &mut SchemaGenerator) -> Schema {
+    string_enum(&["message", "block"])
+}
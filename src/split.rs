@@ -0,0 +1,169 @@
+//! # kakao-rs
+//! 1000자가 넘는 글을 SimpleText 여러 개로 나눕니다.
+//!
+//! 빈 줄 → 줄바꿈 → 문장 끝 → 띄어쓰기 순서로 자를 곳을 찾습니다. (글자 수는 바이트가 아니라 글자 단위)
+//! 말풍선 3개로도 모자라면 남은 글을 "더보기" 바로가기의 extra에 담고,
+//! 다음 스킬 요청에서 [TextSplitter::remainder]로 꺼내 이어서 보냅니다.
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//! use kakao_rs::split::TextSplitter;
+//!
+//! let notice = "수강신청 안내입니다. ".repeat(400);
+//! let result = TextSplitter::new().template(&notice);
+//!
+//! assert!(result.validate().is_ok());
+//! assert_eq!(result.template.outputs.len(), 3);
+//! assert_eq!(result.template.quick_replies[0].label(), "더보기");
+//! ```
+use crate::components::basics::*;
+use crate::limits;
+use crate::request::SkillRequest;
+
+/// 남은 글을 담는 extra 키
+pub const MORE_KEY: &str = "more";
+
+/// 문장 끝으로 보는 문자
+const SENTENCE_ENDS: [char; 5] = ['.', '?', '!', '。', '…'];
+
+/// 긴 글을 나누는 설정
+pub struct TextSplitter {
+    max_chars: usize,
+    max_outputs: usize,
+    more_label: String,
+    block_id: Option<String>,
+}
+
+impl Default for TextSplitter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextSplitter {
+    /// 말풍선마다 1000자, 3개까지, 바로가기 "더보기"
+    #[inline]
+    pub fn new() -> Self {
+        TextSplitter {
+            max_chars: limits::SIMPLE_TEXT,
+            max_outputs: limits::OUTPUTS,
+            more_label: "더보기".to_string(),
+            block_id: None,
+        }
+    }
+
+    /// 말풍선 하나의 최대 글자 수 (1 ~ 1000)
+    pub fn put_max_chars(&mut self, max_chars: usize) -> &mut Self {
+        self.max_chars = max_chars.clamp(1, limits::SIMPLE_TEXT);
+        self
+    }
+
+    /// 한 번에 보낼 말풍선 수 (1 ~ 3, 다른 출력과 함께 보낼 때 줄이세요)
+    pub fn put_max_outputs(&mut self, max_outputs: usize) -> &mut Self {
+        self.max_outputs = max_outputs.clamp(1, limits::OUTPUTS);
+        self
+    }
+
+    pub fn put_more_label<S: Into<String>>(&mut self, label: S) -> &mut Self {
+        self.more_label = label.into();
+        self
+    }
+
+    /// "더보기"를 발화 대신 이 블록으로 보냅니다.
+    pub fn put_block_id<S: Into<String>>(&mut self, id: S) -> &mut Self {
+        self.block_id = Some(id.into());
+        self
+    }
+
+    /// 말풍선 수 제한 없이 모두 나눕니다.
+    pub fn split<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        let mut chunks = Vec::new();
+        let mut rest = text.trim();
+        while !rest.is_empty() {
+            let (chunk, next) = self.cut(rest);
+            chunks.push(chunk);
+            rest = next;
+        }
+        chunks
+    }
+
+    /// 바로 보낼 수 있는 응답: SimpleText 최대 3개 + 남으면 "더보기"
+    pub fn template(&self, text: &str) -> Template {
+        let mut result = Template::new();
+        let mut rest = text.trim();
+        for _ in 0..self.max_outputs {
+            if rest.is_empty() {
+                break;
+            }
+            let (chunk, next) = self.cut(rest);
+            result.push_output(SimpleText::new(chunk).build());
+            rest = next;
+        }
+
+        if !rest.is_empty() {
+            let mut qr = QuickReply::new(self.more_label.as_str(), self.more_label.as_str())
+                .with_extra(MORE_KEY, rest);
+            if let Some(id) = &self.block_id {
+                qr.put_action("block").put_block_id(id.as_str());
+            }
+            result.push_quick_reply(qr);
+        }
+        result
+    }
+
+    /// "더보기"로 넘어온 남은 글
+    pub fn remainder<'a>(&self, request: &'a SkillRequest) -> Option<&'a str> {
+        request
+            .client_extra(MORE_KEY)
+            .and_then(|value| value.as_str())
+    }
+
+    /// 앞에서 max_chars 안쪽의 자를 곳을 찾아 (말풍선, 나머지)로 나눕니다.
+    fn cut<'a>(&self, text: &'a str) -> (&'a str, &'a str) {
+        let limit = match text.char_indices().nth(self.max_chars) {
+            Some((limit, _)) => limit,
+            None => return (text, ""),
+        };
+        let window = &text[..limit];
+
+        // 너무 앞에서 자르면 말풍선이 낭비되므로 뒤쪽 절반에서만 찾습니다.
+        let at = [
+            window.rfind("\n\n"),
+            window.rfind('\n'),
+            sentence_end(text, limit),
+            window.rfind(char::is_whitespace),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|&at| at > 0 && at >= limit / 2)
+        .unwrap_or(limit);
+
+        (text[..at].trim_end(), text[at..].trim_start())
+    }
+}
+
+with!(TextSplitter {
+    with_max_chars => put_max_chars(max_chars: usize);
+    with_max_outputs => put_max_outputs(max_outputs: usize);
+    with_more_label => put_more_label(label: impl Into<String>);
+    with_block_id => put_block_id(id: impl Into<String>);
+});
+
+/// limit 안에서 마지막 문장 끝 (뒤에 공백이 오는 .?! 다음)
+fn sentence_end(text: &str, limit: usize) -> Option<usize> {
+    let mut found = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let end = i + c.len_utf8();
+        if end > limit {
+            break;
+        }
+        if SENTENCE_ENDS.contains(&c) {
+            match chars.peek() {
+                Some((_, next)) if next.is_whitespace() => found = Some(end),
+                _ => {}
+            }
+        }
+    }
+    found
+}
@@ -0,0 +1,130 @@
+//! # kakao-rs
+//! 카카오 글자 수 제한에 맞추는 텍스트 도우미
+//!
+//! 한글은 바이트가 아니라 글자 단위로 세고, 이모지(👨‍👩‍👧)나 조합 문자는 중간에서 자르지 않습니다.
+//! 제한은 [Validate](crate::validate::Validate)와 같은 기준(char 수)으로 맞춥니다.
+//!
+//! 빌더는 글을 그대로 두고, 제한에 맞춰 줄이려면 다 만든 응답에 [Fit::fit]을 부릅니다.
+//!
+//! ```
+//! use kakao_rs::text;
+//!
+//! assert_eq!(text::len("공지👨‍👩‍👧"), 3);
+//! assert_eq!(text::truncate("2022학년도 1학기 수강신청 안내", 10), "2022학년도 1…");
+//! assert_eq!(text::normalize_whitespace("  수강신청\t\t안내 \r\n\n\n일정  "), "수강신청 안내\n\n일정");
+//! ```
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// 줄인 글 끝에 붙는 말줄임표 (1자)
+pub const ELLIPSIS: &str = "…";
+
+/// 보이는 글자 수 (이모지 하나는 1자)
+pub fn len(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+
+/// 카카오 제한(`max`자) 안에 들어가는지
+pub fn fits(text: &str, max: usize) -> bool {
+    text.chars().nth(max).is_none()
+}
+
+/// `max`자 이내로 자릅니다. 이모지, 조합 문자는 통째로 빼고 자릅니다.
+pub fn cut(text: &str, max: usize) -> &str {
+    let mut count = 0;
+    let mut end = 0;
+    for (i, grapheme) in text.grapheme_indices(true) {
+        count += grapheme.chars().count();
+        if count > max {
+            break;
+        }
+        end = i + grapheme.len();
+    }
+    &text[..end]
+}
+
+/// `max`자를 넘으면 잘라서 "…"을 붙입니다. (말줄임표 포함 `max`자)
+pub fn truncate(text: &str, max: usize) -> Cow<'_, str> {
+    if fits(text, max) {
+        return Cow::Borrowed(text);
+    }
+    if max == 0 {
+        return Cow::Borrowed("");
+    }
+    Cow::Owned(format!("{}{}", cut(text, max - 1).trim_end(), ELLIPSIS))
+}
+
+/// 연속 공백은 한 칸, 연속 빈 줄은 하나로 줄이고 앞뒤 공백을 지웁니다.
+pub fn normalize_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut blank = false;
+    for line in text.lines() {
+        let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+        if line.is_empty() {
+            blank = !out.is_empty();
+            continue;
+        }
+        if !out.is_empty() {
+            out.push_str(if blank { "\n\n" } else { "\n" });
+        }
+        out.push_str(&line);
+        blank = false;
+    }
+    out
+}
+
+/// 글자 수 제한에 맞게 줄이기: [limits](crate::limits)를 넘는 글만 [truncate]합니다.
+///
+/// 응답이나 컴포넌트를 다 만든 뒤 그 값에만 부릅니다.
+///
+/// ```
+/// use kakao_rs::prelude::*;
+///
+/// let button = Button::text("수강신청 바로가기 버튼입니다").fitted();
+/// assert_eq!(button.label(), "수강신청 바로가기 버튼입…");
+///
+/// let mut result = Template::new();
+/// result.push_output(SimpleText::new("공지").build());
+/// result.push_quick_reply(QuickReply::new("오늘 올라온 학사 공지사항", "오늘 공지"));
+/// result.fit();
+/// assert!(result.validate().is_ok());
+/// ```
+pub trait Fit {
+    fn fit(&mut self);
+
+    #[must_use]
+    fn fitted(mut self) -> Self
+    where
+        Self: Sized,
+    {
+        self.fit();
+        self
+    }
+}
+
+impl<T: Fit> Fit for [T] {
+    fn fit(&mut self) {
+        for item in self {
+            item.fit();
+        }
+    }
+}
+
+impl<T: Fit> Fit for Vec<T> {
+    fn fit(&mut self) {
+        self.as_mut_slice().fit();
+    }
+}
+
+/// `text`가 `max`자를 넘으면 그 자리에서 줄입니다.
+pub(crate) fn fit(text: &mut String, max: usize) {
+    if let Cow::Owned(truncated) = truncate(text, max) {
+        *text = truncated;
+    }
+}
+
+pub(crate) fn fit_opt(text: &mut Option<String>, max: usize) {
+    if let Some(text) = text {
+        fit(text, max);
+    }
+}
@@ -0,0 +1,263 @@
+//! # kakao-rs
+//! URL 만들기와 카카오 딥링크
+//!
+//! 경로 조각과 쿼리 값은 percent-encoding 됩니다. [Url]은 `Into<String>`이라서
+//! `Button::link`, `with_link` 등에 그대로 넘길 수 있습니다.
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//! use kakao_rs::url::Url;
+//!
+//! let url = Url::new("https://namu.wiki")
+//!     .with_segment("w")
+//!     .with_segment("나연(TWICE)");
+//! assert_eq!(url.to_string(), "https://namu.wiki/w/%EB%82%98%EC%97%B0(TWICE)");
+//!
+//! let url = Url::new("https://ajou.ac.kr/search?page=1")
+//!     .with_query("q", "학사 일정&공지")
+//!     .with_fragment("결과");
+//! assert_eq!(
+//!     url.to_string(),
+//!     "https://ajou.ac.kr/search?page=1&q=%ED%95%99%EC%82%AC%20%EC%9D%BC%EC%A0%95%26%EA%B3%B5%EC%A7%80#%EA%B2%B0%EA%B3%BC"
+//! );
+//!
+//! let button = Button::link("검색", url);
+//! ```
+use std::fmt;
+
+/***** Url *****/
+/// percent-encoding 하면서 URL을 만듭니다.
+///
+/// `base`는 이미 인코딩된 것으로 보고 그대로 씁니다. `base`에 있던 쿼리와 `#fragment`는 유지됩니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Url {
+    base: String,
+    query: Vec<String>,
+    fragment: Option<String>,
+}
+
+impl Url {
+    pub fn new<S: Into<String>>(base: S) -> Self {
+        let mut base = base.into();
+        let fragment = base.find('#').map(|i| {
+            let fragment = base[i + 1..].to_string();
+            base.truncate(i);
+            fragment
+        });
+        let query = match base.find('?') {
+            Some(i) => {
+                let query = base[i + 1..]
+                    .split('&')
+                    .filter(|pair| !pair.is_empty())
+                    .map(String::from)
+                    .collect();
+                base.truncate(i);
+                query
+            }
+            None => Vec::new(),
+        };
+        Url {
+            base,
+            query,
+            fragment,
+        }
+    }
+
+    /// `/조각`을 붙입니다. `/`, `?`, `#`, 한글 등은 인코딩됩니다.
+    pub fn push_segment(&mut self, segment: &str) -> &mut Self {
+        self.push_raw_segment(&encode_segment(segment))
+    }
+
+    pub fn extend_segments<'a, I: IntoIterator<Item = &'a str>>(
+        &mut self,
+        segments: I,
+    ) -> &mut Self {
+        for segment in segments {
+            self.push_segment(segment);
+        }
+        self
+    }
+
+    /// `key=value`를 붙입니다. 키와 값 모두 RFC 3986 unreserved 외에는 인코딩됩니다.
+    pub fn push_query(&mut self, key: &str, value: &str) -> &mut Self {
+        self.query
+            .push(format!("{}={}", encode_query(key), encode_query(value)));
+        self
+    }
+
+    pub fn extend_query<'a, I: IntoIterator<Item = (&'a str, &'a str)>>(
+        &mut self,
+        params: I,
+    ) -> &mut Self {
+        for (key, value) in params {
+            self.push_query(key, value);
+        }
+        self
+    }
+
+    pub fn put_fragment(&mut self, fragment: &str) -> &mut Self {
+        self.fragment = Some(encode_fragment(fragment));
+        self
+    }
+
+    fn push_raw_segment(&mut self, segment: &str) -> &mut Self {
+        if !self.base.ends_with('/') {
+            self.base.push('/');
+        }
+        self.base.push_str(segment);
+        self
+    }
+
+    #[must_use]
+    pub fn with_segment(mut self, segment: &str) -> Self {
+        self.push_segment(segment);
+        self
+    }
+
+    #[must_use]
+    pub fn with_segments<'a, I: IntoIterator<Item = &'a str>>(mut self, segments: I) -> Self {
+        self.extend_segments(segments);
+        self
+    }
+
+    #[must_use]
+    pub fn with_query(mut self, key: &str, value: &str) -> Self {
+        self.push_query(key, value);
+        self
+    }
+
+    #[must_use]
+    pub fn with_fragment(mut self, fragment: &str) -> Self {
+        self.put_fragment(fragment);
+        self
+    }
+}
+
+impl fmt::Display for Url {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.base)?;
+        if !self.query.is_empty() {
+            write!(f, "?{}", self.query.join("&"))?;
+        }
+        if let Some(fragment) = &self.fragment {
+            write!(f, "#{}", fragment)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Url> for String {
+    fn from(url: Url) -> Self {
+        url.to_string()
+    }
+}
+
+/// 쿼리 키/값 인코딩: unreserved(`A-Z a-z 0-9 - . _ ~`) 외에는 모두 `%XX`
+pub fn encode_query(value: &str) -> String {
+    percent(value, b"")
+}
+
+/// 경로 조각 인코딩: unreserved와 `!$&'()*+,;=:@`는 그대로
+///
+/// `.`과 `..`은 브라우저가 현재/상위 경로로 해석하므로 `%2E`로 바꿉니다.
+pub fn encode_segment(value: &str) -> String {
+    match value {
+        "." => "%2E".to_string(),
+        ".." => "%2E%2E".to_string(),
+        _ => percent(value, b"!$&'()*+,;=:@"),
+    }
+}
+
+fn encode_fragment(value: &str) -> String {
+    percent(value, b"!$&'()*+,;=:@/?")
+}
+
+fn percent(value: &str, keep: &[u8]) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ if keep.contains(&byte) => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+/***** Url *****/
+
+/***** Kakao *****/
+/// 카카오맵 링크의 장소: 이름과 WGS84 좌표
+#[derive(Debug, Clone, PartialEq)]
+pub struct Place {
+    pub name: String,
+    pub lat: f64,
+    pub lng: f64,
+}
+
+impl Place {
+    pub fn new<S: Into<String>>(name: S, lat: f64, lng: f64) -> Self {
+        Place {
+            name: name.into(),
+            lat,
+            lng,
+        }
+    }
+
+    /// `이름,위도,경도`: 이름의 `,`도 인코딩됩니다.
+    fn segment(&self) -> String {
+        format!("{},{},{}", encode_query(&self.name), self.lat, self.lng)
+    }
+}
+
+const KAKAO_MAP: &str = "https://map.kakao.com/link";
+
+/// 카카오맵 검색 결과
+///
+/// ```
+/// use kakao_rs::url;
+///
+/// assert_eq!(
+///     url::kakao_map_search("아주대 정문").to_string(),
+///     "https://map.kakao.com/link/search/%EC%95%84%EC%A3%BC%EB%8C%80%20%EC%A0%95%EB%AC%B8"
+/// );
+/// ```
+pub fn kakao_map_search(query: &str) -> Url {
+    Url::new(KAKAO_MAP).with_segments(["search", query])
+}
+
+/// 카카오맵 길찾기: 출발지가 없으면 현재 위치에서 출발합니다.
+///
+/// 카카오내비 앱 스킴(`kakaonavi://`)은 `webLinkUrl`에 쓸 수 없으므로 길안내는 이 링크로 엽니다.
+///
+/// ```
+/// use kakao_rs::url::{self, Place};
+///
+/// let to = Place::new("아주대학교", 37.2830, 127.0435);
+/// assert_eq!(
+///     url::kakao_map_route(None, &to).to_string(),
+///     "https://map.kakao.com/link/to/%EC%95%84%EC%A3%BC%EB%8C%80%ED%95%99%EA%B5%90,37.283,127.0435"
+/// );
+///
+/// let from = Place::new("수원역", 37.2664, 127.0001);
+/// assert_eq!(
+///     url::kakao_map_route(Some(&from), &to).to_string(),
+///     "https://map.kakao.com/link/from/%EC%88%98%EC%9B%90%EC%97%AD,37.2664,127.0001/to/%EC%95%84%EC%A3%BC%EB%8C%80%ED%95%99%EA%B5%90,37.283,127.0435"
+/// );
+/// ```
+pub fn kakao_map_route(from: Option<&Place>, to: &Place) -> Url {
+    let mut url = Url::new(KAKAO_MAP);
+    if let Some(from) = from {
+        url.push_raw_segment("from")
+            .push_raw_segment(&from.segment());
+    }
+    url.push_raw_segment("to").push_raw_segment(&to.segment());
+    url
+}
+
+/// 카카오톡 채널 1:1 채팅 (`_xxxx` 꼴 채널 ID)
+pub fn kakao_channel_chat(channel_id: &str) -> Url {
+    Url::new("https://pf.kakao.com").with_segments([channel_id, "chat"])
+}
+/***** Kakao *****/
@@ -0,0 +1,210 @@
+//! # kakao-rs
+//! 카카오가 거절하는 응답을 보내기 전에 찾아냅니다: 필수 필드, 개수/글자 수 제한, URL과 전화번호 형식
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//!
+//! let mut result = Template::new();
+//! result.push_output(SimpleText::new("가".repeat(1001)).build());
+//!
+//! let errors = result.validate().unwrap_err();
+//! assert_eq!(errors[0].path, "template.outputs[0].simpleText.text");
+//! ```
+//!
+//! 모르는 필드는 응답에 그대로 남지만 `validate_strict`로 찾을 수 있습니다.
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//!
+//! let json = r#"{"version":"2.0","template":{"outputs":[{"simpleText":{"text":"안녕","forwardable":true}}]}}"#;
+//! let result: Template = serde_json::from_str(json).unwrap();
+//! assert_eq!(result.validate(), Ok(()));
+//!
+//! let errors = result.validate_strict().unwrap_err();
+//! assert_eq!(errors[0].path, "template.outputs[0].simpleText.forwardable");
+//! ```
+//!
+//! URL과 전화번호 형식도 같이 검사합니다. ([check](crate::check))
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//!
+//! let result = Template::new().with_output(
+//!     BasicCard::new()
+//!         .with_thumbnail("https://ajou.ac.kr/a.png")
+//!         .with_button(Button::link("열기", "htps://ajou.ac.kr"))
+//!         .build(),
+//! );
+//! let errors = result.validate().unwrap_err();
+//! assert_eq!(errors[0].path, "template.outputs[0].basicCard.buttons[0].webLinkUrl");
+//! ```
+use crate::check::CheckError;
+use serde_json::{Map, Value};
+use std::fmt;
+
+const UNKNOWN_FIELD: &str = "알 수 없는 필드입니다";
+
+/// 문제가 있는 필드의 JSON 경로와 이유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub path: String,
+    pub message: String,
+    kind: Kind,
+}
+
+/// 어느 검사에서 보고하는지
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Invalid,
+    /// `validate_strict`에서만
+    Unknown,
+    /// URL, 전화번호 형식
+    Format,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl ValidationError {
+    /// `validate_strict`에서만 보고하는 모르는 필드인지
+    pub fn is_unknown_field(&self) -> bool {
+        self.kind == Kind::Unknown
+    }
+
+    /// URL/전화번호 형식 오류인지
+    pub fn is_format_error(&self) -> bool {
+        self.kind == Kind::Format
+    }
+}
+
+/// 응답 컴포넌트 검사
+///
+/// `validate_at`만 구현하면 `validate`는 자동으로 생깁니다.
+pub trait Validate {
+    /// `path` 아래에서 찾은 문제를 `errors`에 추가합니다.
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>);
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        collect(self, |error| error.kind != Kind::Unknown)
+    }
+
+    /// `validate`에 더해 이 크레이트가 모르는 필드도 오류로 봅니다.
+    fn validate_strict(&self) -> Result<(), Vec<ValidationError>> {
+        collect(self, |_| true)
+    }
+}
+
+fn collect<T: Validate + ?Sized>(
+    target: &T,
+    keep: impl Fn(&ValidationError) -> bool,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::new();
+    target.validate_at("", &mut errors);
+    errors.retain(keep);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+impl<T: Validate> Validate for [T] {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        for (i, item) in self.iter().enumerate() {
+            item.validate_at(&format!("{}[{}]", path, i), errors);
+        }
+    }
+}
+
+/// `path.field`
+pub(crate) fn join(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+pub(crate) fn error<S: Into<String>>(errors: &mut Vec<ValidationError>, path: String, message: S) {
+    errors.push(ValidationError {
+        path,
+        message: message.into(),
+        kind: Kind::Invalid,
+    });
+}
+
+/// 모르는 필드마다 `path.field`
+pub(crate) fn unknown(errors: &mut Vec<ValidationError>, path: &str, fields: &Map<String, Value>) {
+    for field in fields.keys() {
+        errors.push(ValidationError {
+            path: join(path, field),
+            message: UNKNOWN_FIELD.to_string(),
+            kind: Kind::Unknown,
+        });
+    }
+}
+
+/// 값이 있을 때만 [check](crate::check) 결과를 `path.field`에 보고합니다.
+pub(crate) fn format(
+    errors: &mut Vec<ValidationError>,
+    path: &str,
+    field: &str,
+    value: Option<&str>,
+    check: impl Fn(&str) -> Result<(), CheckError>,
+) {
+    let Some(value) = value.filter(|value| !value.trim().is_empty()) else {
+        return;
+    };
+    if let Err(e) = check(value) {
+        errors.push(ValidationError {
+            path: join(path, field),
+            message: e.to_string(),
+            kind: Kind::Format,
+        });
+    }
+}
+
+pub(crate) fn required(errors: &mut Vec<ValidationError>, path: &str, field: &str, value: &str) {
+    if value.trim().is_empty() {
+        error(errors, join(path, field), "필수 항목입니다");
+    }
+}
+
+pub(crate) fn max_chars(
+    errors: &mut Vec<ValidationError>,
+    path: &str,
+    field: &str,
+    value: &str,
+    max: usize,
+) {
+    let len = value.chars().count();
+    if len > max {
+        error(
+            errors,
+            join(path, field),
+            format!("최대 {}자까지 가능합니다 (현재 {}자)", max, len),
+        );
+    }
+}
+
+pub(crate) fn item_count(
+    errors: &mut Vec<ValidationError>,
+    path: &str,
+    field: &str,
+    len: usize,
+    min: usize,
+    max: usize,
+) {
+    if len < min || len > max {
+        error(
+            errors,
+            join(path, field),
+            format!("{} ~ {}개까지 가능합니다 (현재 {}개)", min, max, len),
+        );
+    }
+}
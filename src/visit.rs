@@ -0,0 +1,464 @@
+//! # kakao-rs
+//! 응답 트리 순회: `Template` → `Types` → `Card` → `Button`/`ThumbNail`/`ListItem`/`QuickReply`
+//!
+//! 필요한 `visit_*`만 구현하면 나머지는 기본 구현이 `walk_*`로 내려갑니다.
+//! 직접 구현한 `visit_*`에서 더 내려가려면 같은 이름의 `walk_*`를 부르세요.
+//!
+//! ```
+//! use kakao_rs::prelude::*;
+//! use kakao_rs::visit::{self, Visitor, VisitorMut};
+//!
+//! let mut result = Template::new();
+//! result.push_output(
+//!     BasicCard::new()
+//!         .with_thumbnail("http://ajou.ac.kr/notice.png")
+//!         .with_button(Button::link("열기", "https://ajou.ac.kr/notice"))
+//!         .build(),
+//! );
+//!
+//! visit::upgrade_https().visit_template_mut(&mut result);
+//! visit::append_query(&[("utm_source", "kakao")]).visit_template_mut(&mut result);
+//!
+//! struct Links(Vec<String>);
+//!
+//! impl Visitor for Links {
+//!     fn visit_button(&mut self, button: &Button) {
+//!         self.0.extend(button.web_link_url().map(String::from));
+//!     }
+//! }
+//!
+//! let mut links = Links(Vec::new());
+//! links.visit_template(&result);
+//! assert_eq!(links.0, ["https://ajou.ac.kr/notice?utm_source=kakao"]);
+//! assert!(result.to_string().contains("https://ajou.ac.kr/notice.png"));
+//! ```
+use crate::components::basics::*;
+use crate::components::buttons::Button;
+use crate::components::cards::*;
+use crate::url::Url;
+
+/***** Visitor *****/
+/// 읽기 전용 순회
+pub trait Visitor {
+    fn visit_template(&mut self, template: &Template) {
+        walk_template(self, template);
+    }
+
+    fn visit_output(&mut self, output: &Types) {
+        walk_output(self, output);
+    }
+
+    fn visit_carousel(&mut self, carousel: &Carousel) {
+        walk_carousel(self, carousel);
+    }
+
+    /// 캐로셀 안의 카드
+    fn visit_card(&mut self, card: &Card) {
+        walk_card(self, card);
+    }
+
+    fn visit_basic_card(&mut self, card: &BasicCardContent) {
+        walk_basic_card(self, card);
+    }
+
+    fn visit_commerce_card(&mut self, card: &CommerceCardContent) {
+        walk_commerce_card(self, card);
+    }
+
+    fn visit_item_card(&mut self, card: &ItemCardContent) {
+        walk_item_card(self, card);
+    }
+
+    fn visit_list_card(&mut self, card: &ListCardContent) {
+        walk_list_card(self, card);
+    }
+
+    fn visit_list_item(&mut self, item: &ListItem) {
+        walk_list_item(self, item);
+    }
+
+    fn visit_thumbnail(&mut self, thumbnail: &ThumbNail) {
+        walk_thumbnail(self, thumbnail);
+    }
+
+    fn visit_simple_text(&mut self, text: &SimpleText) {}
+
+    fn visit_simple_image(&mut self, image: &SimpleImage) {}
+
+    fn visit_button(&mut self, button: &Button) {}
+
+    fn visit_quick_reply(&mut self, quick_reply: &QuickReply) {}
+
+    fn visit_link(&mut self, link: &Link) {}
+}
+
+pub fn walk_template<V: Visitor + ?Sized>(v: &mut V, template: &Template) {
+    for output in template.outputs() {
+        v.visit_output(output);
+    }
+    for quick_reply in template.quick_replies() {
+        v.visit_quick_reply(quick_reply);
+    }
+}
+
+pub fn walk_output<V: Visitor + ?Sized>(v: &mut V, output: &Types) {
+    match output {
+        Types::List(list) => v.visit_list_card(&list.list_card),
+        Types::Basic(basic) => v.visit_basic_card(&basic.content),
+        Types::Commerce(commerce) => v.visit_commerce_card(&commerce.content),
+        Types::Item(item) => v.visit_item_card(&item.content),
+        Types::SimpleTxt(text) => v.visit_simple_text(text),
+        Types::SimpleImg(image) => v.visit_simple_image(image),
+        Types::Carousel(carousel) => v.visit_carousel(carousel),
+    }
+}
+
+pub fn walk_carousel<V: Visitor + ?Sized>(v: &mut V, carousel: &Carousel) {
+    if let Some(header) = carousel.header() {
+        v.visit_thumbnail(header.thumbnail());
+    }
+    for card in carousel.cards() {
+        v.visit_card(card);
+    }
+}
+
+pub fn walk_card<V: Visitor + ?Sized>(v: &mut V, card: &Card) {
+    match card {
+        Card::Basic(basic) => v.visit_basic_card(basic),
+        Card::Commerce(commerce) => v.visit_commerce_card(commerce),
+        Card::Item(item) => v.visit_item_card(item),
+        Card::List(list) => v.visit_list_card(list),
+    }
+}
+
+pub fn walk_basic_card<V: Visitor + ?Sized>(v: &mut V, card: &BasicCardContent) {
+    v.visit_thumbnail(card.thumbnail());
+    for button in card.buttons() {
+        v.visit_button(button);
+    }
+}
+
+pub fn walk_commerce_card<V: Visitor + ?Sized>(v: &mut V, card: &CommerceCardContent) {
+    for thumbnail in card.thumbnails() {
+        v.visit_thumbnail(thumbnail);
+    }
+    for button in card.buttons() {
+        v.visit_button(button);
+    }
+}
+
+pub fn walk_item_card<V: Visitor + ?Sized>(v: &mut V, card: &ItemCardContent) {
+    if let Some(thumbnail) = card.thumbnail() {
+        v.visit_thumbnail(thumbnail);
+    }
+    for button in card.buttons() {
+        v.visit_button(button);
+    }
+}
+
+pub fn walk_list_card<V: Visitor + ?Sized>(v: &mut V, card: &ListCardContent) {
+    for item in card.items() {
+        v.visit_list_item(item);
+    }
+    for button in card.buttons() {
+        v.visit_button(button);
+    }
+}
+
+pub fn walk_list_item<V: Visitor + ?Sized>(v: &mut V, item: &ListItem) {
+    if let Some(link) = item.link() {
+        v.visit_link(link);
+    }
+}
+
+pub fn walk_thumbnail<V: Visitor + ?Sized>(v: &mut V, thumbnail: &ThumbNail) {
+    if let Some(link) = &thumbnail.link {
+        v.visit_link(link);
+    }
+}
+/***** Visitor *****/
+
+/***** VisitorMut *****/
+/// 고치면서 순회
+pub trait VisitorMut {
+    fn visit_template_mut(&mut self, template: &mut Template) {
+        walk_template_mut(self, template);
+    }
+
+    fn visit_output_mut(&mut self, output: &mut Types) {
+        walk_output_mut(self, output);
+    }
+
+    fn visit_carousel_mut(&mut self, carousel: &mut Carousel) {
+        walk_carousel_mut(self, carousel);
+    }
+
+    /// 캐로셀 안의 카드
+    fn visit_card_mut(&mut self, card: &mut Card) {
+        walk_card_mut(self, card);
+    }
+
+    fn visit_basic_card_mut(&mut self, card: &mut BasicCardContent) {
+        walk_basic_card_mut(self, card);
+    }
+
+    fn visit_commerce_card_mut(&mut self, card: &mut CommerceCardContent) {
+        walk_commerce_card_mut(self, card);
+    }
+
+    fn visit_item_card_mut(&mut self, card: &mut ItemCardContent) {
+        walk_item_card_mut(self, card);
+    }
+
+    fn visit_list_card_mut(&mut self, card: &mut ListCardContent) {
+        walk_list_card_mut(self, card);
+    }
+
+    fn visit_list_item_mut(&mut self, item: &mut ListItem) {
+        walk_list_item_mut(self, item);
+    }
+
+    fn visit_thumbnail_mut(&mut self, thumbnail: &mut ThumbNail) {
+        walk_thumbnail_mut(self, thumbnail);
+    }
+
+    fn visit_simple_text_mut(&mut self, text: &mut SimpleText) {}
+
+    fn visit_simple_image_mut(&mut self, image: &mut SimpleImage) {}
+
+    fn visit_button_mut(&mut self, button: &mut Button) {}
+
+    fn visit_quick_reply_mut(&mut self, quick_reply: &mut QuickReply) {}
+
+    fn visit_link_mut(&mut self, link: &mut Link) {}
+}
+
+pub fn walk_template_mut<V: VisitorMut + ?Sized>(v: &mut V, template: &mut Template) {
+    for output in template.outputs_mut() {
+        v.visit_output_mut(output);
+    }
+    for quick_reply in template.quick_replies_mut() {
+        v.visit_quick_reply_mut(quick_reply);
+    }
+}
+
+pub fn walk_output_mut<V: VisitorMut + ?Sized>(v: &mut V, output: &mut Types) {
+    match output {
+        Types::List(list) => v.visit_list_card_mut(&mut list.list_card),
+        Types::Basic(basic) => v.visit_basic_card_mut(&mut basic.content),
+        Types::Commerce(commerce) => v.visit_commerce_card_mut(&mut commerce.content),
+        Types::Item(item) => v.visit_item_card_mut(&mut item.content),
+        Types::SimpleTxt(text) => v.visit_simple_text_mut(text),
+        Types::SimpleImg(image) => v.visit_simple_image_mut(image),
+        Types::Carousel(carousel) => v.visit_carousel_mut(carousel),
+    }
+}
+
+pub fn walk_carousel_mut<V: VisitorMut + ?Sized>(v: &mut V, carousel: &mut Carousel) {
+    if let Some(header) = carousel.header_mut() {
+        v.visit_thumbnail_mut(header.thumbnail_mut());
+    }
+    for card in carousel.cards_mut() {
+        v.visit_card_mut(card);
+    }
+}
+
+pub fn walk_card_mut<V: VisitorMut + ?Sized>(v: &mut V, card: &mut Card) {
+    match card {
+        Card::Basic(basic) => v.visit_basic_card_mut(basic),
+        Card::Commerce(commerce) => v.visit_commerce_card_mut(commerce),
+        Card::Item(item) => v.visit_item_card_mut(item),
+        Card::List(list) => v.visit_list_card_mut(list),
+    }
+}
+
+pub fn walk_basic_card_mut<V: VisitorMut + ?Sized>(v: &mut V, card: &mut BasicCardContent) {
+    v.visit_thumbnail_mut(card.thumbnail_mut());
+    for button in card.buttons_mut() {
+        v.visit_button_mut(button);
+    }
+}
+
+pub fn walk_commerce_card_mut<V: VisitorMut + ?Sized>(v: &mut V, card: &mut CommerceCardContent) {
+    for thumbnail in card.thumbnails_mut() {
+        v.visit_thumbnail_mut(thumbnail);
+    }
+    for button in card.buttons_mut() {
+        v.visit_button_mut(button);
+    }
+}
+
+pub fn walk_item_card_mut<V: VisitorMut + ?Sized>(v: &mut V, card: &mut ItemCardContent) {
+    if let Some(thumbnail) = card.thumbnail_mut() {
+        v.visit_thumbnail_mut(thumbnail);
+    }
+    for button in card.buttons_mut() {
+        v.visit_button_mut(button);
+    }
+}
+
+pub fn walk_list_card_mut<V: VisitorMut + ?Sized>(v: &mut V, card: &mut ListCardContent) {
+    for item in card.items_mut() {
+        v.visit_list_item_mut(item);
+    }
+    for button in card.buttons_mut() {
+        v.visit_button_mut(button);
+    }
+}
+
+pub fn walk_list_item_mut<V: VisitorMut + ?Sized>(v: &mut V, item: &mut ListItem) {
+    if let Some(link) = item.link_mut() {
+        v.visit_link_mut(link);
+    }
+}
+
+pub fn walk_thumbnail_mut<V: VisitorMut + ?Sized>(v: &mut V, thumbnail: &mut ThumbNail) {
+    if let Some(link) = &mut thumbnail.link {
+        v.visit_link_mut(link);
+    }
+}
+/***** VisitorMut *****/
+
+/***** Transforms *****/
+/// [RewriteUrls]가 넘겨주는 URL 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlKind {
+    /// 버튼 webLinkUrl, 썸네일/리스트 아이템 link
+    Link,
+    /// 썸네일, 리스트 아이템, SimpleImage, ItemCard imageTitle의 이미지
+    Image,
+}
+
+/// 응답 안의 모든 URL을 함수 하나로 바꿉니다.
+///
+/// ```
+/// use kakao_rs::prelude::*;
+/// use kakao_rs::visit::{RewriteUrls, UrlKind, VisitorMut};
+///
+/// let mut result = Template::new();
+/// result.push_output(SimpleImage::new("http://cdn.old.kr/a.png", "이미지").build());
+///
+/// RewriteUrls(|kind: UrlKind, url: &mut String| {
+///     if kind == UrlKind::Image {
+///         *url = url.replace("cdn.old.kr", "cdn.ajou.ac.kr");
+///     }
+/// })
+/// .visit_template_mut(&mut result);
+/// assert!(result.to_string().contains("http://cdn.ajou.ac.kr/a.png"));
+/// ```
+pub struct RewriteUrls<F>(pub F);
+
+impl<F: FnMut(UrlKind, &mut String)> VisitorMut for RewriteUrls<F> {
+    fn visit_item_card_mut(&mut self, card: &mut ItemCardContent) {
+        if let Some(url) = card
+            .image_title_mut()
+            .and_then(|title| title.image_url.as_mut())
+        {
+            (self.0)(UrlKind::Image, url);
+        }
+        walk_item_card_mut(self, card);
+    }
+
+    fn visit_list_item_mut(&mut self, item: &mut ListItem) {
+        if let Some(url) = &mut item.image_url {
+            (self.0)(UrlKind::Image, url);
+        }
+        walk_list_item_mut(self, item);
+    }
+
+    fn visit_thumbnail_mut(&mut self, thumbnail: &mut ThumbNail) {
+        (self.0)(UrlKind::Image, &mut thumbnail.image_url);
+        walk_thumbnail_mut(self, thumbnail);
+    }
+
+    fn visit_simple_image_mut(&mut self, image: &mut SimpleImage) {
+        (self.0)(UrlKind::Image, &mut image.simple_image.image_url);
+    }
+
+    fn visit_button_mut(&mut self, button: &mut Button) {
+        if let Some(url) = &mut button.web_link_url {
+            (self.0)(UrlKind::Link, url);
+        }
+    }
+
+    fn visit_link_mut(&mut self, link: &mut Link) {
+        for url in link.targets_mut() {
+            (self.0)(UrlKind::Link, url);
+        }
+    }
+}
+
+/// 모든 링크에 쿼리 파라미터(UTM 등)를 붙입니다. 값은 percent-encoding 합니다.
+pub fn append_query<'a>(
+    params: &'a [(&'a str, &'a str)],
+) -> RewriteUrls<impl FnMut(UrlKind, &mut String) + 'a> {
+    RewriteUrls(move |kind: UrlKind, url: &mut String| {
+        if kind == UrlKind::Link && !url.is_empty() {
+            *url = with_query(url, params);
+        }
+    })
+}
+
+/// `http://` 이미지를 `https://`로 바꿉니다.
+pub fn upgrade_https() -> RewriteUrls<impl FnMut(UrlKind, &mut String)> {
+    RewriteUrls(|kind: UrlKind, url: &mut String| {
+        if kind == UrlKind::Image && url.starts_with("http://") {
+            url.insert(4, 's');
+        }
+    })
+}
+
+/// 로그에 남기기 전에 전화 버튼 번호를 끝 4자리만 남기고 가립니다.
+///
+/// ```
+/// use kakao_rs::prelude::*;
+/// use kakao_rs::visit::{MaskPhoneNumbers, VisitorMut};
+///
+/// let mut result = Template::new();
+/// result.push_output(
+///     BasicCard::new()
+///         .with_thumbnail("https://ajou.ac.kr/a.png")
+///         .with_button(Button::call("전화", "010-1234-5678"))
+///         .build(),
+/// );
+/// MaskPhoneNumbers.visit_template_mut(&mut result);
+/// assert!(result.to_string().contains(r#""phoneNumber":"***-****-5678""#));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MaskPhoneNumbers;
+
+impl VisitorMut for MaskPhoneNumbers {
+    fn visit_button_mut(&mut self, button: &mut Button) {
+        if let Some(number) = &mut button.phone_number {
+            *number = mask_digits(number, 4);
+        }
+    }
+}
+
+/// 끝 `keep`자리를 뺀 숫자를 `*`로 바꿉니다.
+fn mask_digits(number: &str, keep: usize) -> String {
+    let digits = number.chars().filter(char::is_ascii_digit).count();
+    let mut seen = 0;
+    number
+        .chars()
+        .map(|c| {
+            if !c.is_ascii_digit() {
+                return c;
+            }
+            seen += 1;
+            if seen + keep > digits {
+                c
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+/// `#fragment` 앞에 쿼리를 붙입니다.
+fn with_query(url: &str, params: &[(&str, &str)]) -> String {
+    let mut url = Url::new(url);
+    url.extend_query(params.iter().copied());
+    url.to_string()
+}
+/***** Transforms *****/
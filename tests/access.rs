@@ -0,0 +1,111 @@
+use kakao_rs::money::Price;
+use kakao_rs::prelude::*;
+
+const JSON: &str = r#"{"version":"2.0","template":{"outputs":[{"listCard":{"header":{"title":"공지"},"items":[{"title":"1번 공지","description":"첫 공지","link":{"web":"http://ajou.ac.kr/1"}}],"buttons":[{"label":"더보기","action":"webLink","webLinkUrl":"http://ajou.ac.kr"}]}},{"carousel":{"type":"basicCard","items":[{"title":"카드","thumbnail":{"imageUrl":"http://ajou.ac.kr/1.png"},"buttons":[{"label":"전화","action":"phone","phoneNumber":"031-219-2114"}]}]}}],"quickReplies":[{"action":"message","label":"다음","messageText":"다음 공지"}]}}"#;
+
+#[test]
+fn getters_test() {
+    let result: Template = serde_json::from_str(JSON).unwrap();
+
+    assert_eq!(result.outputs().len(), 2);
+    match &result.outputs()[0] {
+        Types::List(list) => {
+            assert_eq!(list.header(), "공지");
+            assert_eq!(list.items()[0].title(), "1번 공지");
+            assert_eq!(list.items()[0].description(), Some("첫 공지"));
+            assert_eq!(
+                list.items()[0].link().unwrap().url(),
+                Some("http://ajou.ac.kr/1")
+            );
+            assert_eq!(list.buttons()[0].web_link_url(), Some("http://ajou.ac.kr"));
+        }
+        _ => panic!("listCard가 아닙니다"),
+    }
+    match &result.outputs()[1] {
+        Types::Carousel(carousel) => {
+            assert_eq!(carousel.card_type(), "basicCard");
+            assert!(carousel.header().is_none());
+            match &carousel.cards()[0] {
+                Card::Basic(basic) => {
+                    assert_eq!(basic.title(), Some("카드"));
+                    assert_eq!(basic.thumbnail().image_url, "http://ajou.ac.kr/1.png");
+                }
+                _ => panic!("basicCard가 아닙니다"),
+            }
+        }
+        _ => panic!("carousel이 아닙니다"),
+    }
+
+    let labels: Vec<&str> = result.buttons().map(Button::label).collect();
+    assert_eq!(labels, ["더보기", "전화"]);
+    assert_eq!(
+        result.buttons().nth(1).unwrap().phone_number(),
+        Some("031-219-2114")
+    );
+    assert_eq!(result.quick_replies()[0].message_text(), "다음 공지");
+    assert!(result.contexts().is_empty());
+}
+
+#[test]
+fn mutate_test() {
+    let mut result: Template = serde_json::from_str(JSON).unwrap();
+
+    for button in result.buttons_mut() {
+        if let Some(url) = button.web_link_url() {
+            let url = url.replace("http://", "https://");
+            button.put_link(url);
+        }
+    }
+    for qr in result.quick_replies_mut() {
+        qr.put_extra("from", "notice");
+    }
+    if let Types::List(list) = &mut result.outputs_mut()[0] {
+        list.items_mut()[0].put_description("수정한 공지");
+        list.items_mut()[0]
+            .link_mut()
+            .unwrap()
+            .put_web("https://ajou.ac.kr/1");
+    }
+    if let Types::Carousel(carousel) = &mut result.outputs_mut()[1] {
+        if let Card::Basic(basic) = &mut carousel.cards_mut()[0] {
+            basic
+                .thumbnail_mut()
+                .put_image_url("https://ajou.ac.kr/1.png");
+        }
+    }
+    result.outputs_mut().truncate(1);
+
+    let serialized = result.to_string();
+    assert!(serialized.contains(r#""webLinkUrl":"https://ajou.ac.kr""#));
+    assert!(serialized.contains(r#""description":"수정한 공지""#));
+    assert!(serialized.contains(r#""link":{"web":"https://ajou.ac.kr/1"}"#));
+    assert!(serialized.contains(r#""extra":{"from":"notice"}"#));
+    assert!(!serialized.contains("carousel"));
+}
+
+#[test]
+fn card_getters_test() {
+    let card = CommerceCard::new()
+        .with_description("후드티")
+        .with_price(35000)
+        .with_currency("won")
+        .with_discount(5000)
+        .with_thumbnail("https://ajou.ac.kr/hood.png")
+        .with_button(Button::text("구매"));
+    assert_eq!(card.description(), "후드티");
+    assert_eq!(card.price(), 35000);
+    assert_eq!(card.currency(), "won");
+    assert_eq!(card.discount(), Some(Price::new(5000)));
+    assert_eq!(card.discounted_price(), Some(Price::new(30000)));
+    assert_eq!(card.thumbnails().len(), 1);
+
+    let mut card = ItemCard::new()
+        .with_head("영수증")
+        .with_item(ItemList::new("합계", "5,000원"))
+        .with_item_list_summary("총액", "5,000원");
+    assert_eq!(card.head().unwrap().title(), "영수증");
+    assert_eq!(card.items()[0].description(), "5,000원");
+    assert_eq!(card.item_list_summary().unwrap().title(), "총액");
+    card.items_mut().clear();
+    assert!(card.items().is_empty());
+}
@@ -0,0 +1,107 @@
+use kakao_rs::prelude::*;
+
+fn wrap(output: Types) -> String {
+    let mut result = Template::new();
+    result.push_output(output);
+    result.to_string()
+}
+
+#[test]
+fn with_and_set_test() {
+    let chained = BasicCard::new()
+        .with_title("공지")
+        .with_description("오늘 공지")
+        .with_thumbnail("https://ajou.ac.kr/notice.png")
+        .with_button(Button::text("더보기"));
+
+    let mut card = BasicCard::new();
+    card.put_title("공지")
+        .put_description("오늘 공지")
+        .put_thumbnail("https://ajou.ac.kr/notice.png")
+        .push_button(Button::text("더보기"));
+
+    assert_eq!(wrap(chained.build()), wrap(card.build()));
+}
+
+#[test]
+fn extend_test() {
+    let items = (1..=3).map(|i| ListItem::new(format!("{}번 공지", i)));
+
+    let mut pushed = ListCard::new("공지");
+    for i in 1..=3 {
+        pushed.push_item(ListItem::new(format!("{}번 공지", i)));
+    }
+    pushed.push_button(Button::text("더보기"));
+
+    let extended = ListCard::new("공지")
+        .with_items(items)
+        .with_buttons(vec![Button::text("더보기")]);
+
+    assert_eq!(wrap(pushed.build()), wrap(extended.build()));
+
+    let mut result = Template::new();
+    result
+        .extend_outputs(vec![
+            SimpleText::new("1").build(),
+            SimpleText::new("2").build(),
+        ])
+        .extend_quick_replies(
+            (1..=2).map(|i| QuickReply::new(format!("{}", i), "다음".to_string())),
+        );
+    assert_eq!(
+        result.to_string(),
+        Template::new()
+            .with_output(SimpleText::new("1").build())
+            .with_output(SimpleText::new("2").build())
+            .with_quick_reply(QuickReply::new("1", "다음"))
+            .with_quick_reply(QuickReply::new("2", "다음"))
+            .to_string()
+    );
+}
+
+#[test]
+fn carousel_header_test() {
+    let mut carousel = Carousel::new().with_type(CommerceCard::id());
+    carousel
+        .put_header_title("굿즈")
+        .put_header_description("아주대 굿즈")
+        .put_header_thumbnail("https://ajou.ac.kr/goods.png");
+
+    let serialized = wrap(carousel.build());
+    assert!(serialized.contains(
+        r#""header":{"title":"굿즈","description":"아주대 굿즈","thumbnail":{"imageUrl":"https://ajou.ac.kr/goods.png"}}"#
+    ));
+
+    // 인자마다 다른 타입
+    let desc = format!("{} 굿즈", "아주대");
+    let mut mixed = Carousel::new().with_type(CommerceCard::id());
+    mixed.put_header("굿즈", desc, "https://ajou.ac.kr/goods.png");
+    assert_eq!(wrap(mixed.build()), serialized);
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_shim_test() {
+    let old = BasicCard::new()
+        .set_desc("오늘 공지")
+        .add_button(Button::text("더보기"));
+    let new = BasicCard::new()
+        .with_description("오늘 공지")
+        .with_button(Button::text("더보기"));
+    assert_eq!(wrap(old.build()), wrap(new.build()));
+
+    let mut old = Template::new();
+    old.add_output(SimpleText::new("공지").build());
+    old.add_qr(QuickReply::new("다음", "다음"));
+    old.add_context(ContextValue::new("notice", 1).add_param("page", "2"));
+    let new = Template::new()
+        .with_output(SimpleText::new("공지").build())
+        .with_quick_reply(QuickReply::new("다음", "다음"))
+        .with_context(ContextValue::new("notice", 1).with_param("page", "2"));
+    assert_eq!(old.to_string(), new.to_string());
+
+    let mut old = ListCard::new("공지");
+    old.add_item(ListItem::new("1번").set_desc("첫 공지"));
+    let new = ListCard::new("공지").with_item(ListItem::new("1번").with_description("첫 공지"));
+    assert_eq!(wrap(old.build()), wrap(new.build()));
+}
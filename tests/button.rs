@@ -1,3 +1,5 @@
+#![allow(deprecated)] // 0.3 API가 그대로 빌드되는지 확인
+
 // use kakao_rs::{Button, CallButton, LinkButton, MsgButton, ShareButton};
 
 use kakao_rs::prelude::*;
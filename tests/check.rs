@@ -0,0 +1,157 @@
+use kakao_rs::check::{self, CheckError};
+use kakao_rs::prelude::*;
+
+#[test]
+fn url_test() {
+    for url in [
+        "https://ajou.ac.kr",
+        "http://ajou.ac.kr:8080/notice?id=1#top",
+        "HTTPS://user@ajou.ac.kr/",
+        "https://아주대.한국/공지",
+    ] {
+        assert_eq!(check::check_url(url), Ok(()), "{}", url);
+    }
+
+    assert!(matches!(
+        check::check_url("htps://ajou.ac.kr"),
+        Err(CheckError::Scheme { scheme, .. }) if scheme == "htps"
+    ));
+    assert!(matches!(
+        check::check_url("javascript:alert(1)"),
+        Err(CheckError::Scheme { .. })
+    ));
+    for url in [
+        "",
+        "ajou.ac.kr",
+        "https://",
+        "https://ajou..ac.kr",
+        "https://ajou.ac.kr:port",
+        "https://ajou ac.kr",
+        "https:ajou.ac.kr",
+    ] {
+        assert!(
+            matches!(check::check_url(url), Err(CheckError::Url { .. })),
+            "{}",
+            url
+        );
+    }
+
+    assert_eq!(check::check_app_url("kakaomap://route?ep=1,2"), Ok(()));
+    assert!(check::check_app_url("kakaomap:").is_err());
+}
+
+#[test]
+fn phone_test() {
+    let cases = [
+        ("010-1234-5678", "010-1234-5678"),
+        ("01012345678", "010-1234-5678"),
+        ("tel:010 1234 5678", "010-1234-5678"),
+        ("+82-10-1234-5678", "010-1234-5678"),
+        ("+821012345678", "010-1234-5678"),
+        ("(02) 123-4567", "02-123-4567"),
+        ("0212345678", "02-1234-5678"),
+        ("031-219-2114", "031-219-2114"),
+        ("070.1234.5678", "070-1234-5678"),
+        ("0505-123-4567", "0505-123-4567"),
+        ("080-123-4567", "080-123-4567"),
+        ("08012345678", "080-1234-5678"),
+        ("060-700-1234", "060-700-1234"),
+        ("tel:0607001234", "060-700-1234"),
+        ("1588-1234", "1588-1234"),
+        ("tel:15771234", "1577-1234"),
+        ("112", "112"),
+    ];
+    for (number, expected) in cases {
+        assert_eq!(
+            check::normalize_phone(number).as_deref(),
+            Ok(expected),
+            "{}",
+            number
+        );
+    }
+
+    for number in [
+        "010-12345678",
+        "010-123-45678",
+        "0101234567",
+        "+82-010-1234-5678",
+        "099-123-4567",
+        "1234-5678",
+        "911",
+        "010-1234-567a",
+        "",
+    ] {
+        assert_eq!(
+            check::normalize_phone(number),
+            Err(CheckError::Phone(number.to_string()))
+        );
+    }
+}
+
+#[test]
+fn try_constructor_test() {
+    let button = Button::try_call("전화", "tel:+82-31-219-2114").unwrap();
+    assert_eq!(button.phone_number(), Some("031-219-2114"));
+    assert!(Button::try_call("전화", "010-12345678").is_err());
+    assert_eq!(
+        Button::try_call("상담", "080-123-4567")
+            .unwrap()
+            .phone_number(),
+        Some("080-123-4567")
+    );
+
+    assert_eq!(
+        Button::try_link("열기", "https://ajou.ac.kr")
+            .unwrap()
+            .web_link_url(),
+        Some("https://ajou.ac.kr")
+    );
+    let error = Button::try_link("열기", "htps://ajou.ac.kr").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "허용하지 않는 스킴 `htps`입니다 (http, https만 가능): `htps://ajou.ac.kr`"
+    );
+
+    assert!(ThumbNail::try_new("https://ajou.ac.kr/a.png").is_ok());
+    assert!(Link::try_new("ajou.ac.kr").is_err());
+
+    let mut item = ListItem::new("공지");
+    assert!(item.try_set_link("https://ajou.ac.kr").is_ok());
+    assert!(item.try_set_link("ajou.ac.kr").is_err());
+    assert_eq!(item.link().unwrap().url(), Some("https://ajou.ac.kr"));
+}
+
+#[test]
+fn validate_format_test() {
+    let list_card = ListCard::new("공지")
+        .with_item(
+            ListItem::new("1번")
+                .with_image("ftp://ajou.ac.kr/a.png")
+                .with_link_targets(
+                    Link::default()
+                        .with_pc("https://ajou.ac.kr")
+                        .with_ios("ajou"),
+                ),
+        )
+        .with_button(Button::call("전화", "010-12345678"));
+    let result = Template::new()
+        .with_output(list_card.build())
+        .with_output(SimpleImage::new("https://ajou.ac.kr/a.png", "이미지").build());
+
+    let errors = result.validate().unwrap_err();
+    assert_eq!(result.validate_strict().unwrap_err(), errors);
+    let paths: Vec<&str> = errors.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        [
+            "template.outputs[0].listCard.items[0].link.ios",
+            "template.outputs[0].listCard.items[0].imageUrl",
+            "template.outputs[0].listCard.buttons[0].phoneNumber",
+        ]
+    );
+    assert!(errors.iter().all(ValidationError::is_format_error));
+    assert_eq!(
+        errors[2].to_string(),
+        "template.outputs[0].listCard.buttons[0].phoneNumber: 올바른 전화번호가 아닙니다: `010-12345678`"
+    );
+}
@@ -0,0 +1,89 @@
+use kakao_rs::prelude::*;
+
+/// 실패한 위치와 이유
+fn error_at(output: &str) -> (String, String) {
+    let json = format!(
+        r#"{{"version":"2.0","template":{{"outputs":[{}]}}}}"#,
+        output
+    );
+    let de = &mut serde_json::Deserializer::from_str(&json);
+    let error = serde_path_to_error::deserialize::<_, Template>(de).unwrap_err();
+    let message = error.inner().to_string();
+    // serde_json이 붙이는 " at line 1 column n" 제거
+    let message = message.split(" at line ").next().unwrap().to_string();
+    (error.path().to_string(), message)
+}
+
+#[test]
+fn error_location_test() {
+    let (path, message) = error_at(
+        r#"{"carousel": {"type": "commerceCard", "items": [{
+            "description": "아메리카노", "price": "5000원", "currency": "won",
+            "thumbnails": [{"imageUrl": "https://example.com/a.png"}]
+        }]}}"#,
+    );
+    assert_eq!(path, "template.outputs[0].carousel.items[0].price");
+    assert!(message.contains("expected u32"), "{}", message);
+
+    let (path, message) = error_at(
+        r#"{"listCard": {"header": {"title": "공지"}, "items": [{"description": "제목 없음"}]}}"#,
+    );
+    assert_eq!(path, "template.outputs[0].listCard.items[0]");
+    assert_eq!(message, "missing field `title`");
+}
+
+#[test]
+fn output_type_test() {
+    let (path, message) = error_at(r#"{"forwardable": true}"#);
+    assert_eq!(path, "template.outputs[0]");
+    assert!(message.starts_with("missing output type"), "{}", message);
+
+    let (_, message) = error_at(
+        r#"{"simpleText": {"text": "안녕"}, "simpleImage": {"imageUrl": "https://example.com/a.png", "altText": "a"}}"#,
+    );
+    assert_eq!(
+        message,
+        "an output has only one type, found `simpleText` and `simpleImage`"
+    );
+}
+
+#[test]
+fn carousel_items_follow_type_test() {
+    // basicCard 모양의 아이템도 type이 commerceCard면 commerceCard로 읽습니다.
+    let (path, message) = error_at(
+        r#"{"carousel": {"type": "commerceCard", "items": [
+            {"title": "제목", "thumbnail": {"imageUrl": "https://example.com/a.png"}}
+        ]}}"#,
+    );
+    assert_eq!(path, "template.outputs[0].carousel.items[0]");
+    assert_eq!(message, "missing field `description`");
+
+    let (_, message) = error_at(r#"{"carousel": {"type": "videoCard", "items": [{}]}}"#);
+    assert!(
+        message.starts_with("unknown carousel type `videoCard`"),
+        "{}",
+        message
+    );
+
+    // items가 type보다 먼저 와도 됩니다.
+    let json = r#"{"version": "2.0", "template": {"outputs": [{"carousel": {
+        "items": [{"itemList": [], "thumbnail": {"imageUrl": "https://example.com/a.png"}}],
+        "type": "itemCard"
+    }}]}}"#;
+    let template: Template = serde_json::from_str(json).unwrap();
+    assert!(template.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(&template).unwrap()["template"]["outputs"][0]["carousel"]["items"][0]
+            ["itemList"],
+        serde_json::json!([])
+    );
+
+    let (path, message) =
+        error_at(r#"{"carousel": {"items": [{"itemList": [], "title": 1}], "type": "itemCard"}}"#);
+    assert_eq!(path, "template.outputs[0].carousel");
+    assert!(
+        message.starts_with("items[0].title: invalid type"),
+        "{}",
+        message
+    );
+}
@@ -0,0 +1,416 @@
+#![cfg(feature = "engine")]
+
+use kakao_rs::engine::{EngineError, Format, HotRegistry, Registry};
+use serde::Serialize;
+use serde_json::json;
+use std::fs;
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
+
+const NOTICE_YAML: &str = r#"
+outputs:
+  - listCard:
+      header: { title: "{{major}} 공지" }
+      items:
+        - $each: notices
+          $as: notice
+          $item:
+            title: "{{$index}}. {{notice.title}}"
+            link: { web: "{{notice.url}}" }
+quickReplies:
+  - { label: 처음으로, action: message, messageText: 처음으로 }
+"#;
+
+const PRODUCT_TOML: &str = r#"
+version = "2.0"
+
+[[template.outputs]]
+[template.outputs.commerceCard]
+description = "{{name}}"
+price = "{{price}}"
+currency = "won"
+thumbnails = [{ imageUrl = "{{image}}" }]
+buttons = [{ label = "구매하기", action = "webLink", webLinkUrl = "{{url}}" }]
+"#;
+
+#[derive(Serialize)]
+struct Notice {
+    title: String,
+    url: String,
+}
+
+#[derive(Serialize)]
+struct NoticePage {
+    major: String,
+    notices: Vec<Notice>,
+}
+
+fn notice_page(n: usize) -> NoticePage {
+    NoticePage {
+        major: "소프트웨어학과".to_string(),
+        notices: (1..=n)
+            .map(|i| Notice {
+                title: format!("{}번 공지", i),
+                url: format!("https://ajou.ac.kr/notice/{}", i),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn render_test() {
+    let mut registry = Registry::new();
+    registry.add("notice", NOTICE_YAML, Format::Yaml).unwrap();
+    registry.add("product", PRODUCT_TOML, Format::Toml).unwrap();
+    assert_eq!(registry.names(), ["notice", "product"]);
+
+    let result = registry.render("notice", &notice_page(2)).unwrap();
+    assert_eq!(
+        result.to_string(),
+        r#"{"template":{"outputs":[{"listCard":{"header":{"title":"소프트웨어학과 공지"},"items":[{"title":"1. 1번 공지","link":{"web":"https://ajou.ac.kr/notice/1"}},{"title":"2. 2번 공지","link":{"web":"https://ajou.ac.kr/notice/2"}}]}}],"quickReplies":[{"action":"message","label":"처음으로","messageText":"처음으로"}]},"version":"2.0"}"#
+    );
+
+    // "{{price}}" 하나뿐이면 숫자 그대로
+    let result = registry
+        .render(
+            "product",
+            &json!({
+                "name": "아주대 후드티",
+                "price": 35000,
+                "image": "https://ajou.ac.kr/hood.png",
+                "url": "https://ajou.ac.kr/shop"
+            }),
+        )
+        .unwrap();
+    assert!(result
+        .to_string()
+        .contains(r#""description":"아주대 후드티","price":35000,"currency":"won""#));
+}
+
+#[test]
+fn error_test() {
+    let mut registry = Registry::new();
+    registry.add("notice", NOTICE_YAML, Format::Yaml).unwrap();
+
+    let error = registry
+        .render("notice", &json!({ "notices": [] }))
+        .unwrap_err();
+    assert!(matches!(error, EngineError::MissingVariable { .. }));
+    assert_eq!(
+        error.to_string(),
+        "notice: template.outputs[0].listCard.header.title: 변수가 없습니다: `major`"
+    );
+
+    let error = registry
+        .render("notice", &json!({ "major": "sw", "notices": "없음" }))
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "notice: template.outputs[0].listCard.items: 목록이 아닙니다: `notices`"
+    );
+
+    let error = registry
+        .render(
+            "notice",
+            &json!({ "major": "sw", "notices": [{ "url": "https://ajou.ac.kr" }] }),
+        )
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "notice: template.outputs[0].listCard.items[0].title: 변수가 없습니다: `notice.title`"
+    );
+
+    // 결과도 카카오 제한으로 검사합니다.
+    let error = registry.render("notice", &notice_page(6)).unwrap_err();
+    match &error {
+        EngineError::Invalid { errors, .. } => assert_eq!(
+            errors[0].to_string(),
+            "template.outputs[0].listCard.items: 1 ~ 5개까지 가능합니다 (현재 6개)"
+        ),
+        error => panic!("{}", error),
+    }
+
+    // URL 형식도 그린 값으로 검사합니다.
+    let mut page = notice_page(1);
+    page.notices[0].url = "htps://ajou.ac.kr/notice/1".to_string();
+    match registry.render("notice", &page).unwrap_err() {
+        EngineError::Invalid { errors, .. } => {
+            assert_eq!(
+                errors[0].path,
+                "template.outputs[0].listCard.items[0].link.web"
+            );
+            assert!(errors[0].is_format_error());
+        }
+        error => panic!("{}", error),
+    }
+
+    assert!(matches!(
+        registry.render("없음", &json!({})),
+        Err(EngineError::NotFound(_))
+    ));
+
+    // 결과가 응답 형식이 아닐 때
+    registry
+        .add(
+            "broken",
+            r#"{"outputs":[{"simpleText":{"text":"{{count}}"}}]}"#,
+            Format::Json,
+        )
+        .unwrap();
+    let error = registry
+        .render("broken", &json!({ "count": 3 }))
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("broken: template.outputs[0].simpleText.text: invalid type"));
+}
+
+#[test]
+fn syntax_test() {
+    let mut registry = Registry::new();
+    let error = registry
+        .add(
+            "a",
+            r#"{"outputs":[{"simpleText":{"text":"{{name 님"}}]}"#,
+            Format::Json,
+        )
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "a: template.outputs[0].simpleText.text: 닫히지 않은 `{{`"
+    );
+
+    let error = registry
+        .add(
+            "b",
+            r#"{"outputs":[{"$each":"texts","$item":{"simpleText":{"text":"{{item}}"}},"extra":1}]}"#,
+            Format::Json,
+        )
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "b: template.outputs[0]: `$each`와 함께 쓸 수 없는 키 `extra`"
+    );
+
+    let error = registry
+        .add(
+            "c",
+            r#"{"outputs":{"$each":"texts","$item":{}}}"#,
+            Format::Json,
+        )
+        .unwrap_err();
+    assert!(error.to_string().contains("목록 안에서만"));
+
+    assert!(matches!(
+        registry.add("d", "outputs: [", Format::Yaml),
+        Err(EngineError::Parse { .. })
+    ));
+    assert!(registry.is_empty());
+}
+
+#[test]
+fn from_dir_test() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("notice.yaml"), NOTICE_YAML).unwrap();
+    fs::write(dir.path().join("product.toml"), PRODUCT_TOML).unwrap();
+    fs::write(
+        dir.path().join("hello.json"),
+        r#"{"outputs":[{"simpleText":{"text":"{{$index}}"}}]}"#,
+    )
+    .unwrap();
+    fs::write(dir.path().join("README.md"), "# 템플릿").unwrap();
+
+    let registry = Registry::from_dir(dir.path()).unwrap();
+    assert_eq!(registry.names(), ["hello", "notice", "product"]);
+    assert!(registry.render("notice", &notice_page(3)).is_ok());
+
+    fs::write(dir.path().join("broken.json"), "{").unwrap();
+    let error = Registry::from_dir(dir.path()).unwrap_err();
+    assert!(error.to_string().starts_with("broken: "));
+}
+
+fn hello(text: &str) -> String {
+    format!(r#"{{"outputs":[{{"simpleText":{{"text":"{}"}}}}]}}"#, text)
+}
+
+fn text_of(registry: &HotRegistry, name: &str) -> String {
+    let result = registry.render(name, &json!({ "name": "아주" })).unwrap();
+    serde_json::to_value(&result).unwrap()["template"]["outputs"][0]["simpleText"]["text"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn hot_reload_test() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("hello.json"), hello("{{name}}님 안녕")).unwrap();
+    fs::write(dir.path().join("notice.yaml"), NOTICE_YAML).unwrap();
+
+    let registry = HotRegistry::open(dir.path())
+        .unwrap()
+        .with_sample("notice", &notice_page(5))
+        .unwrap();
+    assert_eq!(text_of(&registry, "hello"), "아주님 안녕");
+    assert!(!registry.reload().unwrap());
+
+    // 바뀐 파일만 다시 읽습니다.
+    let before = registry.current();
+    fs::write(dir.path().join("hello.json"), hello("{{name}}님 반가워요")).unwrap();
+    assert!(registry.reload().unwrap());
+    assert_eq!(text_of(&registry, "hello"), "아주님 반가워요");
+    // 이미 받은 묶음은 그대로
+    assert!(before.render("hello", &json!({ "name": "아주" })).is_ok());
+    assert!(!Arc::ptr_eq(&before, &registry.current()));
+
+    // 오류가 있으면 이전 버전을 계속 씁니다.
+    fs::write(dir.path().join("hello.json"), hello("{{name 님")).unwrap();
+    let errors = registry.reload().unwrap_err();
+    assert_eq!(
+        errors[0].to_string(),
+        "hello: template.outputs[0].simpleText.text: 닫히지 않은 `{{`"
+    );
+    assert_eq!(text_of(&registry, "hello"), "아주님 반가워요");
+    // 다시 바뀔 때까지 같은 오류를 알리지 않습니다.
+    assert!(!registry.reload().unwrap());
+
+    // 새 파일, 지운 파일, 오류를 한 번에
+    fs::write(dir.path().join("bye.json"), hello("안녕히")).unwrap();
+    fs::write(dir.path().join("broken.json"), "{").unwrap();
+    fs::remove_file(dir.path().join("notice.yaml")).unwrap();
+    let errors = registry.reload().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].to_string().starts_with("broken: "));
+    assert_eq!(registry.current().names(), ["bye", "hello"]);
+}
+
+#[test]
+fn shape_test() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("hello.json"), hello("{{name}}님 안녕")).unwrap();
+    fs::write(dir.path().join("notice.yaml"), NOTICE_YAML).unwrap();
+    fs::write(dir.path().join("product.toml"), PRODUCT_TOML).unwrap();
+    // 예시 값 없이도 모양은 검사합니다.
+    let registry = HotRegistry::open(dir.path()).unwrap();
+
+    // 출력 키 오타
+    fs::write(
+        dir.path().join("hello.json"),
+        r#"{"outputs":[{"simpleTxt":{"text":"{{name}}"}}]}"#,
+    )
+    .unwrap();
+    let errors = registry.reload().unwrap_err();
+    assert!(
+        matches!(&errors[0], EngineError::Deserialize { name, .. } if name == "hello"),
+        "{}",
+        errors[0]
+    );
+    assert_eq!(text_of(&registry, "hello"), "아주님 안녕");
+
+    // items 대신 entries
+    fs::write(
+        dir.path().join("notice.yaml"),
+        NOTICE_YAML.replace("      items:\n", "      entries:\n"),
+    )
+    .unwrap();
+    let errors = registry.reload().unwrap_err();
+    match &errors[0] {
+        EngineError::Deserialize { name, message } => {
+            assert_eq!(name, "notice");
+            assert!(message.contains("items"), "{}", message);
+        }
+        error => panic!("{}", error),
+    }
+
+    // 바로가기 라벨 글자 수 초과
+    fs::write(
+        dir.path().join("notice.yaml"),
+        NOTICE_YAML.replace("label: 처음으로", "label: 처음으로 돌아가서 다른 공지 보기"),
+    )
+    .unwrap();
+    let errors = registry.reload().unwrap_err();
+    match &errors[0] {
+        EngineError::Invalid { name, errors } => {
+            assert_eq!(name, "notice");
+            assert!(errors
+                .iter()
+                .any(|e| e.path == "template.quickReplies[0].label"));
+        }
+        error => panic!("{}", error),
+    }
+    assert!(registry.render("notice", &notice_page(3)).is_ok());
+
+    // 긴 변수 이름은 글자 수 제한에 걸리지 않습니다.
+    fs::write(
+        dir.path().join("hello.json"),
+        r#"{"outputs":[{"simpleText":{"text":"{{name}}"}}],"quickReplies":[{"label":"{{very.long.variable.name}} 보기","action":"message","messageText":"{{name}}"}]}"#,
+    )
+    .unwrap();
+    assert!(registry.reload().unwrap());
+
+    // 처음 열 때도 검사합니다.
+    fs::write(
+        dir.path().join("hello.json"),
+        r#"{"outputs":[{"simpleTxt":{"text":"{{name}}"}}]}"#,
+    )
+    .unwrap();
+    assert!(HotRegistry::open(dir.path()).is_err());
+}
+
+#[test]
+fn sample_test() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("notice.yaml"), NOTICE_YAML).unwrap();
+    let registry = HotRegistry::open(dir.path())
+        .unwrap()
+        .with_sample("notice", &notice_page(5))
+        .unwrap();
+
+    // 예시 값으로 그리면 공지 6개 → 카카오 제한 초과
+    fs::write(
+        dir.path().join("notice.yaml"),
+        NOTICE_YAML.replace(
+            "      items:\n",
+            "      items:\n        - { title: 고정 공지 }\n",
+        ),
+    )
+    .unwrap();
+    let errors = registry.reload().unwrap_err();
+    assert!(matches!(errors[0], EngineError::Invalid { .. }));
+    assert!(registry.render("notice", &notice_page(5)).is_ok());
+
+    // 지금 버전이 예시 값으로 안 그려지면 바로 실패
+    assert!(matches!(
+        HotRegistry::open(dir.path())
+            .unwrap()
+            .with_sample("notice", &json!({ "notices": [] })),
+        Err(EngineError::MissingVariable { .. })
+    ));
+}
+
+#[test]
+fn watch_test() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("hello.json"), hello("처음")).unwrap();
+    let registry = Arc::new(HotRegistry::open(dir.path()).unwrap());
+    let (sender, errors) = mpsc::channel();
+    let watcher = registry.watch(Duration::from_millis(10), move |error| {
+        sender.send(error.to_string()).unwrap();
+    });
+
+    fs::write(dir.path().join("hello.json"), hello("바뀐 문구")).unwrap();
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while text_of(&registry, "hello") != "바뀐 문구" {
+        assert!(Instant::now() < deadline, "다시 읽지 않았습니다");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    fs::write(dir.path().join("hello.json"), "{").unwrap();
+    let error = errors.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert!(error.starts_with("hello: "));
+    assert_eq!(text_of(&registry, "hello"), "바뀐 문구");
+
+    // 모두 버리면 스레드도 끝납니다.
+    drop(registry);
+    watcher.join().unwrap();
+}
@@ -0,0 +1,172 @@
+use kakao_rs::i18n::{Catalog, Locales, MissingKey};
+use kakao_rs::prelude::*;
+
+fn locales() -> Locales {
+    Locales::new()
+        .add(
+            "ko",
+            Catalog::from_json(
+                r#"{
+                    "greeting": "안녕하세요",
+                    "notice": { "title": "공지사항", "more": "더보기" },
+                    "home": "처음으로",
+                    "seats": "남은 자리 {{count}}개"
+                }"#,
+            )
+            .unwrap(),
+        )
+        .add(
+            "en",
+            Catalog::new()
+                .add("greeting", "Hello")
+                .add("notice.title", "Notices")
+                .add("notice.more", "More")
+                .add("seats", "{{count}} seats left"),
+        )
+        .add("en-GB", Catalog::new().add("greeting", "Hello there"))
+}
+
+fn response() -> Template {
+    let mut result = Template::new();
+    result.push_output(SimpleText::new("@{greeting}!").build());
+    result.push_output(
+        BasicCard::new()
+            .with_title("@{notice.title}")
+            .with_thumbnail("https://ajou.ac.kr/notice.png")
+            .with_button(Button::link("@{notice.more}", "https://ajou.ac.kr"))
+            .build(),
+    );
+    result.push_quick_reply(QuickReply::new("@{home}", "@{home}"));
+    result
+}
+
+fn request(lang: Option<&str>) -> SkillRequest {
+    let mut request = SkillRequest::new("공지");
+    request.user_request.lang = lang.map(str::to_string);
+    request
+}
+
+#[test]
+fn localize_test() {
+    let locales = locales();
+
+    let mut result = response();
+    locales
+        .for_request(&request(Some("en")))
+        .localize(&mut result);
+    assert_eq!(
+        result.to_string(),
+        r#"{"template":{"outputs":[{"simpleText":{"text":"Hello!"}},{"basicCard":{"title":"Notices","thumbnail":{"imageUrl":"https://ajou.ac.kr/notice.png"},"buttons":[{"label":"More","action":"webLink","webLinkUrl":"https://ajou.ac.kr"}]}}],"quickReplies":[{"action":"message","label":"처음으로","messageText":"처음으로"}]},"version":"2.0"}"#
+    );
+
+    let mut result = response();
+    locales.for_request(&request(None)).localize(&mut result);
+    assert!(result.to_string().contains(r#""text":"안녕하세요!""#));
+    assert!(result.to_string().contains(r#""title":"공지사항""#));
+
+    // en-US → en, en-GB는 따로, 모르는 언어는 한국어
+    assert_eq!(locales.for_request(&request(Some("en-US"))).lang(), "en");
+    assert_eq!(locales.translator("en_GB").lang(), "en-gb");
+    assert_eq!(locales.translator("en_GB").t("greeting"), "Hello there");
+    assert_eq!(locales.translator("en-GB").t("notice.title"), "공지사항");
+    assert_eq!(locales.translator("ja").lang(), "ko");
+
+    // 어디에도 없으면 키 그대로
+    assert_eq!(locales.translator("en").t("없는.키"), "없는.키");
+}
+
+#[test]
+fn format_test() {
+    let locales = locales();
+    assert_eq!(
+        locales.translator("en").format("seats", &[("count", &3)]),
+        "3 seats left"
+    );
+    assert_eq!(
+        locales.translator("ko").format("seats", &[("count", &3)]),
+        "남은 자리 3개"
+    );
+}
+
+#[test]
+fn check_test() {
+    let locales = locales();
+    let errors = locales.check_template("notice", &response()).unwrap_err();
+    assert_eq!(
+        errors,
+        [
+            MissingKey {
+                template: "notice".to_string(),
+                lang: "en".to_string(),
+                key: "home".to_string(),
+            },
+            MissingKey {
+                template: "notice".to_string(),
+                lang: "en-gb".to_string(),
+                key: "home".to_string(),
+            },
+            MissingKey {
+                template: "notice".to_string(),
+                lang: "en-gb".to_string(),
+                key: "notice.more".to_string(),
+            },
+            MissingKey {
+                template: "notice".to_string(),
+                lang: "en-gb".to_string(),
+                key: "notice.title".to_string(),
+            },
+        ]
+    );
+    assert_eq!(errors[0].to_string(), "notice: en 문구가 없습니다: `home`");
+    assert_eq!(locales.missing("greeting"), Vec::<&str>::new());
+
+    let locales = Locales::new()
+        .add("ko", Catalog::new().add("greeting", "안녕하세요"))
+        .add("en", Catalog::new().add("greeting", "Hello"));
+    let mut result = Template::new();
+    result.push_output(SimpleText::new("@{greeting}").build());
+    assert!(locales.check_template("greeting", &result).is_ok());
+
+    assert!(Catalog::from_json(r#"{"count": 3}"#).is_err());
+}
+
+#[cfg(feature = "engine")]
+#[test]
+fn engine_test() {
+    use kakao_rs::engine::{Format, Registry};
+    use serde_json::json;
+    use std::fs;
+
+    let mut registry = Registry::new();
+    registry
+        .add(
+            "seats",
+            r#"{"outputs":[{"simpleText":{"text":"@{seats}"}}],"quickReplies":[{"label":"@{home}","action":"message","messageText":"@{home}"}]}"#,
+            Format::Json,
+        )
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("ko.yaml"),
+        "seats: 남은 자리 {{count}}개\nhome: 처음으로\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("en.json"),
+        r#"{"seats": "{{count}} seats left"}"#,
+    )
+    .unwrap();
+    let locales = Locales::from_dir(dir.path()).unwrap();
+    assert_eq!(locales.langs(), ["en", "ko"]);
+
+    let result = locales
+        .render(&registry, "seats", "en", &json!({ "count": 3 }))
+        .unwrap();
+    assert!(result.to_string().contains(r#""text":"3 seats left""#));
+    assert!(result.to_string().contains(r#""label":"처음으로""#));
+
+    let errors = locales.check_registry(&registry).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].to_string(), "seats: en 문구가 없습니다: `home`");
+}
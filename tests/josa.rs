@@ -0,0 +1,112 @@
+use kakao_rs::josa;
+use kakao_rs::prelude::*;
+
+#[test]
+fn particle_test() {
+    // 받침
+    assert_eq!(josa::attach("공지", "은"), "공지는");
+    assert_eq!(josa::attach("학과", "이"), "학과가");
+    assert_eq!(josa::attach("전공", "를"), "전공을");
+    assert_eq!(josa::attach("교수님", "와"), "교수님과");
+    assert_eq!(josa::attach("도서관", "이에요"), "도서관이에요");
+    assert_eq!(josa::attach("학교", "이에요"), "학교예요");
+
+    // ㄹ 받침은 "로"
+    assert_eq!(josa::attach("서울", "으로"), "서울로");
+    assert_eq!(josa::attach("학생회관", "로"), "학생회관으로");
+    assert_eq!(josa::attach("학교", "(으)로"), "학교로");
+
+    // 괄호, 따옴표는 건너뜁니다.
+    assert_eq!(josa::attach("\"아주대\"", "을"), "\"아주대\"를");
+    assert_eq!(josa::attach("팔달관(본관)", "을"), "팔달관(본관)을");
+
+    // 모르는 조사는 그대로
+    assert_eq!(josa::particle("학교", "에서"), "에서");
+}
+
+#[test]
+fn number_test() {
+    assert_eq!(josa::attach("1", "을"), "1을"); // 일
+    assert_eq!(josa::attach("2", "을"), "2를"); // 이
+    assert_eq!(josa::attach("3", "으로"), "3으로"); // 삼
+    assert_eq!(josa::attach("7", "으로"), "7로"); // 칠
+    assert_eq!(josa::attach("10", "이"), "10이"); // 십
+    assert_eq!(josa::attach("1,000", "은"), "1,000은"); // 천
+    assert_eq!(josa::attach("0", "은"), "0은"); // 영
+    assert_eq!(josa::attach("3.5", "는"), "3.5는"); // 오
+    assert_eq!(josa::attach("2층", "이"), "2층이");
+}
+
+#[test]
+fn english_test() {
+    assert_eq!(josa::attach("Kakao", "을"), "Kakao를");
+    assert_eq!(josa::attach("Google", "으로"), "Google로");
+    assert_eq!(josa::attach("Python", "은"), "Python은");
+    assert_eq!(josa::attach("Rust", "은"), "Rust는");
+    assert_eq!(josa::attach("bot", "이"), "bot이");
+    assert_eq!(josa::attach("API", "를"), "API를");
+    assert_eq!(josa::attach("URL", "으로"), "URL로");
+    assert_eq!(josa::attach("KTX", "을"), "KTX를");
+
+    // 알 수 없으면 두 가지 모두
+    assert_eq!(josa::attach("😀", "을"), "😀을(를)");
+    assert_eq!(josa::attach("", "으로"), "(으)로");
+}
+
+#[test]
+fn macro_test() {
+    let major = "소프트웨어학과";
+    assert_eq!(
+        josa!("{}을 선택했어요", major),
+        "소프트웨어학과를 선택했어요"
+    );
+    assert_eq!(
+        josa!("{}을(를) 선택했어요", "전자공학과"),
+        "전자공학과를 선택했어요"
+    );
+    assert_eq!(
+        josa!("{name}은 {count}이에요.", name = "남은 자리", count = 5),
+        "남은 자리는 5예요."
+    );
+    assert_eq!(josa!("{}와 {}", "사과", "귤",), "사과와 귤");
+    // 조사가 아닌 글자는 그대로
+    assert_eq!(josa!("{}에서 {} 공지", "학교", 3), "학교에서 3 공지");
+    assert_eq!(josa!("공지 없음"), "공지 없음");
+
+    let mut list_card = ListCard::new(josa!("{}이 올린 공지", "학생회"));
+    list_card.push_item(ListItem::new("1번 공지"));
+    let mut result = Template::new();
+    result.push_output(list_card.build());
+    result.push_output(SimpleText::new(josa!("{}를 확인하세요", "공지")).build());
+    assert!(result
+        .to_string()
+        .contains(r#""title":"학생회가 올린 공지""#));
+    assert!(result.to_string().contains(r#""text":"공지를 확인하세요""#));
+}
+
+#[test]
+fn boundary_test() {
+    // 조사가 다음 글자와 붙어 있으면 긴 조사로 보거나 그대로 둡니다.
+    assert_eq!(josa!("{}이나 {}", "사과", "배"), "사과나 배");
+    assert_eq!(josa!("{}이나 {}", "귤", "배"), "귤이나 배");
+    assert_eq!(josa!("{}이면 돼요", "학교"), "학교면 돼요");
+    assert_eq!(josa!("{}면 돼요", "도서관"), "도서관이면 돼요");
+    assert_eq!(
+        josa!("{}이랑 {}으로서", "친구", "회장"),
+        "친구랑 회장으로서"
+    );
+    assert_eq!(josa!("{}이라고 불러요", "Rust"), "Rust라고 불러요");
+    assert_eq!(josa!("{}이다", "사과"), "사과이다");
+    assert_eq!(josa!("{}가게", "학교"), "학교가게");
+    assert_eq!(josa!("{}을, {}은!", "책", "펜"), "책을, 펜은!");
+    assert_eq!(josa!("{}이나", "😀"), "😀(이)나");
+    assert_eq!(josa::attach("서울", "으로써"), "서울로써");
+}
+
+#[test]
+fn format_spec_test() {
+    assert_eq!(josa!("{:.1}점", 4.26519), "4.3점");
+    assert_eq!(josa!("{:.1}이 나왔어요", 2.71), "2.7이 나왔어요");
+    assert_eq!(josa!("[{:>6}]", "사과"), "[    사과]");
+    assert_eq!(josa!("{:03}을", 7), "007을");
+}
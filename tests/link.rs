@@ -0,0 +1,68 @@
+use kakao_rs::prelude::*;
+
+fn wrap(output: Types) -> Template {
+    Template::new().with_output(output)
+}
+
+#[test]
+fn link_targets_serialize_test() {
+    let item = ListItem::new("공지").with_link_targets(
+        Link::default()
+            .with_pc("https://ajou.ac.kr")
+            .with_mobile("https://m.ajou.ac.kr")
+            .with_android("ajou://notice"),
+    );
+    let list_card = ListCard::new("공지").with_item(item);
+    assert_eq!(
+        wrap(list_card.build()).to_string(),
+        r#"{"template":{"outputs":[{"listCard":{"header":{"title":"공지"},"items":[{"title":"공지","link":{"pc":"https://ajou.ac.kr","mobile":"https://m.ajou.ac.kr","android":"ajou://notice"}}]}}]},"version":"2.0"}"#
+    );
+
+    let thumbnail = ThumbNail::new("https://ajou.ac.kr/a.png").with_link("https://ajou.ac.kr");
+    assert_eq!(
+        serde_json::to_string(&thumbnail).unwrap(),
+        r#"{"imageUrl":"https://ajou.ac.kr/a.png","link":{"web":"https://ajou.ac.kr"}}"#
+    );
+}
+
+#[test]
+fn link_targets_deserialize_test() {
+    let json = r#"{"pc":"https://ajou.ac.kr","ios":"ajou://notice","android":"ajou://notice"}"#;
+    let link: Link = serde_json::from_str(json).unwrap();
+    assert_eq!(link.web, None);
+    assert_eq!(link.url(), Some("https://ajou.ac.kr"));
+    assert_eq!(
+        link.targets().collect::<Vec<_>>(),
+        [
+            ("pc", "https://ajou.ac.kr"),
+            ("ios", "ajou://notice"),
+            ("android", "ajou://notice")
+        ]
+    );
+    assert!(link.unknown_fields().is_empty());
+    assert_eq!(serde_json::to_string(&link).unwrap(), json);
+}
+
+#[test]
+fn empty_link_test() {
+    let card = BasicCard::new()
+        .with_thumbnail("https://ajou.ac.kr/a.png")
+        .build();
+    let mut result = wrap(card);
+    assert_eq!(result.validate(), Ok(()));
+
+    if let Types::Basic(basic) = &mut result.outputs_mut()[0] {
+        basic.thumbnail_mut().put_links(Link::default());
+    }
+    let errors = result.validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].path,
+        "template.outputs[0].basicCard.thumbnail.link"
+    );
+
+    let json = r#"{"version":"2.0","template":{"outputs":[{"listCard":{"header":{"title":"공지"},"items":[{"title":"1번","link":{}}]}}]}}"#;
+    let result: Template = serde_json::from_str(json).unwrap();
+    let errors = result.validate().unwrap_err();
+    assert_eq!(errors[0].path, "template.outputs[0].listCard.items[0].link");
+}
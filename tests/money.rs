@@ -0,0 +1,176 @@
+use kakao_rs::money::{self, Currency, Price};
+use kakao_rs::prelude::*;
+
+fn commerce(json: &str) -> Template {
+    let json = format!(
+        r#"{{"version":"2.0","template":{{"outputs":[{{"commerceCard":{{"description":"후드티",{},"thumbnails":[{{"imageUrl":"https://ajou.ac.kr/a.png"}}],"buttons":[{{"label":"구매","action":"message"}}]}}}}]}}}}"#,
+        json
+    );
+    serde_json::from_str(&json).unwrap()
+}
+
+fn errors(json: &str) -> Vec<String> {
+    match commerce(json).validate() {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors.iter().map(ToString::to_string).collect(),
+    }
+}
+
+#[test]
+fn discount_math_test() {
+    let mut card = CommerceCard::new().with_price(35000).with_currency("won");
+    card.put_discount_rate(15);
+    assert_eq!(card.discounted_price(), Some(Price::new(29750)));
+
+    // 가격을 바꾸면 할인가도 다시 계산
+    card.put_price(10001);
+    assert_eq!(card.discounted_price(), Some(Price::new(8500)));
+
+    card.put_discount(2000);
+    assert_eq!(card.discount_rate(), None);
+    assert_eq!(card.discounted_price(), Some(Price::new(8001)));
+
+    card.put_discount_rate(100);
+    assert_eq!(card.discount(), None);
+    assert_eq!(card.discounted_price(), Some(Price::new(0)));
+
+    // 할인 전에 가격을 정하지 않아도 됩니다.
+    let card = CommerceCard::new().with_discount(500).with_price(5000);
+    assert_eq!(card.discounted_price(), Some(Price::new(4500)));
+    assert_eq!(card.sale_price(), Some(Price::new(4500)));
+}
+
+#[test]
+fn format_test() {
+    assert_eq!(money::thousands(0), "0");
+    assert_eq!(money::thousands(1234567), "1,234,567");
+    assert_eq!(money::thousands(-5000), "-5,000");
+    assert_eq!(Price::new(100).to_string(), "100");
+
+    assert_eq!(Currency::Won.format(Price::new(5000)), "5,000원");
+    assert_eq!(Currency::from("USD").format(Price::new(5000)), "5,000 USD");
+
+    let card = CommerceCard::new()
+        .with_price(35000)
+        .with_currency(Currency::Won)
+        .with_discount(5000);
+    assert_eq!(card.price_text(), "35,000원 → 30,000원");
+    let card = CommerceCard::new().with_price(5000).with_currency("won");
+    assert_eq!(card.price_text(), "5,000원");
+}
+
+#[test]
+fn serde_test() {
+    let card = CommerceCard::new()
+        .with_price(35000)
+        .with_currency("won")
+        .with_discount_rate(15);
+    let json = serde_json::to_string(&card).unwrap();
+    assert_eq!(
+        json,
+        r#"{"commerceCard":{"description":"","price":35000,"currency":"won","discountRate":15,"discountedPrice":29750}}"#
+    );
+    let card: CommerceCard = serde_json::from_str(&json).unwrap();
+    assert_eq!(card.currency(), &Currency::Won);
+
+    let json = r#"{"commerceCard":{"description":"","price":-1,"currency":"won"}}"#;
+    assert!(serde_json::from_str::<CommerceCard>(json).is_err());
+}
+
+#[test]
+fn validate_discount_test() {
+    assert!(errors(r#""price":10000,"currency":"won""#).is_empty());
+    assert!(
+        errors(r#""price":10000,"currency":"won","discount":1000,"discountedPrice":9000"#)
+            .is_empty()
+    );
+    assert!(errors(r#""price":10000,"currency":"won","discount":1000"#).is_empty());
+    // 반올림한 할인가도 통과
+    assert!(
+        errors(r#""price":999,"currency":"won","discountRate":15,"discountedPrice":849"#)
+            .is_empty()
+    );
+    assert!(
+        errors(r#""price":999,"currency":"won","discountRate":15,"discountedPrice":850"#)
+            .is_empty()
+    );
+
+    let path = "template.outputs[0].commerceCard";
+    assert_eq!(
+        errors(r#""price":5000,"currency":"WON""#),
+        [format!(
+            "{}.currency: 지원하지 않는 통화입니다 (won만 가능): `WON`",
+            path
+        )]
+    );
+    assert_eq!(
+        errors(r#""price":10000,"currency":"won","discount":1000,"discountedPrice":8000"#),
+        [format!(
+            "{}.discountedPrice: 가격 - 할인 금액(9,000)과 다릅니다 (현재 8,000)",
+            path
+        )]
+    );
+    assert_eq!(
+        errors(r#""price":10000,"currency":"won","discountRate":10,"discountedPrice":8000"#),
+        [format!(
+            "{}.discountedPrice: 할인율 10%를 적용한 가격(9,000)과 다릅니다 (현재 8,000)",
+            path
+        )]
+    );
+    assert_eq!(
+        errors(r#""price":10000,"currency":"won","discountRate":10"#),
+        [format!(
+            "{}.discountedPrice: discountRate가 있으면 필수입니다",
+            path
+        )]
+    );
+    assert_eq!(
+        errors(
+            r#""price":1000,"currency":"won","discount":2000,"discountRate":150,"discountedPrice":3000"#
+        ),
+        [
+            format!("{}.discountRate: 0 ~ 100%만 가능합니다 (현재 150%)", path),
+            format!(
+                "{}.discount: 가격(1,000)보다 클 수 없습니다 (현재 2,000)",
+                path
+            ),
+            format!(
+                "{}.discountedPrice: 가격(1,000)보다 클 수 없습니다 (현재 3,000)",
+                path
+            ),
+        ]
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_price_test() {
+    let card = CommerceCard::new()
+        .set_price(10000)
+        .set_discount_rate_price(10, 9000);
+    assert_eq!(card.price(), Price::new(10000));
+    assert_eq!(card.discounted_price(), Some(Price::new(9000)));
+}
+
+#[test]
+#[allow(deprecated)]
+#[should_panic(expected = "price는 음수가 될 수 없습니다: -5000")]
+fn deprecated_negative_price_test() {
+    let _ = CommerceCard::new().set_price(-5000);
+}
+
+#[test]
+#[allow(deprecated)]
+#[should_panic(expected = "discount는 음수가 될 수 없습니다: -1")]
+fn deprecated_negative_discount_test() {
+    let _ = CommerceCard::new().set_price(5000).set_discount(-1);
+}
+
+#[test]
+#[allow(deprecated)]
+#[should_panic(expected = "discountRate는 0~100이어야 합니다: 150")]
+fn deprecated_rate_out_of_range_test() {
+    let _ = CommerceCard::new()
+        .set_price(5000)
+        .set_discount_rate_price(150, 0);
+}
@@ -0,0 +1,137 @@
+use kakao_rs::prelude::*;
+
+fn notices(n: usize) -> Vec<ListItem> {
+    (1..=n)
+        .map(|i| ListItem::new(format!("{}번 공지", i)))
+        .collect()
+}
+
+fn list_titles(result: &Template) -> Vec<String> {
+    let json: serde_json::Value = serde_json::from_str(&result.to_string()).unwrap();
+    json["template"]["outputs"][0]["listCard"]["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["title"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn page_test() {
+    let paginator = Paginator::new("공지");
+
+    let first = paginator.page(notices(12), 1);
+    assert!(first.validate().is_ok());
+    assert_eq!(
+        list_titles(&first),
+        ["1번 공지", "2번 공지", "3번 공지", "4번 공지", "5번 공지"]
+    );
+    assert!(first
+        .to_string()
+        .contains(r#""header":{"title":"공지 (1/3)"}"#));
+    assert!(first.to_string().contains(
+        r#"{"action":"message","label":"다음 페이지","messageText":"다음 페이지","extra":{"page":2}}"#
+    ));
+
+    // 마지막 페이지에는 바로가기가 없습니다.
+    let last = paginator.page(notices(12), 3);
+    assert_eq!(list_titles(&last), ["11번 공지", "12번 공지"]);
+    assert!(last.template.quick_replies.is_empty());
+
+    // 범위를 넘으면 마지막 페이지
+    assert_eq!(
+        list_titles(&paginator.page(notices(12), 9)),
+        ["11번 공지", "12번 공지"]
+    );
+
+    let empty = paginator.page(notices(0), 1);
+    assert!(empty.validate().is_ok());
+    assert_eq!(
+        empty.to_string(),
+        r#"{"template":{"outputs":[{"simpleText":{"text":"목록이 비어 있습니다."}}]},"version":"2.0"}"#
+    );
+}
+
+#[test]
+fn carousel_test() {
+    let paginator = Paginator::new("공지");
+
+    // 카드마다 4개, 한 페이지에 5장 → 30개는 8장, 2페이지
+    let first = paginator.carousel(notices(30), 1);
+    assert!(first.validate().is_ok(), "{:?}", first.validate());
+    let json: serde_json::Value = serde_json::from_str(&first.to_string()).unwrap();
+    let carousel = &json["template"]["outputs"][0]["carousel"];
+    assert_eq!(carousel["type"], "listCard");
+    assert_eq!(carousel["items"].as_array().unwrap().len(), 5);
+    assert_eq!(carousel["items"][0]["header"]["title"], "공지 (1/8)");
+    assert_eq!(carousel["items"][4]["items"][3]["title"], "20번 공지");
+    assert_eq!(json["template"]["quickReplies"][0]["extra"]["page"], 2);
+
+    let second = paginator.carousel(notices(30), 2);
+    let json: serde_json::Value = serde_json::from_str(&second.to_string()).unwrap();
+    let items = json["template"]["outputs"][0]["carousel"]["items"]
+        .as_array()
+        .unwrap();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0]["header"]["title"], "공지 (6/8)");
+    assert_eq!(items[2]["items"][1]["title"], "30번 공지");
+    assert!(second.template.quick_replies.is_empty());
+
+    // 다시 읽어도 listCard 캐로셀
+    let parsed: Template = serde_json::from_str(&second.to_string()).unwrap();
+    assert_eq!(parsed.to_string(), second.to_string());
+
+    // 캐로셀 안 ListCard는 items 4개까지
+    let mut list_card = ListCard::new("공지");
+    for item in notices(5) {
+        list_card.push_item(item);
+    }
+    let mut carousel = Carousel::new().with_type(ListCard::id());
+    carousel.push_card(list_card.build_card());
+    let mut result = Template::new();
+    result.push_output(carousel.build());
+    let errors = result.validate().unwrap_err();
+    assert_eq!(
+        errors[0].to_string(),
+        "template.outputs[0].carousel.items[0].items: 1 ~ 4개까지 가능합니다 (현재 5개)"
+    );
+}
+
+#[test]
+fn requested_page_test() {
+    let router = Router::new()
+        .utterance("공지", |req: &SkillRequest| {
+            let paginator = Paginator::new("공지").with_context("notice");
+            paginator.page(notices(12), paginator.requested_page(req))
+        })
+        .utterance("다음 페이지", |req: &SkillRequest| {
+            let paginator = Paginator::new("공지").with_context("notice");
+            paginator.page(notices(12), paginator.requested_page(req))
+        });
+
+    // 바로가기 extra
+    Scenario::new(&router)
+        .user("공지")
+        .expect(|result| assert_eq!(list_titles(result)[0], "1번 공지"))
+        .expect_context("notice")
+        .quick_reply("다음 페이지")
+        .expect(|result| assert_eq!(list_titles(result)[0], "6번 공지"))
+        .quick_reply("다음 페이지")
+        .expect(|result| assert_eq!(list_titles(result)[0], "11번 공지"))
+        .run();
+
+    // 직접 입력하면 context
+    Scenario::new(&router)
+        .user("공지")
+        .user("다음 페이지")
+        .expect(|result| assert_eq!(list_titles(result)[0], "6번 공지"))
+        .run();
+
+    let paginator = Paginator::new("공지");
+    let mut request = SkillRequest::new("다음 페이지");
+    assert_eq!(paginator.requested_page(&request), 1);
+    request.action.client_extra = Some(serde_json::from_str(r#"{"page":"3"}"#).unwrap());
+    assert_eq!(paginator.requested_page(&request), 3);
+    request.action.client_extra = Some(serde_json::from_str(r#"{"page":0}"#).unwrap());
+    assert_eq!(paginator.requested_page(&request), 1);
+}
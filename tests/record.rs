@@ -0,0 +1,105 @@
+use kakao_rs::prelude::*;
+use kakao_rs::record::{self, MaskKey, Recorder, Replay};
+use serde_json::Value;
+
+fn echo(req: &SkillRequest) -> Template {
+    let mut result = Template::new();
+    result.push_output(SimpleText::new(req.utterance()).build());
+    result
+}
+
+#[test]
+fn recorder_masks_user_keys_test() {
+    let mut request = SkillRequest::new("오늘 공지").with_user("abcdef123");
+    request
+        .user_request
+        .user
+        .properties
+        .insert("plusfriendUserKey".into(), "plus-key".into());
+    request
+        .user_request
+        .user
+        .properties
+        .insert("isFriend".into(), true.into());
+
+    let recorder = Recorder::new(echo, Vec::new());
+    recorder.handle(&request);
+    recorder.handle(&request);
+
+    let jsonl = String::from_utf8(recorder.into_inner()).unwrap();
+    let lines: Vec<&str> = jsonl.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], lines[1]); // 같은 유저는 같은 값으로 가려짐
+    assert!(!jsonl.contains("abcdef123"));
+    assert!(!jsonl.contains("plus-key"));
+    assert!(jsonl.contains(r#""isFriend":true"#));
+    assert!(jsonl.contains(
+        r#""response":{"template":{"outputs":[{"simpleText":{"text":"오늘 공지"}}]},"version":"2.0"}"#
+    ));
+}
+
+#[test]
+fn mask_key_test() {
+    let key = MaskKey::new("salt");
+    let masked = key.mask("abcdef123");
+    assert!(masked.starts_with("masked:"));
+    assert_eq!(masked.len(), "masked:".len() + 32);
+    assert_eq!(masked, MaskKey::new("salt").mask("abcdef123"));
+    assert_ne!(masked, MaskKey::new("pepper").mask("abcdef123"));
+    assert_ne!(masked, MaskKey::random().mask("abcdef123"));
+    // 이미 가린 것처럼 보여도 다시 가립니다.
+    assert_ne!(key.mask("masked:0123"), "masked:0123");
+    assert_eq!(format!("{:?}", key), "MaskKey(..)");
+
+    let mut request = SkillRequest::new("공지").with_user("masked:abc");
+    record::mask_user_keys(&mut request, &key);
+    assert_eq!(request.user_request.user.id, key.mask("masked:abc"));
+
+    // 같은 키면 기록끼리 같은 값
+    let record = |key: MaskKey| {
+        let recorder = Recorder::new(echo, Vec::new()).with_mask_key(key);
+        recorder.handle(&SkillRequest::new("공지").with_user("abcdef123"));
+        String::from_utf8(recorder.into_inner()).unwrap()
+    };
+    assert_eq!(record(MaskKey::new("salt")), record(MaskKey::new("salt")));
+    assert!(record(MaskKey::new("salt")).contains(&masked));
+}
+
+#[test]
+fn replay_reports_changed_responses_test() {
+    let recorder = Recorder::new(echo, Vec::new());
+    recorder.handle(&SkillRequest::new("공지"));
+    recorder.handle(&SkillRequest::new("학식"));
+    let jsonl = recorder.into_inner();
+
+    let replay = Replay::from_reader(jsonl.as_slice()).unwrap();
+    assert!(replay.run(&echo).is_clean());
+
+    let changed = |req: &SkillRequest| {
+        if req.utterance() == "학식" {
+            let mut result = Template::new();
+            result.push_output(SimpleText::new("오늘 학식 없음").build());
+            result
+        } else {
+            echo(req)
+        }
+    };
+    let report = replay.run(&changed);
+    assert_eq!(report.total, 2);
+    assert_eq!(report.changed.len(), 1);
+    assert_eq!(report.changed[0].line, 2);
+    assert_eq!(report.changed[0].utterance, "학식");
+
+    // HTTP 등으로 응답 JSON을 직접 구할 때
+    let report = replay.run_with(|req| match req.utterance() {
+        "공지" => echo(req).build(),
+        _ => Value::String("HTTP 500".to_string()),
+    });
+    assert_eq!(report.changed.len(), 1);
+    assert_eq!(report.changed[0].current, "HTTP 500");
+
+    let err = Replay::from_reader("{}\nnot json".as_bytes())
+        .err()
+        .unwrap();
+    assert!(err.to_string().starts_with("line 1"));
+}
@@ -0,0 +1,163 @@
+use kakao_rs::prelude::*;
+use kakao_rs::render::{TerminalRenderer, TextRenderer};
+
+#[test]
+fn terminal_list_card_test() {
+    let mut result = Template::new();
+    result.push_quick_reply(QuickReply::new("오늘", "오늘 공지 보여줘"));
+    result.push_quick_reply(QuickReply::new("어제", "어제 공지 보여줘"));
+
+    let mut list_card = ListCard::new("아주대 공지");
+    list_card.push_item(ListItem::new("수강신청 안내").with_description("2학기 수강신청 일정"));
+    list_card.push_item(ListItem::new("장학금 신청"));
+    list_card.push_button(Button::text("더보기"));
+    list_card.push_button(Button::link("홈페이지", "https://ajou.ac.kr"));
+    result.push_output(list_card.build());
+
+    let rendered = TerminalRenderer::new().with_width(30).render(&result);
+    let expected = "\
+┌────────────────────────────────┐
+│ 아주대 공지                    │
+├────────────────────────────────┤
+│ * 수강신청 안내                │
+│   2학기 수강신청 일정          │
+│ * 장학금 신청                  │
+├────────────────────────────────┤
+│ [1] 더보기                     │
+│ [홈페이지] ->                  │
+│ https://ajou.ac.kr             │
+└────────────────────────────────┘
+(2 오늘) (3 어제)
+";
+    assert_eq!(expected, rendered);
+}
+
+#[test]
+fn terminal_carousel_width_test() {
+    let mut result = Template::new();
+
+    let mut carousel = Carousel::new().with_type(CommerceCard::id());
+    for i in 0..2 {
+        let commerce_card = CommerceCard::new()
+            .with_price(15000)
+            .with_description(format!(
+                "{}번 상품 설명이 아주 길어서 여러 줄로 나뉘어야 합니다",
+                i
+            ))
+            .with_currency("won")
+            .with_discount_rate_price(10, 13500)
+            .with_thumbnail(
+                "http://k.kakaocdn.net/dn/APR96/btqqH7zLanY/kD5mIPX7TdD2NAxgP29cC0/1x1.jpg",
+            )
+            .with_button(Button::text("구매하기"));
+        carousel.push_card(commerce_card.build_card());
+    }
+    result.push_output(carousel.build());
+
+    let rendered = result.render_terminal();
+    assert!(rendered.contains("< 2/2 >"));
+    assert!(rendered.contains("정가 15,000원 / 할인가 13,500원"));
+    assert!(rendered.contains("[2] 구매하기"));
+
+    // 한글이 섞여도 모든 박스 줄의 폭이 같아야 함
+    for line in rendered.lines().filter(|l| l.starts_with('│')) {
+        let width: usize = line
+            .chars()
+            .map(|c| if ('가'..='힣').contains(&c) { 2 } else { 1 })
+            .sum();
+        assert_eq!(width, 40, "{}", line);
+    }
+}
+
+#[test]
+fn html_preview_test() {
+    let mut result = Template::new();
+    result.push_quick_reply(QuickReply::new("<오늘>", "오늘 공지 보여줘"));
+
+    let mut carousel = Carousel::new().with_type(CommerceCard::id());
+    carousel.push_card(
+        CommerceCard::new()
+            .with_description("아메리카노")
+            .with_price(5000)
+            .with_discount(500)
+            .with_currency("won")
+            .with_thumbnail("https://example.com/coffee.png")
+            .with_button(Button::link("주문", "javascript:alert(1)"))
+            .build_card(),
+    );
+    result.push_output(carousel.build());
+
+    let html = result.render_html();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<div class=\"kakao-carousel\">"));
+    assert!(html.contains("<span class=\"original\">5,000원</span>"));
+    assert!(html.contains("<span class=\"final\">4,500원</span>"));
+    assert!(html.contains("&lt;오늘&gt;"));
+    assert!(html.contains("src=\"https://example.com/coffee.png\""));
+    assert!(!html.contains("javascript:"));
+}
+
+#[test]
+fn plain_text_and_markdown_test() {
+    let mut result = Template::new();
+    result.push_output(SimpleText::new("오늘의 메뉴").build());
+
+    let mut carousel = Carousel::new().with_type(CommerceCard::id());
+    carousel.push_card(
+        CommerceCard::new()
+            .with_description("아메리카노")
+            .with_price(5000)
+            .with_discount(500)
+            .with_currency("won")
+            .with_thumbnail("https://example.com/coffee.png")
+            .with_button(Button::link("주문", "https://example.com/order"))
+            .build_card(),
+    );
+    carousel.push_card(
+        CommerceCard::new()
+            .with_description("라떼")
+            .with_price(5500)
+            .with_currency("won")
+            .with_button(Button::call("전화 주문", "0311234567"))
+            .build_card(),
+    );
+    result.push_output(carousel.build());
+    result.push_quick_reply(QuickReply::new("오늘", "오늘 메뉴"));
+    result.push_quick_reply(QuickReply::new("내일", "내일 메뉴"));
+
+    assert_eq!(
+        result.to_plain_text(),
+        "\
+오늘의 메뉴
+
+(1/2) 아메리카노
+5,000원 → 4,500원
+▶ 주문 (https://example.com/order)
+
+(2/2) 라떼
+5,500원
+▶ 전화 주문 (전화 0311234567)
+
+바로가기: 오늘, 내일"
+    );
+
+    let markdown = TextRenderer::markdown()
+        .with_images(true)
+        .with_quick_replies(false)
+        .with_separator("\n---\n")
+        .render(&result);
+    assert_eq!(
+        markdown,
+        "\
+오늘의 메뉴
+---
+(1/2) **아메리카노**
+5,000원 → 4,500원
+![](https://example.com/coffee.png)
+▶ [주문](https://example.com/order)
+---
+(2/2) **라떼**
+5,500원
+▶ 전화 주문 (전화 0311234567)"
+    );
+}
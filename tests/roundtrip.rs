@@ -0,0 +1,89 @@
+use kakao_rs::prelude::*;
+use serde_json::{json, Value};
+
+fn future_response() -> Value {
+    json!({
+        "version": "2.0",
+        "useCallback": false,
+        "template": {
+            "outputs": [
+                {
+                    "simpleText": {"text": "안녕하세요", "forwardable": true}
+                },
+                {
+                    "carousel": {
+                        "type": "itemCard",
+                        "items": [
+                            {
+                                "thumbnail": {"imageUrl": "https://example.com/a.png", "fixedRatio": true},
+                                "profile": {"title": "아주대", "imageUrl": "https://example.com/p.png"},
+                                "itemList": [{"title": "항목", "description": "설명"}],
+                                "buttons": [
+                                    {"label": "보기", "action": "webLink", "webLinkUrl": "https://ajou.ac.kr", "highlight": true}
+                                ]
+                            }
+                        ]
+                    }
+                },
+                {
+                    "basicCard": {
+                        "title": "제목",
+                        "thumbnail": {"imageUrl": "https://example.com/b.png"},
+                        "forwardable": true
+                    }
+                }
+            ],
+            "quickReplies": [
+                {"label": "오늘", "action": "message", "messageText": "오늘", "icon": "today"}
+            ]
+        }
+    })
+}
+
+#[test]
+fn lossless_roundtrip_test() {
+    let response = future_response();
+    let template: Template = serde_json::from_value(response.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&template).unwrap(), response);
+
+    // 문자열로 다시 읽어도 같아야 합니다.
+    let again: Template = serde_json::from_str(&template.to_string()).unwrap();
+    assert_eq!(serde_json::to_value(&again).unwrap(), response);
+
+    assert_eq!(template.unknown_fields()["useCallback"], json!(false));
+    assert_eq!(template.validate(), Ok(()));
+}
+
+#[test]
+fn item_card_with_thumbnail_is_not_basic_card_test() {
+    let template: Template = serde_json::from_value(future_response()).unwrap();
+    let carousel = serde_json::to_value(&template).unwrap()["template"]["outputs"][1].clone();
+    assert!(carousel["carousel"]["items"][0].get("itemList").is_some());
+
+    // 타입이 맞지 않으면 validate에서 캐로셀 타입 오류가 났을 것입니다.
+    assert!(template.validate().is_ok());
+}
+
+#[test]
+fn strict_mode_reports_unknown_fields_test() {
+    let template: Template = serde_json::from_value(future_response()).unwrap();
+    let paths: Vec<String> = template
+        .validate_strict()
+        .unwrap_err()
+        .into_iter()
+        .inspect(|e| assert!(e.is_unknown_field()))
+        .map(|e| e.path)
+        .collect();
+
+    assert_eq!(
+        paths,
+        vec![
+            "useCallback",
+            "template.outputs[0].simpleText.forwardable",
+            "template.outputs[1].carousel.items[0].profile",
+            "template.outputs[1].carousel.items[0].buttons[0].highlight",
+            "template.outputs[2].basicCard.forwardable",
+            "template.quickReplies[0].icon",
+        ]
+    );
+}
@@ -0,0 +1,207 @@
+use kakao_rs::prelude::*;
+use kakao_rs::scenario::choices;
+
+fn notice_router() -> Router {
+    Router::new()
+        .utterance("공지", |_: &SkillRequest| {
+            let mut result = Template::new();
+            result.push_output(SimpleText::new("학과를 선택하세요.").build());
+            result.push_quick_reply(
+                QuickReply::new("소프트웨어학과", "소프트웨어학과")
+                    .with_action("block")
+                    .with_block_id("major")
+                    .with_extra("major", "sw"),
+            );
+            result
+        })
+        .block("major", |req: &SkillRequest| {
+            let major = req.client_extra("major").and_then(|v| v.as_str()).unwrap();
+
+            let mut result = Template::new();
+            result.push_context(ContextValue::new("notice", 2).with_param("major", major));
+
+            let mut list_card = ListCard::new(format!("{} 공지", major));
+            list_card.push_item(ListItem::new("1번 공지"));
+            list_card.push_button(Button::block("더보기", "more").with_extra("page", 2));
+            result.push_output(list_card.build());
+            result
+        })
+        .block("more", |req: &SkillRequest| {
+            let major = req
+                .context("notice")
+                .and_then(|c| c.param("major"))
+                .unwrap_or("없음");
+            let page = req.client_extra("page").and_then(|v| v.as_i64()).unwrap();
+
+            let mut result = Template::new();
+            result.push_output(SimpleText::new(format!("{} {}페이지", major, page)).build());
+            result
+        })
+        .fallback(|req: &SkillRequest| {
+            let mut result = Template::new();
+            result
+                .push_output(SimpleText::new(format!("context {}개", req.contexts.len())).build());
+            result
+        })
+}
+
+#[test]
+fn scenario_carries_extra_and_context_test() {
+    let router = notice_router();
+
+    let session = Scenario::new(&router)
+        .user("공지")
+        .expect_text("학과를 선택하세요.")
+        .expect_quick_reply("소프트웨어학과")
+        .quick_reply("소프트웨어학과")
+        .expect_context("notice")
+        .expect(|res| assert_eq!(res.template.outputs.len(), 1))
+        .button("더보기")
+        .expect_text("sw 2페이지")
+        .user("아무말")
+        .expect_text("context 1개")
+        .user("아무말")
+        .expect_text("context 0개")
+        .run();
+
+    assert!(session.contexts().is_empty());
+}
+
+#[test]
+fn scenario_reports_failed_step_test() {
+    let router = notice_router();
+
+    let err = Scenario::new(&router)
+        .user("공지")
+        .quick_reply("없는 학과")
+        .try_run()
+        .unwrap_err();
+    assert_eq!(err.step, 2);
+    assert!(matches!(err.action, UserAction::QuickReply(_)));
+
+    let err = Scenario::new(&router)
+        .user("공지")
+        .expect_text("다른 텍스트")
+        .try_run()
+        .unwrap_err();
+    assert_eq!(err.step, 1);
+}
+
+fn carousel_router() -> Router {
+    Router::new()
+        .utterance("학과", |_: &SkillRequest| {
+            let mut result = Template::new();
+            let mut carousel = Carousel::new().with_type(BasicCard::id());
+            for major in ["sw", "media"] {
+                carousel.push_card(
+                    BasicCard::new()
+                        .with_title(major)
+                        .with_button(Button::link("홈페이지", "https://ajou.ac.kr"))
+                        .with_button(Button::block("공지", "notice").with_extra("major", major))
+                        .build_card(),
+                );
+            }
+            result.push_output(carousel.build());
+            result.push_quick_reply(QuickReply::new("처음으로", "처음"));
+            result.push_quick_reply(QuickReply::new("처음으로", "다시"));
+            result
+        })
+        .block("notice", |req: &SkillRequest| {
+            let major = req.client_extra("major").and_then(|v| v.as_str()).unwrap();
+            let mut result = Template::new();
+            result.push_output(SimpleText::new(format!("{} 공지", major)).build());
+            result
+        })
+        .fallback(|req: &SkillRequest| {
+            let mut result = Template::new();
+            result.push_output(SimpleText::new(format!("발화 {}", req.utterance())).build());
+            result
+        })
+}
+
+#[test]
+fn scenario_button_position_test() {
+    let router = carousel_router();
+
+    // 같은 라벨은 위치로 고릅니다.
+    Scenario::new(&router)
+        .user("학과")
+        .button_at(0, 1, 1)
+        .expect_text("media 공지")
+        .user("학과")
+        .button_at(0, 0, 1)
+        .expect_text("sw 공지")
+        .user("학과")
+        .quick_reply_at(1)
+        .expect_text("발화 다시")
+        .run();
+
+    let err = Scenario::new(&router)
+        .user("학과")
+        .button("공지")
+        .try_run()
+        .unwrap_err();
+    assert_eq!(err.step, 2);
+    assert!(err.message.contains("여러 개"), "{}", err.message);
+
+    let err = Scenario::new(&router)
+        .user("학과")
+        .quick_reply("처음으로")
+        .try_run()
+        .unwrap_err();
+    assert!(err.message.contains("여러 개"), "{}", err.message);
+
+    let err = Scenario::new(&router)
+        .user("학과")
+        .button_at(0, 2, 0)
+        .try_run()
+        .unwrap_err();
+    assert_eq!(err.action, UserAction::ButtonAt(ButtonAt::new(0, 2, 0)));
+
+    // 링크 버튼은 발화를 보내지 않습니다.
+    let err = Scenario::new(&router)
+        .user("학과")
+        .button_at(0, 0, 0)
+        .try_run()
+        .unwrap_err();
+    assert!(
+        err.message.contains("발화를 보내지 않습니다"),
+        "{}",
+        err.message
+    );
+}
+
+#[test]
+fn choices_test() {
+    let router = carousel_router();
+    let template = router.handle(&SkillRequest::new("학과"));
+
+    // 링크 버튼은 빠지고 위치로 고릅니다.
+    assert_eq!(
+        choices(&template),
+        [
+            UserAction::ButtonAt(ButtonAt::new(0, 0, 1)),
+            UserAction::ButtonAt(ButtonAt::new(0, 1, 1)),
+            UserAction::QuickReplyAt(0),
+            UserAction::QuickReplyAt(1),
+        ]
+    );
+    let rendered = template.render_terminal();
+    assert!(rendered.contains("[1] 공지"), "{}", rendered);
+    assert!(rendered.contains("[2] 공지"), "{}", rendered);
+    assert!(rendered.contains("[홈페이지] ->"), "{}", rendered);
+    assert!(
+        rendered.contains("(3 처음으로) (4 처음으로)"),
+        "{}",
+        rendered
+    );
+
+    let mut session = Session::new();
+    let actions = choices(&template);
+    session.absorb(template);
+    let response = session.send(&router, &actions[1]).unwrap();
+    match &response.template.outputs[0] {
+        Types::SimpleTxt(text) => assert_eq!(text.text(), "media 공지"),
+        output => panic!("{:?}", output),
+    }
+}
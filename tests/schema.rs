@@ -0,0 +1,96 @@
+#![cfg(feature = "schema")]
+
+use jsonschema::JSONSchema;
+use kakao_rs::prelude::*;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+
+fn compile(schema: impl serde::Serialize) -> JSONSchema {
+    let schema = serde_json::to_value(schema).unwrap();
+    JSONSchema::compile(&schema).expect("valid JSON Schema")
+}
+
+fn errors(schema: &JSONSchema, instance: &Value) -> Vec<String> {
+    match schema.validate(instance) {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors
+            .map(|e| format!("{}: {}", e.instance_path, e))
+            .collect(),
+    }
+}
+
+/// `//` 주석 줄을 뺀 골든 JSON
+fn golden(path: &Path) -> Value {
+    let text: String = fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("//"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    serde_json::from_str(&text).unwrap()
+}
+
+#[test]
+fn golden_files_match_schema_test() {
+    let template = compile(kakao_rs::schema::template());
+    let request = compile(kakao_rs::schema::skill_request());
+
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+    let mut checked = 0;
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().map_or(true, |ext| ext != "json") {
+            continue;
+        }
+        let schema = if path.file_stem().unwrap() == "request" {
+            &request
+        } else {
+            &template
+        };
+        let instance = golden(&path);
+        assert_eq!(
+            errors(schema, &instance),
+            Vec::<String>::new(),
+            "{:?}",
+            path
+        );
+        checked += 1;
+    }
+    assert!(checked >= 2);
+}
+
+#[test]
+fn schema_limits_test() {
+    let schema = compile(kakao_rs::schema::template());
+
+    let mut result = Template::new();
+    result.push_quick_reply(QuickReply::new("오늘", "오늘 공지 보여줘"));
+    let mut list_card = ListCard::new("공지");
+    list_card.push_item(ListItem::new("1번 공지").with_link("https://naver.com"));
+    list_card.push_button(Button::link("더보기", "https://ajou.ac.kr"));
+    result.push_output(list_card.build());
+    assert_eq!(
+        errors(&schema, &serde_json::to_value(&result).unwrap()),
+        Vec::<String>::new()
+    );
+
+    // 말풍선 4개, 알 수 없는 버튼 action
+    let mut value = serde_json::to_value(&result).unwrap();
+    let output = value["template"]["outputs"][0].clone();
+    for _ in 0..3 {
+        value["template"]["outputs"]
+            .as_array_mut()
+            .unwrap()
+            .push(output.clone());
+    }
+    value["template"]["outputs"][0]["listCard"]["buttons"][0]["action"] = json!("open");
+
+    let errors = errors(&schema, &value);
+    assert!(
+        errors.iter().any(|e| e.starts_with("/template/outputs:")),
+        "{:?}",
+        errors
+    );
+    assert!(!schema.is_valid(&value));
+}
@@ -0,0 +1,116 @@
+use kakao_rs::prelude::*;
+
+fn texts(result: &Template) -> Vec<String> {
+    let json: serde_json::Value = serde_json::from_str(&result.to_string()).unwrap();
+    json["template"]["outputs"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|output| output["simpleText"]["text"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn split_test() {
+    let splitter = TextSplitter::new().with_max_chars(20);
+
+    // 문장 끝
+    assert_eq!(
+        splitter.split("오늘은 수강신청 첫날입니다. 서버가 느릴 수 있습니다. 양해 바랍니다."),
+        [
+            "오늘은 수강신청 첫날입니다.",
+            "서버가 느릴 수 있습니다.",
+            "양해 바랍니다."
+        ]
+    );
+
+    // 줄바꿈이 문장 끝보다 먼저
+    assert_eq!(
+        splitter.split("신청은 포털에서. 일정은 다음 주\n문의는 학사팀"),
+        ["신청은 포털에서. 일정은 다음 주", "문의는 학사팀"]
+    );
+
+    // 앞쪽 절반의 줄바꿈은 건너뜁니다.
+    assert_eq!(
+        splitter.split("1. 일정 안내\n2. 신청 방법. 자세한 내용은 홈페이지"),
+        ["1. 일정 안내\n2. 신청 방법.", "자세한 내용은 홈페이지"]
+    );
+
+    // 자를 곳이 없으면 글자 수대로 (바이트가 아니라 글자)
+    let long = "가".repeat(1500);
+    let chunks = TextSplitter::new().split(&long);
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].chars().count(), 1000);
+    assert_eq!(chunks[1].chars().count(), 500);
+
+    assert!(splitter.split("  ").is_empty());
+    assert_eq!(splitter.split("짧은 글"), ["짧은 글"]);
+}
+
+#[test]
+fn template_test() {
+    let short = TextSplitter::new().template("짧은 공지");
+    assert_eq!(
+        short.to_string(),
+        r#"{"template":{"outputs":[{"simpleText":{"text":"짧은 공지"}}]},"version":"2.0"}"#
+    );
+
+    let notice = (1..=500)
+        .map(|i| format!("{}번째 문장입니다.", i))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let result = TextSplitter::new().template(&notice);
+    assert!(result.validate().is_ok());
+
+    let outputs = texts(&result);
+    assert_eq!(outputs.len(), 3);
+    assert!(outputs.iter().all(|text| text.ends_with("문장입니다.")));
+
+    // 남은 글은 "더보기"에
+    let qr = &result.template.quick_replies[0];
+    assert_eq!(qr.label(), "더보기");
+    let rest = qr.extra().unwrap()["more"].as_str().unwrap();
+    assert_eq!(format!("{} {}", outputs.join(" "), rest), notice);
+
+    // 제자리 설정
+    let mut splitter = TextSplitter::new();
+    splitter
+        .put_max_outputs(1)
+        .put_more_label("계속")
+        .put_block_id("more");
+    let result = splitter.template(&notice);
+    assert_eq!(texts(&result).len(), 1);
+    let qr = &result.template.quick_replies[0];
+    assert_eq!((qr.label(), qr.block_id()), ("계속", Some("more")));
+}
+
+#[test]
+fn more_test() {
+    let notice = (1..=300)
+        .map(|i| format!("{}번째 문장입니다.", i))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let router = Router::new()
+        .utterance("공지", move |_: &SkillRequest| {
+            TextSplitter::new().template(&notice)
+        })
+        .utterance("더보기", |req: &SkillRequest| {
+            let splitter = TextSplitter::new();
+            splitter.template(splitter.remainder(req).unwrap_or("더 없습니다."))
+        });
+
+    Scenario::new(&router)
+        .user("공지")
+        .expect_quick_reply("더보기")
+        .quick_reply("더보기")
+        .expect(|result| {
+            assert!(result.validate().is_ok());
+            assert!(texts(result)
+                .last()
+                .unwrap()
+                .ends_with("300번째 문장입니다."));
+            assert!(result.template.quick_replies.is_empty());
+        })
+        .run();
+}
@@ -0,0 +1,82 @@
+use kakao_rs::prelude::*;
+use kakao_rs::text;
+
+#[test]
+fn truncate_test() {
+    // 한글은 바이트가 아니라 글자 단위
+    assert_eq!(text::truncate("수강신청 안내", 7), "수강신청 안내");
+    assert_eq!(text::truncate("수강신청 안내", 6), "수강신청…");
+    assert_eq!(text::truncate("수강신청 안내", 0), "");
+
+    // 이모지를 반으로 자르지 않습니다.
+    let family = "가족👨‍👩‍👧 행사";
+    assert_eq!(text::len(family), 6);
+    assert_eq!(text::cut(family, 3), "가족");
+    assert_eq!(text::cut(family, 7), "가족👨‍👩‍👧");
+    assert_eq!(text::truncate(family, 6), "가족…");
+    assert!(text::fits(&text::truncate(family, 6), 6));
+
+    // 조합형 한글(NFD)
+    let nfd = "\u{1100}\u{1161}\u{11A8}\u{1100}\u{1161}";
+    assert_eq!(text::len(nfd), 2);
+    assert_eq!(text::cut(nfd, 4), "\u{1100}\u{1161}\u{11A8}");
+}
+
+#[test]
+fn normalize_whitespace_test() {
+    assert_eq!(
+        text::normalize_whitespace("\n  1학기\u{a0} 수강신청\t안내  \r\n\r\n\r\n 일정:  2월 \n"),
+        "1학기 수강신청 안내\n\n일정: 2월"
+    );
+    assert_eq!(text::normalize_whitespace(" \n\t"), "");
+}
+
+#[test]
+fn fit_test() {
+    // 빌더는 줄이지 않습니다.
+    let long = "가".repeat(60);
+    let item = ListItem::new(long.as_str()).with_description(long.as_str());
+    let mut list_card = ListCard::new(long.as_str());
+    list_card.push_item(item);
+    let mut result = Template::new();
+    result.push_output(list_card.build());
+    assert_eq!(result.validate().unwrap_err().len(), 3);
+
+    let item = ListItem::new(long.as_str()).with_description(long.as_str());
+    let mut list_card = ListCard::new(long.as_str());
+    list_card.push_item(item);
+    list_card.push_button(Button::link(
+        "학사 공지사항 전체 보러 가기",
+        "https://ajou.ac.kr",
+    ));
+    let mut result = Template::new();
+    result.push_output(list_card.build());
+    result.push_output(
+        BasicCard::new()
+            .with_title(long.as_str())
+            .with_description("나".repeat(300))
+            .with_thumbnail("https://ajou.ac.kr/logo.png")
+            .build(),
+    );
+    result.push_quick_reply(QuickReply::new("오늘 올라온 학사 공지", "오늘 공지"));
+    assert!(result.validate().is_err());
+    result.fit();
+
+    assert!(result.validate().is_ok(), "{:?}", result.validate());
+    let json = result.to_string();
+    assert!(json.contains(&format!(r#""title":"{}…""#, "가".repeat(49))));
+    assert!(json.contains(r#""label":"학사 공지사항 전체 보러…""#));
+    assert!(json.contains(r#""label":"오늘 올라온 학사 공지""#));
+
+    // 부른 값만 줄입니다.
+    let label = "수강신청 바로가기 버튼입니다";
+    assert_eq!(
+        Button::text(label).fitted().label(),
+        "수강신청 바로가기 버튼입…"
+    );
+    assert_eq!(Button::text(label).label(), label);
+    let mut carousel = Carousel::new().with_type(BasicCard::id());
+    carousel.push_card(BasicCard::new().with_title(long.as_str()).build_card());
+    let json = serde_json::to_string(&carousel.fitted()).unwrap();
+    assert!(json.contains(&format!(r#""title":"{}…""#, "가".repeat(49))));
+}
@@ -0,0 +1,77 @@
+use kakao_rs::prelude::*;
+
+const THUMBNAIL: &str = "https://ajou.ac.kr/notice.png";
+
+fn to_string(output: Types) -> String {
+    let mut result = Template::new();
+    result.push_output(output);
+    assert!(result.validate().is_ok(), "{:?}", result.validate());
+    result.to_string()
+}
+
+#[test]
+fn basic_card_test() {
+    let typed = BasicCard::builder()
+        .with_title("공지")
+        .with_description("1번 공지")
+        .with_button(Button::link("열기", "https://ajou.ac.kr"))
+        .with_thumbnail(THUMBNAIL)
+        .with_fixed_ratio(true)
+        .build();
+    let loose = BasicCard::new()
+        .with_title("공지")
+        .with_description("1번 공지")
+        .with_button(Button::link("열기", "https://ajou.ac.kr"))
+        .with_thumbnail(THUMBNAIL)
+        .with_fixed_ratio(true)
+        .build();
+    assert_eq!(to_string(typed), to_string(loose));
+
+    // 기존 빌더로 바꿔서 이어 쓰기
+    let card = BasicCard::builder()
+        .with_thumbnail(THUMBNAIL)
+        .into_card()
+        .with_title("제목");
+    assert!(to_string(card.build()).contains(r#""title":"제목""#));
+}
+
+#[test]
+fn commerce_card_test() {
+    // 필수 필드는 어떤 순서로 채워도 됩니다.
+    let typed = CommerceCard::builder("아주대 후드티")
+        .with_button(Button::link("구매하기", "https://ajou.ac.kr/shop"))
+        .with_price(35000, "won")
+        .with_thumbnail("https://ajou.ac.kr/old.png")
+        .with_thumbnail("https://ajou.ac.kr/hood.png")
+        .with_discount(5000)
+        .build();
+    assert_eq!(
+        to_string(typed),
+        r#"{"template":{"outputs":[{"commerceCard":{"description":"아주대 후드티","price":35000,"currency":"won","discount":5000,"discountedPrice":30000,"thumbnails":[{"imageUrl":"https://ajou.ac.kr/hood.png"}],"buttons":[{"label":"구매하기","action":"webLink","webLinkUrl":"https://ajou.ac.kr/shop"}]}}]},"version":"2.0"}"#
+    );
+}
+
+#[test]
+fn list_card_test() {
+    let mut list_card = ListCard::builder("공지").with_item(ListItem::new("1번 공지"));
+    for i in 2..=3 {
+        list_card = list_card.with_item(ListItem::new(format!("{}번 공지", i)));
+    }
+    let list_card = list_card.with_button(Button::text("더보기"));
+
+    let mut loose = ListCard::new("공지");
+    for i in 1..=3 {
+        loose.push_item(ListItem::new(format!("{}번 공지", i)));
+    }
+    loose.push_button(Button::text("더보기"));
+    assert_eq!(to_string(list_card.build()), to_string(loose.build()));
+
+    // 캐로셀에도
+    let mut carousel = Carousel::new().with_type(ListCard::id());
+    carousel.push_card(
+        ListCard::builder("학식")
+            .with_item(ListItem::new("오늘"))
+            .build_card(),
+    );
+    assert!(to_string(carousel.build()).contains(r#""type":"listCard""#));
+}
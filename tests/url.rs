@@ -0,0 +1,76 @@
+use kakao_rs::prelude::*;
+use kakao_rs::url::{self, Place, Url};
+
+#[test]
+fn url_builder_test() {
+    let url = Url::new("https://ajou.ac.kr/")
+        .with_segments(["게시판", "a/b?c#d", "x y"])
+        .with_query("q", "a+b=c&d")
+        .with_query("빈값", "");
+    assert_eq!(
+        url.to_string(),
+        "https://ajou.ac.kr/%EA%B2%8C%EC%8B%9C%ED%8C%90/a%2Fb%3Fc%23d/x%20y?q=a%2Bb%3Dc%26d&%EB%B9%88%EA%B0%92="
+    );
+
+    // base에 있던 쿼리와 fragment는 그대로
+    let url = Url::new("https://ajou.ac.kr/notice?id=1#top").with_query("page", "2");
+    assert_eq!(url.to_string(), "https://ajou.ac.kr/notice?id=1&page=2#top");
+
+    assert_eq!(
+        url::encode_segment("나연(TWICE):@"),
+        "%EB%82%98%EC%97%B0(TWICE):@"
+    );
+    assert_eq!(url::encode_query("(TWICE)"), "%28TWICE%29");
+
+    // 사용자 입력이 상위 경로로 빠져나가지 않도록
+    let url = Url::new("https://namu.wiki/w").with_segments(["..", ".", "...", "a.b"]);
+    assert_eq!(url.to_string(), "https://namu.wiki/w/%2E%2E/%2E/.../a.b");
+}
+
+#[test]
+fn link_button_test() {
+    let url = Url::new("https://namu.wiki").with_segments(["w", "나연(TWICE)"]);
+    let button = Button::link("나무위키", url.clone());
+    assert_eq!(
+        button.web_link_url(),
+        Some("https://namu.wiki/w/%EB%82%98%EC%97%B0(TWICE)")
+    );
+
+    let mut button = Button::new(ButtonType::Link).with_label("열기");
+    button.put_link(url);
+    assert_eq!(
+        button.web_link_url(),
+        Some("https://namu.wiki/w/%EB%82%98%EC%97%B0(TWICE)")
+    );
+    assert!(Button::try_link("열기", Url::new("https://ajou.ac.kr")).is_ok());
+}
+
+#[test]
+fn deep_link_test() {
+    let ajou = Place::new("아주대, 정문", 37.2830, 127.0435);
+    let buttons = vec![
+        Button::kakao_map_search("검색", "아주대"),
+        Button::kakao_map_route(
+            "길찾기",
+            Some(&Place::new("수원역", 37.2664, 127.0001)),
+            &ajou,
+        ),
+        Button::kakao_channel_chat("상담", "_ZeUTxl"),
+        Button::kakao_map_route("길안내", None, &ajou),
+    ];
+    let urls: Vec<_> = buttons.iter().map(|b| b.web_link_url().unwrap()).collect();
+    assert_eq!(
+        urls,
+        [
+            "https://map.kakao.com/link/search/%EC%95%84%EC%A3%BC%EB%8C%80",
+            "https://map.kakao.com/link/from/%EC%88%98%EC%9B%90%EC%97%AD,37.2664,127.0001/to/%EC%95%84%EC%A3%BC%EB%8C%80%2C%20%EC%A0%95%EB%AC%B8,37.283,127.0435",
+            "https://pf.kakao.com/_ZeUTxl/chat",
+            "https://map.kakao.com/link/to/%EC%95%84%EC%A3%BC%EB%8C%80%2C%20%EC%A0%95%EB%AC%B8,37.283,127.0435",
+        ]
+    );
+    assert!(buttons.iter().all(|b| b.action() == "webLink"));
+
+    let result = Template::new().with_output(ListCard::new("안내").with_buttons(buttons).build());
+    let errors = result.validate().unwrap_err();
+    assert!(!errors.iter().any(ValidationError::is_format_error));
+}
@@ -0,0 +1,58 @@
+use kakao_rs::prelude::*;
+
+#[test]
+fn valid_template_test() {
+    let mut result = Template::new();
+    result.push_quick_reply(QuickReply::new("오늘", "오늘 공지 보여줘"));
+
+    let mut list_card = ListCard::new("공지");
+    list_card.push_item(ListItem::new("1번 공지").with_link("https://naver.com"));
+    list_card.push_button(Button::link("더보기", "https://ajou.ac.kr"));
+    result.push_output(list_card.build());
+
+    assert_eq!(result.validate(), Ok(()));
+}
+
+#[test]
+fn validation_error_paths_test() {
+    let mut result = Template::new();
+    result.push_quick_reply(QuickReply::new(
+        "라벨이 열네 글자를 넘는 바로가기 응답",
+        "발화",
+    ));
+
+    let list_card = ListCard::new("아이템 없음");
+    result.push_output(list_card.build());
+
+    let mut carousel = Carousel::new().with_type(BasicCard::id());
+    carousel.push_card(
+        BasicCard::new()
+            .with_title("썸네일 없음")
+            .with_button(Button::new(ButtonType::Link).with_label("링크"))
+            .build_card(),
+    );
+    carousel.push_card(CommerceCard::new().build_card());
+    result.push_output(carousel.build());
+
+    let paths: Vec<String> = result
+        .validate()
+        .unwrap_err()
+        .into_iter()
+        .map(|e| e.path)
+        .collect();
+
+    assert_eq!(
+        paths,
+        vec![
+            "template.outputs[0].listCard.items",
+            "template.outputs[1].carousel.items[0].thumbnail.imageUrl",
+            "template.outputs[1].carousel.items[0].buttons[0].webLinkUrl",
+            "template.outputs[1].carousel.items[1]",
+            "template.outputs[1].carousel.items[1].description",
+            "template.outputs[1].carousel.items[1].currency",
+            "template.outputs[1].carousel.items[1].thumbnails",
+            "template.outputs[1].carousel.items[1].buttons",
+            "template.quickReplies[0].label",
+        ]
+    );
+}
@@ -0,0 +1,112 @@
+use kakao_rs::prelude::*;
+use kakao_rs::visit::{self, Visitor, VisitorMut};
+
+fn sample() -> Template {
+    let mut carousel = Carousel::new().with_type(BasicCard::id());
+    carousel
+        .push_card(
+            BasicCard::new()
+                .with_thumbnail("http://ajou.ac.kr/1.png")
+                .with_link("https://ajou.ac.kr/1")
+                .with_button(Button::link("열기", "https://ajou.ac.kr/1#top"))
+                .build_card(),
+        )
+        .put_header_thumbnail("http://ajou.ac.kr/header.png");
+
+    let list_card = ListCard::new("공지")
+        .with_item(
+            ListItem::new("1번 공지")
+                .with_image("http://ajou.ac.kr/item.png")
+                .with_link("https://ajou.ac.kr/notice?id=1"),
+        )
+        .with_button(Button::call("전화", "031-219-2114"));
+
+    Template::new()
+        .with_output(carousel.build())
+        .with_output(list_card.build())
+        .with_output(SimpleImage::new("http://ajou.ac.kr/simple.png", "이미지").build())
+        .with_quick_reply(QuickReply::new("다음", "다음"))
+}
+
+#[derive(Default)]
+struct Count {
+    buttons: usize,
+    thumbnails: usize,
+    links: usize,
+    quick_replies: usize,
+    cards: usize,
+}
+
+impl Visitor for Count {
+    fn visit_button(&mut self, _: &Button) {
+        self.buttons += 1;
+    }
+
+    fn visit_thumbnail(&mut self, thumbnail: &ThumbNail) {
+        self.thumbnails += 1;
+        visit::walk_thumbnail(self, thumbnail);
+    }
+
+    fn visit_link(&mut self, _: &Link) {
+        self.links += 1;
+    }
+
+    fn visit_quick_reply(&mut self, _: &QuickReply) {
+        self.quick_replies += 1;
+    }
+
+    fn visit_card(&mut self, card: &Card) {
+        self.cards += 1;
+        visit::walk_card(self, card);
+    }
+}
+
+#[test]
+fn visitor_test() {
+    let mut count = Count::default();
+    count.visit_template(&sample());
+
+    assert_eq!(count.buttons, 2);
+    assert_eq!(count.thumbnails, 2); // 캐로셀 헤더 + 카드
+    assert_eq!(count.links, 2); // 썸네일 link + 리스트 아이템 link
+    assert_eq!(count.quick_replies, 1);
+    assert_eq!(count.cards, 1);
+}
+
+#[test]
+fn rewrite_test() {
+    let mut result = sample();
+    visit::upgrade_https().visit_template_mut(&mut result);
+    visit::append_query(&[("utm_source", "kakao"), ("utm_campaign", "공지")])
+        .visit_template_mut(&mut result);
+    visit::MaskPhoneNumbers.visit_template_mut(&mut result);
+
+    let serialized = result.to_string();
+    assert!(!serialized.contains("http://"));
+    assert!(serialized.contains(r#""imageUrl":"https://ajou.ac.kr/header.png""#));
+    assert!(serialized.contains(r#""imageUrl":"https://ajou.ac.kr/item.png""#));
+    assert!(serialized.contains(r#""imageUrl":"https://ajou.ac.kr/simple.png""#));
+    assert!(serialized.contains(
+        r#""webLinkUrl":"https://ajou.ac.kr/1?utm_source=kakao&utm_campaign=%EA%B3%B5%EC%A7%80#top""#
+    ));
+    assert!(serialized.contains(
+        r#""web":"https://ajou.ac.kr/notice?id=1&utm_source=kakao&utm_campaign=%EA%B3%B5%EC%A7%80""#
+    ));
+    assert!(serialized.contains(r#""phoneNumber":"***-***-2114""#));
+}
+
+#[test]
+fn rewrite_link_targets_test() {
+    let item = ListItem::new("공지").with_link_targets(
+        Link::default()
+            .with_pc("https://ajou.ac.kr")
+            .with_mobile("https://m.ajou.ac.kr"),
+    );
+    let mut result = Template::new().with_output(ListCard::new("공지").with_item(item).build());
+    visit::append_query(&[("utm_source", "kakao")]).visit_template_mut(&mut result);
+
+    let serialized = result.to_string();
+    assert!(serialized.contains(
+        r#""link":{"pc":"https://ajou.ac.kr?utm_source=kakao","mobile":"https://m.ajou.ac.kr?utm_source=kakao"}"#
+    ));
+}